@@ -0,0 +1,68 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_closure::*;
+use std::collections::HashSet;
+
+type Sets = Vec<HashSet<usize>>;
+
+// data
+fn get_pairs(n: usize) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+    for i in 0..n {
+        for j in 0..n {
+            if (i * 31 + j * 17) % 5 == 0 {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+// variants
+fn get_hash_sets(n: usize) -> ClosureOneOf2<Sets, BitMatrix, (usize, usize), bool> {
+    let mut sets = vec![HashSet::new(); n];
+    for (i, j) in get_pairs(n) {
+        sets[i].insert(j);
+    }
+    Capture(sets)
+        .fun(|sets, (i, j): (usize, usize)| sets[i].contains(&j))
+        .into_oneof2_var1()
+}
+fn get_bit_set(n: usize) -> ClosureOneOf2<Sets, BitMatrix, (usize, usize), bool> {
+    ClosureBitSet::from_pairs(n, get_pairs(n)).into_oneof2_var2()
+}
+
+// benchmark computations
+fn count<F: Fun<(usize, usize), bool>>(n: usize, fun: &F) -> usize {
+    let mut count = 0;
+    for i in 0..n {
+        for j in 0..n {
+            if fun.call((i, j)) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn bit_set(c: &mut Criterion) {
+    let treatments = [100, 1_000, 5_000];
+
+    let mut group = c.benchmark_group("BitSet");
+
+    for n in treatments {
+        group.bench_with_input(BenchmarkId::new("vec_of_hash_sets", n), &n, |b, &n| {
+            let fun = get_hash_sets(n);
+            b.iter(|| count(n, &fun))
+        });
+
+        group.bench_with_input(BenchmarkId::new("closure_bit_set", n), &n, |b, &n| {
+            let fun = get_bit_set(n);
+            b.iter(|| count(n, &fun))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bit_set);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_closure::*;
+
+type Weight = i32;
+type Jagged = Vec<Vec<Weight>>;
+type Flat = (usize, Vec<Weight>);
+
+// data
+fn get_jagged(n: usize) -> Jagged {
+    let mut jagged = Jagged::with_capacity(n);
+    for i in 0..n {
+        jagged.push(Vec::with_capacity(n));
+        for j in 0..n {
+            jagged[i].push((i + j) as Weight)
+        }
+    }
+    jagged
+}
+
+// variants
+type Weights = ClosureOneOf2<Jagged, Flat, (usize, usize), Weight>;
+
+fn get_weights(n: usize) -> Weights {
+    Capture(get_jagged(n))
+        .fun(|w, (i, j): (usize, usize)| w[i][j])
+        .into_oneof2_var1()
+}
+
+// benchmark computations
+fn call(n: usize, weights: &Weights) -> Weight {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += weights.call((i, j));
+        }
+    }
+    sum
+}
+fn call_outlined(n: usize, weights: &Weights) -> Weight {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += weights.call_outlined((i, j));
+        }
+    }
+    sum
+}
+
+fn call_outlined_bench(c: &mut Criterion) {
+    let treatments = [1_000, 5_000];
+
+    let mut group = c.benchmark_group("CallOutlined");
+
+    for n in treatments {
+        let weights = get_weights(n);
+
+        group.bench_with_input(BenchmarkId::new("call", n), &n, |b, &n| {
+            b.iter(|| call(n, &weights))
+        });
+
+        group.bench_with_input(BenchmarkId::new("call_outlined", n), &n, |b, &n| {
+            b.iter(|| call_outlined(n, &weights))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, call_outlined_bench);
+criterion_main!(benches);
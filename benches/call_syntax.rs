@@ -0,0 +1,94 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_closure::*;
+
+type Weight = i32;
+type Jagged = Vec<Vec<Weight>>;
+
+// data
+fn get_jagged(n: usize) -> Jagged {
+    let mut jagged = Jagged::with_capacity(n);
+    for i in 0..n {
+        jagged.push(Vec::with_capacity(n));
+        for j in 0..n {
+            jagged[i].push((i + j) as Weight)
+        }
+    }
+    jagged
+}
+
+// variants
+struct Provider {
+    weights: Closure<Jagged, (usize, usize), Weight>,
+}
+
+// benchmark computations
+fn call(n: usize, provider: &Provider) -> Weight {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += provider.weights.call((i, j));
+        }
+    }
+    sum
+}
+fn call2(n: usize, provider: &Provider) -> Weight {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += provider.weights.call2(i, j);
+        }
+    }
+    sum
+}
+fn as_fn_bound_once(n: usize, provider: &Provider) -> Weight {
+    let weights = provider.weights.as_fn();
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += weights((i, j));
+        }
+    }
+    sum
+}
+fn as_fn_per_call(n: usize, provider: &Provider) -> Weight {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += (provider.weights.as_fn())((i, j));
+        }
+    }
+    sum
+}
+
+fn call_syntax(c: &mut Criterion) {
+    let treatments = [1_000, 5_000];
+
+    let mut group = c.benchmark_group("CallSyntax");
+
+    for n in treatments {
+        let provider = Provider {
+            weights: Capture(get_jagged(n)).fun(|w, (i, j): (usize, usize)| w[i][j]),
+        };
+
+        group.bench_with_input(BenchmarkId::new("call", n), &n, |b, &n| {
+            b.iter(|| call(n, &provider))
+        });
+
+        group.bench_with_input(BenchmarkId::new("call2", n), &n, |b, &n| {
+            b.iter(|| call2(n, &provider))
+        });
+
+        group.bench_with_input(BenchmarkId::new("as_fn_bound_once", n), &n, |b, &n| {
+            b.iter(|| as_fn_bound_once(n, &provider))
+        });
+
+        group.bench_with_input(BenchmarkId::new("as_fn_per_call", n), &n, |b, &n| {
+            b.iter(|| as_fn_per_call(n, &provider))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, call_syntax);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_closure::*;
+
+type Weight = i64;
+
+// data
+fn get_weights(n: usize) -> Vec<Weight> {
+    (0..n).map(|i| i as Weight).collect()
+}
+
+// variants
+fn add<C>(
+    inner: Closure<C, usize, Weight>,
+) -> Closure<(Closure<C, usize, Weight>, Weight), usize, Weight> {
+    Capture((inner, 1)).fun(|(inner, a), i| inner.call(i) + a)
+}
+
+macro_rules! nest {
+    ($f:expr) => { $f };
+    ($f:expr, _ $(, $rest:tt)*) => {
+        add(nest!($f $(, $rest)*))
+    };
+}
+
+// benchmark computations
+fn sum<F: Fun<usize, Weight>>(n: usize, fun: &F) -> Weight {
+    let mut sum = 0;
+    for i in 0..n {
+        sum += fun.call(i);
+    }
+    sum
+}
+
+fn deep_nesting(c: &mut Criterion) {
+    let treatments = [100_000];
+
+    let mut group = c.benchmark_group("DeepNesting");
+
+    for n in treatments {
+        group.bench_with_input(BenchmarkId::new("nested_10", n), &n, |b, &n| {
+            let fun = nest!(
+                Capture(get_weights(n)).fun(|x, i| x[i]),
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _
+            );
+            b.iter(|| sum(n, &fun))
+        });
+
+        group.bench_with_input(BenchmarkId::new("sealed_10", n), &n, |b, &n| {
+            let fun = nest!(
+                Capture(get_weights(n)).fun(|x, i| x[i]),
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _,
+                _
+            );
+            let fun = fun.seal();
+            b.iter(|| sum(n, &fun))
+        });
+
+        group.bench_with_input(BenchmarkId::new("sealed_each_layer_10", n), &n, |b, &n| {
+            let mut fun = Capture(get_weights(n)).fun(|x, i| x[i]).seal();
+            for _ in 0..10 {
+                fun = add(fun).seal();
+            }
+            b.iter(|| sum(n, &fun))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, deep_nesting);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use orx_closure::*;
+
+type Weight = i32;
+type Jagged = Vec<Vec<Weight>>;
+type Pair = (usize, usize);
+
+// data
+fn get_jagged(n: usize) -> Jagged {
+    let mut jagged = Jagged::with_capacity(n);
+    for i in 0..n {
+        jagged.push(Vec::with_capacity(n));
+        for j in 0..n {
+            jagged[i].push((i + j) as Weight)
+        }
+    }
+    jagged
+}
+
+fn weight(n: usize) -> Closure<Jagged, Pair, Weight> {
+    Capture(get_jagged(n)).fun(|x, (i, j): Pair| x[i][j])
+}
+
+fn swap((i, j): Pair) -> Pair {
+    (j, i)
+}
+fn to_cost(w: Weight) -> i64 {
+    2 * w as i64 + 1
+}
+
+// variants
+type Inner = Closure<Jagged, Pair, Weight>;
+type MapIn = Closure<(Inner, fn(Pair) -> Pair), Pair, Weight>;
+type Stacked = Closure<(MapIn, fn(Weight) -> i64), Pair, i64>;
+type Fused = Closure<(Inner, fn(Pair) -> Pair, fn(Weight) -> i64), Pair, i64>;
+
+fn stacked(n: usize) -> Stacked {
+    let map_in: MapIn = Capture((weight(n), swap as fn(Pair) -> Pair))
+        .fun(|(fun, map_in), ij: Pair| fun.call(map_in(ij)));
+    Capture((map_in, to_cost as fn(Weight) -> i64))
+        .fun(|(fun, map_out), ij: Pair| map_out(fun.call(ij)))
+}
+fn fused(n: usize) -> Fused {
+    weight(n).map_in_out(swap, to_cost)
+}
+
+// benchmark computations
+fn sum<F: Fun<Pair, i64>>(n: usize, fun: &F) -> i64 {
+    let mut sum = 0;
+    for i in 0..n {
+        for j in 0..n {
+            sum += fun.call((i, j));
+        }
+    }
+    sum
+}
+
+fn map_in_out(c: &mut Criterion) {
+    let treatments = [1_000, 5_000];
+
+    let mut group = c.benchmark_group("MapInOut");
+
+    for n in treatments {
+        group.bench_with_input(BenchmarkId::new("stacked", n), &n, |b, &n| {
+            let fun = stacked(n);
+            b.iter(|| sum(n, &fun))
+        });
+
+        group.bench_with_input(BenchmarkId::new("fused", n), &n, |b, &n| {
+            let fun = fused(n);
+            b.iter(|| sum(n, &fun))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, map_in_out);
+criterion_main!(benches);
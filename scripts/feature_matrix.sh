@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+# Builds, lints and tests the crate for every combination of its optional features.
+#
+# usage: ./scripts/feature_matrix.sh
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+features=(either mmap petgraph rayon serde testing)
+n=${#features[@]}
+
+for ((mask = 0; mask < (1 << n); mask++)); do
+    selected=()
+    for ((i = 0; i < n; i++)); do
+        if ((mask & (1 << i))); then
+            selected+=("${features[i]}")
+        fi
+    done
+    list=$(IFS=,; echo "${selected[*]:-}")
+
+    echo "== features: [${list}]"
+    cargo clippy --all-targets --no-default-features --features "${list}" -- -D warnings
+    cargo test --no-default-features --features "${list}"
+done
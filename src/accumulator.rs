@@ -0,0 +1,137 @@
+use crate::fun::FunMut;
+use std::fmt::Debug;
+
+/// Accumulator strictly separating the captured state from the update function, and hence, having two components:
+///
+/// * `Capture` is the accumulated state,
+/// * `fn(&mut Capture, In)` is the update which absorbs an input into the state.
+///
+/// It consumes a stream of inputs and can return the final state; which is convenient for event counting or running statistics.
+///
+/// Note that, unlike trait objects of fn-traits, `Accumulator` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// #[derive(Default, Debug, PartialEq)]
+/// struct Stats { count: usize, sum: f64, max: f64 }
+///
+/// // stats: Accumulator<Stats, f64>
+/// let mut stats = Capture(Stats::default()).accumulator(|s, x: f64| {
+///     s.count += 1;
+///     s.sum += x;
+///     s.max = s.max.max(x);
+/// });
+///
+/// stats.push(2.0);
+/// stats.extend([5.0, 1.0]);
+/// assert_eq!(3, stats.captured_data().count);
+///
+/// let stats = stats.fold([4.0]);
+/// assert_eq!(Stats { count: 4, sum: 12.0, max: 5.0 }, stats);
+/// ```
+#[derive(Clone)]
+pub struct Accumulator<Capture, In> {
+    capture: Capture,
+    fun: fn(&mut Capture, In),
+}
+
+impl<Capture: Debug, In> Debug for Accumulator<Capture, In> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Accumulator")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In> Accumulator<Capture, In> {
+    pub(super) fn new(capture: Capture, fun: fn(&mut Capture, In)) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Absorbs the `input` into the accumulated state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut num_errors = Capture(0).accumulator(|count, event: &str| {
+    ///     if event.starts_with("error") {
+    ///         *count += 1;
+    ///     }
+    /// });
+    ///
+    /// num_errors.push("error: disk full");
+    /// num_errors.push("info: retrying");
+    ///
+    /// assert_eq!(&1, num_errors.captured_data());
+    /// ```
+    #[inline(always)]
+    pub fn push(&mut self, input: In) {
+        (self.fun)(&mut self.capture, input)
+    }
+
+    /// Consumes the accumulator, absorbs all `inputs` into the state and returns the final state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    /// use std::collections::HashMap;
+    ///
+    /// let word_counts = Capture(HashMap::new())
+    ///     .accumulator(|counts, word: &str| *counts.entry(word).or_insert(0) += 1)
+    ///     .fold("a rose is a rose".split(' '));
+    ///
+    /// assert_eq!(Some(&2), word_counts.get("rose"));
+    /// assert_eq!(Some(&1), word_counts.get("is"));
+    /// ```
+    pub fn fold(mut self, inputs: impl IntoIterator<Item = In>) -> Capture {
+        self.extend(inputs);
+        self.capture
+    }
+
+    /// Returns a reference to the accumulated state.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the accumulator and returns back the accumulated state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut longest = Capture(String::new()).accumulator(|l, x: &str| {
+    ///     if x.len() > l.len() {
+    ///         *l = x.to_string();
+    ///     }
+    /// });
+    ///
+    /// longest.extend(["abc", "abcde", "ab"]);
+    ///
+    /// assert_eq!("abcde", longest.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+}
+
+impl<Capture, In> Extend<In> for Accumulator<Capture, In> {
+    fn extend<I: IntoIterator<Item = In>>(&mut self, iter: I) {
+        for input in iter {
+            self.push(input);
+        }
+    }
+}
+
+impl<Capture, In> FunMut<In, ()> for Accumulator<Capture, In> {
+    fn call_mut(&mut self, input: In) {
+        self.push(input)
+    }
+}
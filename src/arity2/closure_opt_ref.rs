@@ -0,0 +1,99 @@
+use crate::fun::FunOptRef;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In1, In2) -> Option<&Out>` is the transformation.
+///
+/// It represents the transformation `(In1, In2) -> Option<&Out>` over two separate arguments.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureOptRef2` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **It is equivalent to `ClosureOptRef<Capture, (In1, In2), Out>`; however, it is called with two arguments rather than a tuple.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// // fun: ClosureOptRef2<Vec<Vec<i32>>, usize, usize, i32>
+/// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_option_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)));
+///
+/// assert_eq!(Some(&3), fun.call(1, 0));
+/// assert_eq!(None, fun.call(2, 0));
+/// ```
+#[derive(Clone)]
+pub struct ClosureOptRef2<Capture, In1, In2, Out: ?Sized> {
+    capture: Capture,
+    fun: fn(&Capture, In1, In2) -> Option<&Out>,
+}
+
+impl<Capture: Debug, In1, In2, Out: ?Sized> Debug for ClosureOptRef2<Capture, In1, In2, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureOptRef2")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In1, In2, Out: ?Sized> ClosureOptRef2<Capture, In1, In2, Out> {
+    pub(crate) fn new(capture: Capture, fun: fn(&Capture, In1, In2) -> Option<&Out>) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_option_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)));
+    ///
+    /// assert_eq!(Some(&3), fun.call(1, 0));
+    /// assert_eq!(None, fun.call(2, 0));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input1: In1, input2: In2) -> Option<&Out> {
+        (self.fun)(&self.capture, input1, input2)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In1, In2) -> Option<&Out>` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_option_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)));
+    ///
+    /// let as_fn = fun.as_fn();
+    /// assert_eq!(Some(&3), as_fn(1, 0));
+    /// ```
+    pub fn as_fn<'a>(&'a self) -> impl Fn(In1, In2) -> Option<&'a Out> {
+        move |input1, input2| self.call(input1, input2)
+    }
+}
+
+impl<Capture, In1, In2, Out: ?Sized> FunOptRef<(In1, In2), Out>
+    for ClosureOptRef2<Capture, In1, In2, Out>
+{
+    fn call(&self, (input1, input2): (In1, In2)) -> Option<&Out> {
+        ClosureOptRef2::call(self, input1, input2)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::fun::FunRef;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In1, In2) -> &Out` is the transformation.
+///
+/// It represents the transformation `(In1, In2) -> &Out` over two separate arguments.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureRef2` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **It is equivalent to `ClosureRef<Capture, (In1, In2), Out>`; however, it is called with two arguments rather than a tuple.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// // fun: ClosureRef2<Vec<Vec<String>>, usize, usize, str>
+/// let fun = Capture(vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), "d".to_string()]]).fun2_ref(|m, i: usize, j: usize| m[i][j].as_str());
+///
+/// assert_eq!("c", fun.call(1, 0));
+/// ```
+#[derive(Clone)]
+pub struct ClosureRef2<Capture, In1, In2, Out: ?Sized> {
+    capture: Capture,
+    fun: fn(&Capture, In1, In2) -> &Out,
+}
+
+impl<Capture: Debug, In1, In2, Out: ?Sized> Debug for ClosureRef2<Capture, In1, In2, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureRef2")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In1, In2, Out: ?Sized> ClosureRef2<Capture, In1, In2, Out> {
+    pub(crate) fn new(capture: Capture, fun: fn(&Capture, In1, In2) -> &Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), "d".to_string()]]).fun2_ref(|m, i: usize, j: usize| m[i][j].as_str());
+    ///
+    /// assert_eq!("c", fun.call(1, 0));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input1: In1, input2: In2) -> &Out {
+        (self.fun)(&self.capture, input1, input2)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In1, In2) -> &Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), "d".to_string()]]).fun2_ref(|m, i: usize, j: usize| m[i][j].as_str());
+    ///
+    /// let as_fn = fun.as_fn();
+    /// assert_eq!("c", as_fn(1, 0));
+    /// ```
+    pub fn as_fn<'a>(&'a self) -> impl Fn(In1, In2) -> &'a Out {
+        move |input1, input2| self.call(input1, input2)
+    }
+}
+
+impl<Capture, In1, In2, Out: ?Sized> FunRef<(In1, In2), Out>
+    for ClosureRef2<Capture, In1, In2, Out>
+{
+    fn call(&self, (input1, input2): (In1, In2)) -> &Out {
+        ClosureRef2::call(self, input1, input2)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::fun::FunResRef;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In1, In2) -> Result<&Out, Error>` is the transformation.
+///
+/// It represents the transformation `(In1, In2) -> Result<&Out, Error>` over two separate arguments.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureResRef2` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **It is equivalent to `ClosureResRef<Capture, (In1, In2), Out, Error>`; however, it is called with two arguments rather than a tuple.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// // fun: ClosureResRef2<Vec<Vec<i32>>, usize, usize, i32, &'static str>
+/// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_result_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)).ok_or("out of bounds"));
+///
+/// assert_eq!(Ok(&3), fun.call(1, 0));
+/// assert_eq!(Err("out of bounds"), fun.call(2, 0));
+/// ```
+#[derive(Clone)]
+pub struct ClosureResRef2<Capture, In1, In2, Out: ?Sized, Error> {
+    capture: Capture,
+    fun: fn(&Capture, In1, In2) -> Result<&Out, Error>,
+}
+
+impl<Capture: Debug, In1, In2, Out: ?Sized, Error> Debug
+    for ClosureResRef2<Capture, In1, In2, Out, Error>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureResRef2")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In1, In2, Out: ?Sized, Error> ClosureResRef2<Capture, In1, In2, Out, Error> {
+    pub(crate) fn new(
+        capture: Capture,
+        fun: fn(&Capture, In1, In2) -> Result<&Out, Error>,
+    ) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_result_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)).ok_or("out of bounds"));
+    ///
+    /// assert_eq!(Ok(&3), fun.call(1, 0));
+    /// assert_eq!(Err("out of bounds"), fun.call(2, 0));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input1: In1, input2: In2) -> Result<&Out, Error> {
+        (self.fun)(&self.capture, input1, input2)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In1, In2) -> Result<&Out, Error>` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_result_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)).ok_or("out of bounds"));
+    ///
+    /// let as_fn = fun.as_fn();
+    /// assert_eq!(Ok(&3), as_fn(1, 0));
+    /// ```
+    pub fn as_fn<'a>(&'a self) -> impl Fn(In1, In2) -> Result<&'a Out, Error> {
+        move |input1, input2| self.call(input1, input2)
+    }
+}
+
+impl<Capture, In1, In2, Out: ?Sized, Error> FunResRef<(In1, In2), Out, Error>
+    for ClosureResRef2<Capture, In1, In2, Out, Error>
+{
+    fn call(&self, (input1, input2): (In1, In2)) -> Result<&Out, Error> {
+        ClosureResRef2::call(self, input1, input2)
+    }
+}
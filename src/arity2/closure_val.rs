@@ -0,0 +1,95 @@
+use crate::fun::Fun;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In1, In2) -> Out` is the transformation.
+///
+/// It represents the transformation `(In1, In2) -> Out` over two separate arguments.
+///
+/// Note that, unlike trait objects of fn-traits, `Closure2` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **It is equivalent to `Closure<Capture, (In1, In2), Out>`; however, it is called with two arguments rather than a tuple.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// // fun: Closure2<Vec<Vec<i32>>, usize, usize, i32>
+/// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2(|m, i: usize, j: usize| m[i][j]);
+///
+/// assert_eq!(3, fun.call(1, 0));
+/// ```
+#[derive(Clone)]
+pub struct Closure2<Capture, In1, In2, Out> {
+    capture: Capture,
+    fun: fn(&Capture, In1, In2) -> Out,
+}
+
+impl<Capture: Debug, In1, In2, Out> Debug for Closure2<Capture, In1, In2, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Closure2")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In1, In2, Out> Closure2<Capture, In1, In2, Out> {
+    pub(crate) fn new(capture: Capture, fun: fn(&Capture, In1, In2) -> Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2(|m, i: usize, j: usize| m[i][j]);
+    ///
+    /// assert_eq!(3, fun.call(1, 0));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input1: In1, input2: In2) -> Out {
+        (self.fun)(&self.capture, input1, input2)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In1, In2) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2(|m, i: usize, j: usize| m[i][j]);
+    ///
+    /// let as_fn = fun.as_fn();
+    /// assert_eq!(3, as_fn(1, 0));
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(In1, In2) -> Out + '_ {
+        move |input1, input2| self.call(input1, input2)
+    }
+}
+
+impl<Capture, In1, In2, Out> Fun<(In1, In2), Out> for Closure2<Capture, In1, In2, Out> {
+    fn call(&self, (input1, input2): (In1, In2)) -> Out {
+        Closure2::call(self, input1, input2)
+    }
+}
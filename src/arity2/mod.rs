@@ -0,0 +1,4 @@
+pub(crate) mod closure_opt_ref;
+pub(crate) mod closure_ref;
+pub(crate) mod closure_res_ref;
+pub(crate) mod closure_val;
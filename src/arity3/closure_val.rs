@@ -0,0 +1,97 @@
+use crate::fun::Fun;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In1, In2, In3) -> Out` is the transformation.
+///
+/// It represents the transformation `(In1, In2, In3) -> Out` over three separate arguments.
+///
+/// Note that, unlike trait objects of fn-traits, `Closure3` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **It is equivalent to `Closure<Capture, (In1, In2, In3), Out>`; however, it is called with three arguments rather than a tuple.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// // fun: Closure3<Vec<Vec<Vec<i32>>>, usize, usize, usize, i32>
+/// let fun = Capture(vec![vec![vec![1, 2], vec![3, 4]]]).fun3(|m, i: usize, j: usize, k: usize| m[i][j][k]);
+///
+/// assert_eq!(3, fun.call(0, 1, 0));
+/// ```
+#[derive(Clone)]
+pub struct Closure3<Capture, In1, In2, In3, Out> {
+    capture: Capture,
+    fun: fn(&Capture, In1, In2, In3) -> Out,
+}
+
+impl<Capture: Debug, In1, In2, In3, Out> Debug for Closure3<Capture, In1, In2, In3, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Closure3")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In1, In2, In3, Out> Closure3<Capture, In1, In2, In3, Out> {
+    pub(crate) fn new(capture: Capture, fun: fn(&Capture, In1, In2, In3) -> Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![vec![1, 2], vec![3, 4]]]).fun3(|m, i: usize, j: usize, k: usize| m[i][j][k]);
+    ///
+    /// assert_eq!(3, fun.call(0, 1, 0));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input1: In1, input2: In2, input3: In3) -> Out {
+        (self.fun)(&self.capture, input1, input2, input3)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In1, In2, In3) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![vec![1, 2], vec![3, 4]]]).fun3(|m, i: usize, j: usize, k: usize| m[i][j][k]);
+    ///
+    /// let as_fn = fun.as_fn();
+    /// assert_eq!(3, as_fn(0, 1, 0));
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(In1, In2, In3) -> Out + '_ {
+        move |input1, input2, input3| self.call(input1, input2, input3)
+    }
+}
+
+impl<Capture, In1, In2, In3, Out> Fun<(In1, In2, In3), Out>
+    for Closure3<Capture, In1, In2, In3, Out>
+{
+    fn call(&self, (input1, input2, input3): (In1, In2, In3)) -> Out {
+        Closure3::call(self, input1, input2, input3)
+    }
+}
@@ -0,0 +1,100 @@
+use std::{borrow::Borrow, ops::Deref};
+
+/// Output of a call which is either borrowed from the captured data or computed and owned.
+///
+/// It bridges the divide between the closures returning values, such as `Closure`, and the closures returning references, such as `ClosureRef`:
+/// a single closure can lend references for some inputs and compute owned values for others, without cloning the borrowed values.
+///
+/// Unlike `std::borrow::Cow`, it does not require `T: ToOwned`; and it dereferences to `T` in both cases.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn total(values: &[CallValue<'_, String>]) -> usize {
+///     values.iter().map(|x| x.len()).sum()
+/// }
+///
+/// let stored = String::from("stored");
+/// let values = [CallValue::Borrowed(&stored), CallValue::Owned(String::from("new"))];
+///
+/// assert_eq!(9, total(&values));
+/// assert!(values[0].is_borrowed());
+/// assert_eq!("new", values[1].as_str());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum CallValue<'a, T> {
+    /// Value borrowed from the captured data.
+    Borrowed(&'a T),
+    /// Value computed by the call and owned by the caller.
+    Owned(T),
+}
+
+impl<'a, T> CallValue<'a, T> {
+    /// Returns true if the value is borrowed from the captured data.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns true if the value is owned.
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Converts the value into an owned value, by cloning it only if it is borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let stored = vec![1, 2];
+    ///
+    /// let borrowed = CallValue::Borrowed(&stored);
+    /// let owned = CallValue::Owned(vec![3]);
+    ///
+    /// assert_eq!(vec![1, 2], borrowed.into_owned());
+    /// assert_eq!(vec![3], owned.into_owned());
+    /// ```
+    pub fn into_owned(self) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            Self::Borrowed(x) => x.clone(),
+            Self::Owned(x) => x,
+        }
+    }
+}
+
+impl<T> Deref for CallValue<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(x) => x,
+            Self::Owned(x) => x,
+        }
+    }
+}
+
+impl<T> AsRef<T> for CallValue<'_, T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> Borrow<T> for CallValue<'_, T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: PartialEq> PartialEq for CallValue<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T: Eq> Eq for CallValue<'_, T> {}
@@ -1,4 +1,14 @@
-use crate::{closure_ref::ClosureRef, closure_val::Closure, ClosureOptRef, ClosureResRef};
+use crate::{
+    accumulator::Accumulator, closure_ref::ClosureRef, closure_ref_mut::ClosureRefMut,
+    closure_val::Closure, CallValue, Capture2, Closure2, Closure3, ClosureArrayOf,
+    ClosureCallValue, ClosureFold, ClosureKey, ClosureMut, ClosureOnce, ClosureOptRef,
+    ClosureOptRef2, ClosureOptRefMut, ClosureRef2, ClosureResRef, ClosureResRef2, ClosureResRefMut,
+    ClosureScratch, Lens, Setter,
+};
+use std::{
+    borrow::Borrow,
+    cell::{BorrowError, BorrowMutError, Cell, RefCell},
+};
 
 /// A utility wrapper which simply wraps around data to be captured and allows methods to define desired closures.
 ///
@@ -85,6 +95,112 @@ impl<Data> Capture<Data> {
         Closure::new(self.0, fun)
     }
 
+    /// Defines a `ClosureMut<Data, In, Out>` capturing `Data` and defining `In -> Out` transformation which can mutate the captured data.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// This is the mutable counterpart of `fun`, which allows to keep a state across calls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut counter = Capture(0).fun_mut(|count, step: usize| {
+    ///     *count += step;
+    ///     *count
+    /// });
+    ///
+    /// assert_eq!(1, counter.call_mut(1));
+    /// assert_eq!(3, counter.call_mut(2));
+    /// assert_eq!(3, counter.into_captured_data());
+    /// ```
+    pub fn fun_mut<In, Out>(self, fun: fn(&mut Data, In) -> Out) -> ClosureMut<Data, In, Out> {
+        ClosureMut::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureOnce<Data, In, Out>` capturing `Data` and defining `In -> Out` transformation which consumes the captured data.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// The created closure can be called only once, since the call moves the captured data into the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let init = Capture(vec![3, 1, 2]).fun_once(|mut v, extra: i32| {
+    ///     v.push(extra);
+    ///     v.sort();
+    ///     v
+    /// });
+    ///
+    /// assert_eq!(vec![0, 1, 2, 3], init.call_once(0));
+    /// ```
+    pub fn fun_once<In, Out>(self, fun: fn(Data, In) -> Out) -> ClosureOnce<Data, In, Out> {
+        ClosureOnce::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureOnce<Data, In, Result<Out, Error>>` capturing `Data` and defining the fallible `In -> Result<Out, Error>` transformation which consumes the captured data.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// This is the fallible counterpart of `fun_once`; it is convenient for initialization callbacks which might fail,
+    /// such as validating and converting a configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let parse_port = |raw: &str| {
+    ///     Capture(raw.to_string()).fun_once_result(|raw, offset: u16| {
+    ///         raw.parse::<u16>()
+    ///             .map(|port| port + offset)
+    ///             .map_err(|_| format!("invalid port: {}", raw))
+    ///     })
+    /// };
+    ///
+    /// assert_eq!(Ok(8081), parse_port("8080").call_once(1));
+    /// assert_eq!(Err(String::from("invalid port: http")), parse_port("http").call_once(1));
+    /// ```
+    pub fn fun_once_result<In, Out, Error>(
+        self,
+        fun: fn(Data, In) -> Result<Out, Error>,
+    ) -> ClosureOnce<Data, In, Result<Out, Error>> {
+        ClosureOnce::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureCallValue<Data, In, Out>` capturing `Data` and defining `In -> CallValue<Out>` transformation.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// The function can either lend an output from the captured data as `CallValue::Borrowed` or compute and return it as `CallValue::Owned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let overrides = vec![(2, "two".to_string())];
+    /// let name_of = Capture(overrides).fun_call_value(|o, x: usize| {
+    ///     match o.iter().find(|(k, _)| *k == x) {
+    ///         Some((_, name)) => CallValue::Borrowed(name),
+    ///         None => CallValue::Owned(x.to_string()),
+    ///     }
+    /// });
+    ///
+    /// assert_eq!("two", name_of.call(2).as_str());
+    /// assert_eq!("3", name_of.call(3).as_str());
+    /// ```
+    pub fn fun_call_value<In, Out>(
+        self,
+        fun: fn(&Data, In) -> CallValue<'_, Out>,
+    ) -> ClosureCallValue<Data, In, Out> {
+        ClosureCallValue::new(self.0, fun)
+    }
+
     /// Defines a `ClosureRef<Data, In, Out>` capturing `Data` and defining `In -> &Out` transformation.
     ///
     /// Consumes the `Capture` and moves the captured data inside the created closure.
@@ -111,6 +227,32 @@ impl<Data> Capture<Data> {
         ClosureRef::new(self.0, fun)
     }
 
+    /// Defines a `ClosureRefMut<Data, In, Out>` capturing `Data` and defining `In -> &mut Out` transformation.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// This is the mutable counterpart of `fun_ref`, which allows to mutate a selected element of the captured data in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let grid = vec![vec![0; 3]; 2];
+    /// let mut cell = Capture(grid).fun_ref_mut(|g, (i, j): (usize, usize)| &mut g[i][j]);
+    ///
+    /// *cell.call_mut((1, 2)) = 7;
+    ///
+    /// assert_eq!(&7, cell.call_mut((1, 2)));
+    /// assert_eq!(vec![vec![0, 0, 0], vec![0, 0, 7]], cell.into_captured_data());
+    /// ```
+    pub fn fun_ref_mut<In, Out: ?Sized>(
+        self,
+        fun: fn(&mut Data, In) -> &mut Out,
+    ) -> ClosureRefMut<Data, In, Out> {
+        ClosureRefMut::new(self.0, fun)
+    }
+
     /// Defines a `ClosureOptRef<Data, In, Out>` capturing `Data` and defining `In -> Option<&Out>` transformation.
     ///
     /// Consumes the `Capture` and moves the captured data inside the created closure.
@@ -145,6 +287,35 @@ impl<Data> Capture<Data> {
         ClosureOptRef::new(self.0, fun)
     }
 
+    /// Defines a `ClosureOptRefMut<Data, In, Out>` capturing `Data` and defining `In -> Option<&mut Out>` transformation.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// This is the mutable counterpart of `fun_option_ref`, which allows to mutate an element of the captured data in place whenever it exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let grid = vec![vec![0; 2]; 2];
+    /// let mut cell = Capture(grid)
+    ///     .fun_option_ref_mut(|g, (i, j): (usize, usize)| g.get_mut(i).and_then(|row| row.get_mut(j)));
+    ///
+    /// if let Some(x) = cell.call_mut((1, 0)) {
+    ///     *x = 7;
+    /// }
+    /// assert!(cell.call_mut((2, 0)).is_none());
+    ///
+    /// assert_eq!(vec![vec![0, 0], vec![7, 0]], cell.into_captured_data());
+    /// ```
+    pub fn fun_option_ref_mut<In, Out: ?Sized>(
+        self,
+        fun: fn(&mut Data, In) -> Option<&mut Out>,
+    ) -> ClosureOptRefMut<Data, In, Out> {
+        ClosureOptRefMut::new(self.0, fun)
+    }
+
     /// Defines a `ClosureResRef<Data, In, Out, Error>` capturing `Data` and defining `In -> Result<&Out, Error>` transformation.
     ///
     /// Consumes the `Capture` and moves the captured data inside the created closure.
@@ -178,6 +349,467 @@ impl<Data> Capture<Data> {
         ClosureResRef::new(self.0, fun)
     }
 
+    /// Defines a `ClosureResRefMut<Data, In, Out, Error>` capturing `Data` and defining `In -> Result<&mut Out, Error>` transformation.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// This is the mutable counterpart of `fun_result_ref`, which allows for fallible in-place mutation of the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let grid = vec![vec![0; 2]; 2];
+    /// let mut cell = Capture(grid).fun_result_ref_mut(|g, (i, j): (usize, usize)| {
+    ///     g.get_mut(i)
+    ///         .and_then(|row| row.get_mut(j))
+    ///         .ok_or("out of bounds")
+    /// });
+    ///
+    /// if let Ok(x) = cell.call_mut((0, 1)) {
+    ///     *x = 7;
+    /// }
+    /// assert_eq!(Err("out of bounds"), cell.call_mut((0, 2)));
+    ///
+    /// assert_eq!(vec![vec![0, 7], vec![0, 0]], cell.into_captured_data());
+    /// ```
+    pub fn fun_result_ref_mut<In, Out: ?Sized, Error>(
+        self,
+        fun: fn(&mut Data, In) -> Result<&mut Out, Error>,
+    ) -> ClosureResRefMut<Data, In, Out, Error> {
+        ClosureResRefMut::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureArrayOf<N, Data, In, Out>` capturing `Data` once and defining `N` alternative `In -> Out` transformations, where the function at index `selected` is used.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// Returns `None` if `selected` is out of bounds; i.e., `selected >= N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let scores = vec![4, 9, 1];
+    /// let score = Capture(scores.clone())
+    ///     .fun_array_of([|s, i: usize| s[i], |s, i: usize| -s[i]], 1)
+    ///     .expect("valid selection");
+    /// assert_eq!(-9, score.call(1));
+    ///
+    /// let invalid = Capture(scores).fun_array_of([|s, i: usize| s[i]], 1);
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn fun_array_of<const N: usize, In, Out>(
+        self,
+        funs: [fn(&Data, In) -> Out; N],
+        selected: usize,
+    ) -> Option<ClosureArrayOf<N, Data, In, Out>> {
+        ClosureArrayOf::new(self.0, funs, selected)
+    }
+
+    /// Defines a `ClosureKey<Data, T, K>` capturing `Data` and defining the key extraction `&T -> K`, which accepts references of any lifetime and can be used for sorting and comparisons.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let priorities = vec![('a', 2), ('b', 0), ('c', 1)];
+    /// let priority = Capture(priorities).fun_key(|p, c: &char| {
+    ///     p.iter().find(|x| x.0 == *c).map(|x| x.1).unwrap_or(usize::MAX)
+    /// });
+    ///
+    /// let mut chars = vec!['z', 'a', 'b', 'c'];
+    /// chars.sort_by(priority.as_comparator());
+    /// assert_eq!(vec!['b', 'c', 'a', 'z'], chars);
+    /// ```
+    pub fn fun_key<T: ?Sized, K>(self, fun: fn(&Data, &T) -> K) -> ClosureKey<Data, T, K> {
+        ClosureKey::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureFold<Data, Item, Acc>` capturing `Data` and defining the reduction `(Acc, Item) -> Acc`.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let threshold = 10;
+    /// let count_large = Capture(threshold).fun_fold(|t, count: usize, x: i32| match x > *t {
+    ///     true => count + 1,
+    ///     false => count,
+    /// });
+    ///
+    /// assert_eq!(2, count_large.fold(0, [3, 12, 7, 42]));
+    /// ```
+    pub fn fun_fold<Item, Acc>(
+        self,
+        fun: fn(&Data, Acc, Item) -> Acc,
+    ) -> ClosureFold<Data, Item, Acc> {
+        ClosureFold::new(self.0, fun)
+    }
+
+    /// Defines an `Accumulator<Data, In>` capturing the state `Data` and absorbing each `In` into the state.
+    ///
+    /// Consumes the `Capture` and moves the initial state inside the created accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut histogram = Capture([0; 3]).accumulator(|h, x: usize| h[x.min(2)] += 1);
+    /// histogram.extend([0, 1, 1, 7]);
+    ///
+    /// assert_eq!([1, 2, 1], histogram.into_captured_data());
+    /// ```
+    pub fn accumulator<In>(self, fun: fn(&mut Data, In)) -> Accumulator<Data, In> {
+        Accumulator::new(self.0, fun)
+    }
+
+    /// Defines a `Setter<Data, In, V>` capturing `Data` and writing values `V` at positions `In` of the captured data.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created setter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut set = Capture(vec![0; 3]).setter(|v, i: usize, value: i32| v[i] = value);
+    /// set.set(1, 42);
+    ///
+    /// assert_eq!(vec![0, 42, 0], set.into_captured_data());
+    /// ```
+    pub fn setter<In, V>(self, fun: fn(&mut Data, In, V)) -> Setter<Data, In, V> {
+        Setter::new(self.0, fun)
+    }
+
+    /// Defines a `Lens<Data, In, V>` capturing `Data` and pairing the getter `In -> &V` with the setter writing `V` at position `In`.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created lens.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut name = Capture(vec![String::from("john")])
+    ///     .lens(|n, i: usize| &n[i], |n, i, value| n[i] = value);
+    ///
+    /// name.set(0, String::from("jane"));
+    /// assert_eq!("jane", name.get(0));
+    /// ```
+    pub fn lens<In, V>(
+        self,
+        get: fn(&Data, In) -> &V,
+        set: fn(&mut Data, In, V),
+    ) -> Lens<Data, In, V> {
+        Lens::new(self.0, get, set)
+    }
+
+    /// Defines a `ClosureScratch<Data, Scratch, In, Out>` capturing `Data` and defining `In -> Out` transformation which uses a caller-provided `Scratch` space.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let text = "closures over explicit captures".to_string();
+    /// let nth_word_reversed = Capture(text).fun_with_scratch(|t, buf: &mut String, n: usize| {
+    ///     buf.clear();
+    ///     buf.extend(t.split(' ').nth(n).unwrap_or_default().chars().rev());
+    ///     buf.len()
+    /// });
+    ///
+    /// let mut buf = String::new();
+    /// assert_eq!(8, nth_word_reversed.call(&mut buf, 2));
+    /// assert_eq!("ticilpxe", buf);
+    /// ```
+    pub fn fun_with_scratch<Scratch, In, Out>(
+        self,
+        fun: fn(&Data, &mut Scratch, In) -> Out,
+    ) -> ClosureScratch<Data, Scratch, In, Out> {
+        ClosureScratch::new(self.0, fun)
+    }
+
+    /// Defines a panic-free `Closure` capturing `Data` and defining `In -> Out` transformation from a `get`-based function.
+    ///
+    /// * `fun` is a lookup returning `None` when the input is not in the domain, such as `|vec, i| vec.get(i).copied()`;
+    /// * `default` is the value returned in these cases.
+    ///
+    /// The default value is stored together with the data and the lookup, and hence, the captured data of the created closure is the tuple `(Data, Out, fn(&Data, In) -> Option<Out>)`.
+    ///
+    /// This is the panic-free twin of `fun`, where the same closure is often defined by an indexing function such as `|vec, i| vec[i]` which panics on out of bounds inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let weights = vec![10, 20, 30];
+    /// let weight = Capture(weights).fun_get(0, |w, i: usize| w.get(i).copied());
+    ///
+    /// assert_eq!(20, weight.call(1));
+    /// assert_eq!(0, weight.call(42)); // rather than panicking
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_get<In, Out: Clone>(
+        self,
+        default: Out,
+        fun: fn(&Data, In) -> Option<Out>,
+    ) -> Closure<(Data, Out, fn(&Data, In) -> Option<Out>), In, Out> {
+        Closure::new((self.0, default, fun), |(data, default, fun), input| {
+            fun(data, input).unwrap_or_else(|| default.clone())
+        })
+    }
+
+    /// Defines a panic-free `ClosureRef` capturing `Data` and defining `In -> &Out` transformation from a `get`-based function.
+    ///
+    /// * `fun` is a lookup returning `None` when the input is not in the domain, such as `|vec, i| vec.get(i)`;
+    /// * `default` is the value a reference to which is returned in these cases.
+    ///
+    /// The default value is stored together with the data and the lookup, and hence, the captured data of the created closure is the tuple `(Data, Out, fn(&Data, In) -> Option<&Out>)`.
+    ///
+    /// This is the panic-free twin of `fun_ref`, where the same closure is often defined by an indexing function such as `|vec, i| &vec[i]` which panics on out of bounds inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// struct Person { name: String }
+    /// let people = vec![Person { name: "john".to_string() }, Person { name: "doe".to_string() }];
+    ///
+    /// let name_of_person_with_id = Capture(people).fun_ref_get("unknown".to_string(), |ppl, id: usize| {
+    ///     ppl.get(id).map(|p| &p.name)
+    /// });
+    ///
+    /// assert_eq!("john", name_of_person_with_id.call(0));
+    /// assert_eq!("unknown", name_of_person_with_id.call(42)); // rather than panicking
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_ref_get<In, Out>(
+        self,
+        default: Out,
+        fun: fn(&Data, In) -> Option<&Out>,
+    ) -> ClosureRef<(Data, Out, fn(&Data, In) -> Option<&Out>), In, Out> {
+        ClosureRef::new((self.0, default, fun), |(data, default, fun), input| {
+            fun(data, input).unwrap_or(default)
+        })
+    }
+
+    /// Defines a `Closure` capturing interior-mutable `Data` which borrows a `RefCell<T>`, such as `RefCell<T>` or `Rc<RefCell<T>>`,
+    /// and calls `fun` with a mutable borrow of the `T` inside.
+    ///
+    /// Rather than panicking, calling the created closure returns `Err(BorrowMutError)` when the `RefCell` is already borrowed;
+    /// which might be the case when the data is shared with other closures or with the caller.
+    ///
+    /// The function is stored together with the data, and hence, the captured data of the created closure is the tuple `(Data, fn(&mut T, In) -> Out)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::<String>::new()));
+    ///
+    /// let push = Capture(Rc::clone(&log)).fun_try_mut(|log: &mut Vec<String>, x: &str| {
+    ///     log.push(x.to_string());
+    ///     log.len()
+    /// });
+    ///
+    /// assert_eq!(Some(1), push.call("a").ok());
+    ///
+    /// // while the caller holds a borrow, the call fails rather than panicking
+    /// let reading = log.borrow();
+    /// assert!(push.call("b").is_err());
+    /// drop(reading);
+    ///
+    /// assert_eq!(Some(2), push.call("c").ok());
+    /// assert_eq!(vec!["a", "c"], *log.borrow());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_try_mut<T, In, Out>(
+        self,
+        fun: fn(&mut T, In) -> Out,
+    ) -> Closure<(Data, fn(&mut T, In) -> Out), In, Result<Out, BorrowMutError>>
+    where
+        Data: Borrow<RefCell<T>>,
+    {
+        Closure::new((self.0, fun), |(data, fun), input| {
+            let mut value = data.borrow().try_borrow_mut()?;
+            Ok(fun(&mut value, input))
+        })
+    }
+
+    /// Defines a `Closure` capturing interior-mutable `Data` which borrows a `RefCell<T>`, such as `RefCell<T>` or `Rc<RefCell<T>>`,
+    /// and calls `fun` with a shared borrow of the `T` inside.
+    ///
+    /// Rather than panicking, calling the created closure returns `Err(BorrowError)` when the `RefCell` is already mutably borrowed.
+    ///
+    /// The function is stored together with the data, and hence, the captured data of the created closure is the tuple `(Data, fn(&T, In) -> Out)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// let scores = Rc::new(RefCell::new(vec![3, 5]));
+    /// let score = Capture(Rc::clone(&scores)).fun_try_ref(|s: &Vec<i32>, i: usize| s[i]);
+    ///
+    /// assert_eq!(Some(5), score.call(1).ok());
+    ///
+    /// let mut writing = scores.borrow_mut();
+    /// assert!(score.call(1).is_err());
+    /// writing[1] = 7;
+    /// drop(writing);
+    ///
+    /// assert_eq!(Some(7), score.call(1).ok());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_try_ref<T, In, Out>(
+        self,
+        fun: fn(&T, In) -> Out,
+    ) -> Closure<(Data, fn(&T, In) -> Out), In, Result<Out, BorrowError>>
+    where
+        Data: Borrow<RefCell<T>>,
+    {
+        Closure::new((self.0, fun), |(data, fun), input| {
+            let value = data.borrow().try_borrow()?;
+            Ok(fun(&value, input))
+        })
+    }
+
+    /// Defines a `Closure2<Data, In1, In2, Out>` capturing `Data` and defining `(In1, In2) -> Out` transformation over two arguments.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2(|m, i: usize, j: usize| m[i][j]);
+    ///
+    /// assert_eq!(3, fun.call(1, 0));
+    /// ```
+    pub fn fun2<In1, In2, Out>(
+        self,
+        fun: fn(&Data, In1, In2) -> Out,
+    ) -> Closure2<Data, In1, In2, Out> {
+        Closure2::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureRef2<Data, In1, In2, Out>` capturing `Data` and defining `(In1, In2) -> &Out` transformation over two arguments.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), "d".to_string()]]).fun2_ref(|m, i: usize, j: usize| m[i][j].as_str());
+    ///
+    /// assert_eq!("c", fun.call(1, 0));
+    /// ```
+    pub fn fun2_ref<In1, In2, Out: ?Sized>(
+        self,
+        fun: fn(&Data, In1, In2) -> &Out,
+    ) -> ClosureRef2<Data, In1, In2, Out> {
+        ClosureRef2::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureOptRef2<Data, In1, In2, Out>` capturing `Data` and defining `(In1, In2) -> Option<&Out>` transformation over two arguments.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_option_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)));
+    ///
+    /// assert_eq!(Some(&3), fun.call(1, 0));
+    /// assert_eq!(None, fun.call(2, 0));
+    /// ```
+    pub fn fun2_option_ref<In1, In2, Out: ?Sized>(
+        self,
+        fun: fn(&Data, In1, In2) -> Option<&Out>,
+    ) -> ClosureOptRef2<Data, In1, In2, Out> {
+        ClosureOptRef2::new(self.0, fun)
+    }
+
+    /// Defines a `ClosureResRef2<Data, In1, In2, Out, Error>` capturing `Data` and defining `(In1, In2) -> Result<&Out, Error>` transformation over two arguments.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![1, 2], vec![3, 4]]).fun2_result_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)).ok_or("out of bounds"));
+    ///
+    /// assert_eq!(Ok(&3), fun.call(1, 0));
+    /// assert_eq!(Err("out of bounds"), fun.call(2, 0));
+    /// ```
+    pub fn fun2_result_ref<In1, In2, Out: ?Sized, Error>(
+        self,
+        fun: fn(&Data, In1, In2) -> Result<&Out, Error>,
+    ) -> ClosureResRef2<Data, In1, In2, Out, Error> {
+        ClosureResRef2::new(self.0, fun)
+    }
+
+    /// Defines a `Closure3<Data, In1, In2, In3, Out>` capturing `Data` and defining `(In1, In2, In3) -> Out` transformation over three arguments.
+    ///
+    /// Consumes the `Capture` and moves the captured data inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(vec![vec![vec![1, 2], vec![3, 4]]]).fun3(|m, i: usize, j: usize, k: usize| m[i][j][k]);
+    ///
+    /// assert_eq!(3, fun.call(0, 1, 0));
+    /// ```
+    pub fn fun3<In1, In2, In3, Out>(
+        self,
+        fun: fn(&Data, In1, In2, In3) -> Out,
+    ) -> Closure3<Data, In1, In2, In3, Out> {
+        Closure3::new(self.0, fun)
+    }
+
+    /// Adds a second piece `b` to the captured data, creating a `Capture2`, so that the function receives each piece as a separate parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john", "doe"];
+    /// let ages = vec![42, 7];
+    ///
+    /// let describe = Capture(names).and(ages).fun(|names, ages, i: usize| format!("{} ({})", names[i], ages[i]));
+    /// assert_eq!("doe (7)", describe.call(1));
+    /// ```
+    pub fn and<B>(self, b: B) -> Capture2<Data, B> {
+        Capture2(self.0, b)
+    }
+
     /// Consumes the `Capture` and returns back the captured data.
     ///
     /// # Example
@@ -195,3 +827,54 @@ impl<Data> Capture<Data> {
         self.0
     }
 }
+
+impl<T> Capture<Cell<T>> {
+    /// Captures the `data` behind a `Cell`.
+    ///
+    /// The captured data can then be updated through the shared reference that the functions of closures such as `Closure` receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let counter = Capture::cell(0).fun(|count, step: u32| {
+    ///     count.set(count.get() + step);
+    ///     count.get()
+    /// });
+    ///
+    /// assert_eq!(2, counter.call(2));
+    /// assert_eq!(5, counter.call(3));
+    /// assert_eq!(5, counter.into_captured_data().into_inner());
+    /// ```
+    pub fn cell(data: T) -> Self {
+        Self(Cell::new(data))
+    }
+}
+
+impl<T> Capture<RefCell<T>> {
+    /// Captures the `data` behind a `RefCell`.
+    ///
+    /// The captured data can then be borrowed mutably through the shared reference that the functions of closures such as `Closure` receive.
+    /// The `fun_try_mut` and `fun_try_ref` builders return the borrow errors rather than panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let history = Capture::refcell(vec![]).fun_try_mut(|h: &mut Vec<i32>, x: i32| {
+    ///     h.push(x);
+    ///     h.len()
+    /// });
+    ///
+    /// assert_eq!(Some(1), history.call(4).ok());
+    /// assert_eq!(Some(2), history.call(2).ok());
+    ///
+    /// let (history, _) = history.into_captured_data();
+    /// assert_eq!(vec![4, 2], history.into_inner());
+    /// ```
+    pub fn refcell(data: T) -> Self {
+        Self(RefCell::new(data))
+    }
+}
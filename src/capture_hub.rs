@@ -0,0 +1,194 @@
+use crate::{Closure, ClosureOptRef, ClosureRef, ClosureResRef};
+use std::{fmt::Debug, sync::Arc};
+
+/// A hub owning the captured data once and minting any number of closures of different kinds sharing the same capture.
+///
+/// Each closure created by the hub captures an `Arc<Data>` pointing to the data owned by the hub;
+/// therefore, the data is neither cloned nor manually wrapped in the functions of the closures.
+/// Since `Arc<Data>` dereferences to `Data`, the functions are written almost exactly as they would be written over a `Data` capture.
+///
+/// The hub keeps track of the outstanding closures and allows to take back the data once all of them are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::sync::Arc;
+///
+/// struct Graph {
+///     names: Vec<String>,
+///     weights: Vec<Vec<u32>>,
+/// }
+///
+/// struct Solver {
+///     weight: Closure<Arc<Graph>, (usize, usize), u32>,
+///     name: ClosureRef<Arc<Graph>, usize, str>,
+/// }
+///
+/// let graph = Graph {
+///     names: vec![String::from("a"), String::from("b")],
+///     weights: vec![vec![0, 7], vec![3, 0]],
+/// };
+///
+/// let hub = CaptureHub::new(graph);
+/// let solver = Solver {
+///     weight: hub.fun(|g, (i, j)| g.weights[i][j]),
+///     name: hub.fun_ref(|g, i| g.names[i].as_str()),
+/// };
+///
+/// assert_eq!(2, hub.num_closures());
+/// assert_eq!(7, solver.weight.call((0, 1)));
+/// assert_eq!("b", solver.name.call(1));
+///
+/// drop(solver);
+/// let graph = hub.try_unwrap().ok().expect("all closures are dropped");
+/// assert_eq!(3, graph.weights[1][0]);
+/// ```
+pub struct CaptureHub<Data> {
+    data: Arc<Data>,
+}
+
+impl<Data: Debug> Debug for CaptureHub<Data> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CaptureHub")
+            .field("data", &self.data)
+            .field("num_closures", &self.num_closures())
+            .finish()
+    }
+}
+
+impl<Data> CaptureHub<Data> {
+    /// Creates a hub owning the `data` to be shared by the closures created by the hub.
+    pub fn new(data: Data) -> Self {
+        Self {
+            data: Arc::new(data),
+        }
+    }
+
+    /// Returns a reference to the shared data.
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns the number of outstanding closures created by this hub, or their clones, which are not dropped yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let hub = CaptureHub::new(vec![1, 2, 3]);
+    /// assert_eq!(0, hub.num_closures());
+    ///
+    /// let at = hub.fun(|v, i: usize| v[i]);
+    /// let at_clone = at.clone();
+    /// let len = hub.fun(|v, _: ()| v.len());
+    /// assert_eq!(3, hub.num_closures());
+    ///
+    /// drop(at);
+    /// drop(at_clone);
+    /// assert_eq!(1, hub.num_closures());
+    /// assert_eq!(3, len.call(()));
+    /// ```
+    pub fn num_closures(&self) -> usize {
+        Arc::strong_count(&self.data) - 1
+    }
+
+    /// Consumes the hub and returns back the shared data if all closures created by the hub are dropped;
+    /// returns back the hub itself as the error otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let hub = CaptureHub::new(vec![1, 2, 3]);
+    /// let sum = hub.fun(|v, _: ()| v.iter().sum::<i32>());
+    ///
+    /// let hub = hub.try_unwrap().unwrap_err(); // sum is alive
+    /// assert_eq!(6, sum.call(()));
+    ///
+    /// drop(sum);
+    /// assert_eq!(Ok(vec![1, 2, 3]), hub.try_unwrap().map_err(|_| "closures alive"));
+    /// ```
+    pub fn try_unwrap(self) -> Result<Data, Self> {
+        Arc::try_unwrap(self.data).map_err(|data| Self { data })
+    }
+
+    /// Defines a `Closure<Arc<Data>, In, Out>` sharing the data of the hub and defining `In -> Out` transformation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let hub = CaptureHub::new(vec![1, 2, 3]);
+    /// let double = hub.fun(|v, i: usize| 2 * v[i]);
+    /// let square = hub.fun(|v, i: usize| v[i] * v[i]);
+    ///
+    /// assert_eq!(4, double.call(1));
+    /// assert_eq!(9, square.call(2));
+    /// ```
+    pub fn fun<In, Out>(&self, fun: fn(&Arc<Data>, In) -> Out) -> Closure<Arc<Data>, In, Out> {
+        Closure::new(Arc::clone(&self.data), fun)
+    }
+
+    /// Defines a `ClosureRef<Arc<Data>, In, Out>` sharing the data of the hub and defining `In -> &Out` transformation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let hub = CaptureHub::new(vec![String::from("john"), String::from("doe")]);
+    /// let name = hub.fun_ref(|v, i: usize| v[i].as_str());
+    ///
+    /// assert_eq!("doe", name.call(1));
+    /// ```
+    pub fn fun_ref<In, Out: ?Sized>(
+        &self,
+        fun: fn(&Arc<Data>, In) -> &Out,
+    ) -> ClosureRef<Arc<Data>, In, Out> {
+        ClosureRef::new(Arc::clone(&self.data), fun)
+    }
+
+    /// Defines a `ClosureOptRef<Arc<Data>, In, Out>` sharing the data of the hub and defining `In -> Option<&Out>` transformation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let hub = CaptureHub::new(vec![1, 2, 3]);
+    /// let get = hub.fun_option_ref(|v, i: usize| v.get(i));
+    ///
+    /// assert_eq!(Some(&3), get.call(2));
+    /// assert_eq!(None, get.call(3));
+    /// ```
+    pub fn fun_option_ref<In, Out: ?Sized>(
+        &self,
+        fun: fn(&Arc<Data>, In) -> Option<&Out>,
+    ) -> ClosureOptRef<Arc<Data>, In, Out> {
+        ClosureOptRef::new(Arc::clone(&self.data), fun)
+    }
+
+    /// Defines a `ClosureResRef<Arc<Data>, In, Out, Error>` sharing the data of the hub and defining `In -> Result<&Out, Error>` transformation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let hub = CaptureHub::new(vec![1, 2, 3]);
+    /// let get = hub.fun_result_ref(|v, i: usize| v.get(i).ok_or("out of bounds"));
+    ///
+    /// assert_eq!(Ok(&1), get.call(0));
+    /// assert_eq!(Err("out of bounds"), get.call(3));
+    /// ```
+    pub fn fun_result_ref<In, Out: ?Sized, Error>(
+        &self,
+        fun: fn(&Arc<Data>, In) -> Result<&Out, Error>,
+    ) -> ClosureResRef<Arc<Data>, In, Out, Error> {
+        ClosureResRef::new(Arc::clone(&self.data), fun)
+    }
+}
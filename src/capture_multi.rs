@@ -0,0 +1,264 @@
+use crate::{Closure, ClosureOptRef, ClosureRef, ClosureResRef};
+
+/// A utility wrapper which captures two separate pieces of data and allows methods to define desired closures,
+/// where the function receives each captured piece as a separate parameter.
+///
+/// It can be created directly as `Capture2(a, b)` or by chaining `Capture(a).and(b)`.
+///
+/// The created closures capture the pieces together with the function, such as `Closure<(A, B, fn(&A, &B, In) -> Out), In, Out>`;
+/// hence, the pieces need not be packed into a tuple and destructured within the function.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let fun = Capture2(vec![10, 20], vec![1, 2]).fun(|weights, offsets, i: usize| weights[i] + offsets[i]);
+/// assert_eq!(22, fun.call(1));
+///
+/// let fun = Capture(vec![10, 20]).and(vec![1, 2]).fun(|weights, offsets, i: usize| weights[i] + offsets[i]);
+/// assert_eq!(22, fun.call(1));
+/// ```
+pub struct Capture2<A, B>(pub A, pub B);
+
+impl<A, B> Capture2<A, B> {
+    /// Defines a `Closure<(A, B, fn(&A, &B, In) -> Out), In, Out>` capturing the pieces and defining `In -> Out` transformation.
+    ///
+    /// Consumes the `Capture2` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture2(vec![10, 20], vec![1, 2]).fun(|weights, offsets, i: usize| weights[i] + offsets[i]);
+    /// assert_eq!(22, fun.call(1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun<In, Out>(
+        self,
+        fun: fn(&A, &B, In) -> Out,
+    ) -> Closure<(A, B, fn(&A, &B, In) -> Out), In, Out> {
+        Closure::new((self.0, self.1, fun), |(a, b, fun), input| fun(a, b, input))
+    }
+
+    /// Defines a `ClosureRef<(A, B, for<'a> fn(&'a A, &'a B, In) -> &'a Out), In, Out>` capturing the pieces and defining `In -> &Out` transformation.
+    ///
+    /// Consumes the `Capture2` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture2(vec![String::from("john"), String::from("doe")], vec![1, 0]).fun_ref(|names, order, i: usize| names[order[i]].as_str());
+    /// assert_eq!("doe", fun.call(0));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_ref<In, Out: ?Sized>(
+        self,
+        fun: for<'a> fn(&'a A, &'a B, In) -> &'a Out,
+    ) -> ClosureRef<(A, B, for<'a> fn(&'a A, &'a B, In) -> &'a Out), In, Out> {
+        ClosureRef::new((self.0, self.1, fun), |(a, b, fun), input| fun(a, b, input))
+    }
+
+    /// Defines a `ClosureOptRef<(A, B, for<'a> fn(&'a A, &'a B, In) -> Option<&'a Out>), In, Out>` capturing the pieces and defining `In -> Option<&Out>` transformation.
+    ///
+    /// Consumes the `Capture2` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture2(vec![10, 20], vec![1, 0]).fun_option_ref(|values, order, i: usize| order.get(i).and_then(|&j| values.get(j)));
+    /// assert_eq!(Some(&20), fun.call(0));
+    /// assert_eq!(None, fun.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_option_ref<In, Out: ?Sized>(
+        self,
+        fun: for<'a> fn(&'a A, &'a B, In) -> Option<&'a Out>,
+    ) -> ClosureOptRef<(A, B, for<'a> fn(&'a A, &'a B, In) -> Option<&'a Out>), In, Out> {
+        ClosureOptRef::new((self.0, self.1, fun), |(a, b, fun), input| fun(a, b, input))
+    }
+
+    /// Defines a `ClosureResRef<(A, B, for<'a> fn(&'a A, &'a B, In) -> Result<&'a Out, Error>), In, Out, Error>` capturing the pieces and defining `In -> Result<&Out, Error>` transformation.
+    ///
+    /// Consumes the `Capture2` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture2(vec![10, 20], vec![1, 0]).fun_result_ref(|values, order, i: usize| order.get(i).map(|&j| &values[j]).ok_or("unknown"));
+    /// assert_eq!(Ok(&10), fun.call(1));
+    /// assert_eq!(Err("unknown"), fun.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_result_ref<In, Out: ?Sized, Error>(
+        self,
+        fun: for<'a> fn(&'a A, &'a B, In) -> Result<&'a Out, Error>,
+    ) -> ClosureResRef<(A, B, for<'a> fn(&'a A, &'a B, In) -> Result<&'a Out, Error>), In, Out, Error>
+    {
+        ClosureResRef::new((self.0, self.1, fun), |(a, b, fun), input| fun(a, b, input))
+    }
+
+    /// Consumes the `Capture2` and returns back the captured pieces as a tuple.
+    pub fn into_captured_data(self) -> (A, B) {
+        (self.0, self.1)
+    }
+
+    /// Adds a third piece `c` to the captured data, creating a `Capture3`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture2(2, 3).and(4).fun(|a, b, c, x: i32| a * x * x + b * x + c);
+    /// assert_eq!(9, fun.call(1));
+    /// ```
+    pub fn and<C>(self, c: C) -> Capture3<A, B, C> {
+        Capture3(self.0, self.1, c)
+    }
+}
+
+/// A utility wrapper which captures three separate pieces of data and allows methods to define desired closures,
+/// where the function receives each captured piece as a separate parameter.
+///
+/// It can be created directly as `Capture3(a, b, c)` or by chaining `Capture(a).and(b).and(c)`.
+///
+/// The created closures capture the pieces together with the function, such as `Closure<(A, B, C, fn(&A, &B, &C, In) -> Out), In, Out>`;
+/// hence, the pieces need not be packed into a tuple and destructured within the function.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let fun = Capture3(vec![10, 20], vec![1, 2], 100).fun(|weights, offsets, scale, i: usize| scale * weights[i] + offsets[i]);
+/// assert_eq!(2002, fun.call(1));
+///
+/// let fun = Capture(vec![10, 20]).and(vec![1, 2]).and(100).fun(|weights, offsets, scale, i: usize| scale * weights[i] + offsets[i]);
+/// assert_eq!(2002, fun.call(1));
+/// ```
+pub struct Capture3<A, B, C>(pub A, pub B, pub C);
+
+impl<A, B, C> Capture3<A, B, C> {
+    /// Defines a `Closure<(A, B, C, fn(&A, &B, &C, In) -> Out), In, Out>` capturing the pieces and defining `In -> Out` transformation.
+    ///
+    /// Consumes the `Capture3` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture3(vec![10, 20], vec![1, 2], 100).fun(|weights, offsets, scale, i: usize| scale * weights[i] + offsets[i]);
+    /// assert_eq!(2002, fun.call(1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun<In, Out>(
+        self,
+        fun: fn(&A, &B, &C, In) -> Out,
+    ) -> Closure<(A, B, C, fn(&A, &B, &C, In) -> Out), In, Out> {
+        Closure::new((self.0, self.1, self.2, fun), |(a, b, c, fun), input| {
+            fun(a, b, c, input)
+        })
+    }
+
+    /// Defines a `ClosureRef<(A, B, C, for<'a> fn(&'a A, &'a B, &'a C, In) -> &'a Out), In, Out>` capturing the pieces and defining `In -> &Out` transformation.
+    ///
+    /// Consumes the `Capture3` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture3(vec![String::from("john"), String::from("doe")], vec![1, 0], String::from("?")).fun_ref(|names, order, unknown, i: usize| order.get(i).map(|&j| names[j].as_str()).unwrap_or(unknown));
+    /// assert_eq!("doe", fun.call(0));
+    /// assert_eq!("?", fun.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_ref<In, Out: ?Sized>(
+        self,
+        fun: for<'a> fn(&'a A, &'a B, &'a C, In) -> &'a Out,
+    ) -> ClosureRef<(A, B, C, for<'a> fn(&'a A, &'a B, &'a C, In) -> &'a Out), In, Out> {
+        ClosureRef::new((self.0, self.1, self.2, fun), |(a, b, c, fun), input| {
+            fun(a, b, c, input)
+        })
+    }
+
+    /// Defines a `ClosureOptRef<(A, B, C, for<'a> fn(&'a A, &'a B, &'a C, In) -> Option<&'a Out>), In, Out>` capturing the pieces and defining `In -> Option<&Out>` transformation.
+    ///
+    /// Consumes the `Capture3` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture3(vec![10, 20], vec![1, 0], 1).fun_option_ref(|values, order, shift, i: usize| order.get(i + shift).and_then(|&j| values.get(j)));
+    /// assert_eq!(Some(&10), fun.call(0));
+    /// assert_eq!(None, fun.call(1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_option_ref<In, Out: ?Sized>(
+        self,
+        fun: for<'a> fn(&'a A, &'a B, &'a C, In) -> Option<&'a Out>,
+    ) -> ClosureOptRef<
+        (
+            A,
+            B,
+            C,
+            for<'a> fn(&'a A, &'a B, &'a C, In) -> Option<&'a Out>,
+        ),
+        In,
+        Out,
+    > {
+        ClosureOptRef::new((self.0, self.1, self.2, fun), |(a, b, c, fun), input| {
+            fun(a, b, c, input)
+        })
+    }
+
+    /// Defines a `ClosureResRef<(A, B, C, for<'a> fn(&'a A, &'a B, &'a C, In) -> Result<&'a Out, Error>), In, Out, Error>` capturing the pieces and defining `In -> Result<&Out, Error>` transformation.
+    ///
+    /// Consumes the `Capture3` and moves the captured pieces inside the created closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture3(vec![10, 20], vec![1, 0], "unknown").fun_result_ref(|values, order, err, i: usize| order.get(i).map(|&j| &values[j]).ok_or(*err));
+    /// assert_eq!(Ok(&10), fun.call(1));
+    /// assert_eq!(Err("unknown"), fun.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fun_result_ref<In, Out: ?Sized, Error>(
+        self,
+        fun: for<'a> fn(&'a A, &'a B, &'a C, In) -> Result<&'a Out, Error>,
+    ) -> ClosureResRef<
+        (
+            A,
+            B,
+            C,
+            for<'a> fn(&'a A, &'a B, &'a C, In) -> Result<&'a Out, Error>,
+        ),
+        In,
+        Out,
+        Error,
+    > {
+        ClosureResRef::new((self.0, self.1, self.2, fun), |(a, b, c, fun), input| {
+            fun(a, b, c, input)
+        })
+    }
+
+    /// Consumes the `Capture3` and returns back the captured pieces as a tuple.
+    pub fn into_captured_data(self) -> (A, B, C) {
+        (self.0, self.1, self.2)
+    }
+}
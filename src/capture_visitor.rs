@@ -0,0 +1,49 @@
+/// A visitor applied on the captured data of a closure union, such as `ClosureOneOf2` or `ClosureRefOneOf3`,
+/// without the caller writing the match over the variants.
+///
+/// A visitor to be used by a union over captures `C1`, `C2`, ... must implement `CaptureVisitor<C1>`, `CaptureVisitor<C2>`, ...
+/// with a common `Output` type.
+/// The common case of a visitor applying the same logic on all captures is conveniently handled by a single blanket implementation,
+/// such as `impl<C: Debug> CaptureVisitor<C> for MyVisitor`.
+/// On the other hand, captures requiring special treatment can be handled by separate implementations for each capture type.
+///
+/// Visitors are called by the `for_each_capture` method of the closure unions.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::fmt::Debug;
+///
+/// // generic pass collecting the debug representation of captures
+/// #[derive(Default)]
+/// struct Describe(Vec<String>);
+///
+/// impl<C: Debug> CaptureVisitor<C> for Describe {
+///     type Output = usize;
+///     fn visit(&mut self, capture: &C) -> usize {
+///         self.0.push(format!("{:?}", capture));
+///         self.0.len()
+///     }
+/// }
+///
+/// type Weight = ClosureOneOf2<i32, Vec<i32>, usize, i32>;
+///
+/// let weights: Vec<Weight> = vec![
+///     Capture(7).fun(|w, _: usize| *w).into_oneof2_var1(),
+///     Capture(vec![1, 2]).fun(|w, i: usize| w[i]).into_oneof2_var2(),
+/// ];
+///
+/// let mut describe = Describe::default();
+/// for w in &weights {
+///     w.for_each_capture(&mut describe);
+/// }
+/// assert_eq!(vec!["7", "[1, 2]"], describe.0);
+/// ```
+pub trait CaptureVisitor<Capture: ?Sized> {
+    /// Output of the visit.
+    type Output;
+
+    /// Visits the given `capture`.
+    fn visit(&mut self, capture: &Capture) -> Self::Output;
+}
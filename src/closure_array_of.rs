@@ -0,0 +1,173 @@
+use crate::fun::Fun;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the functions, where all functions share the same captured data; and hence, having three components:
+///
+/// * `Capture` is any captured data,
+/// * `[fn(&Capture, In) -> Out; N]` is the array of alternative transformations,
+/// * `selected` is the index of the transformation which is currently in use.
+///
+/// It represents the transformation `In -> Out` defined by the selected function.
+///
+/// This is useful when there exist alternative functions over the same data, such as `N` different scoring formulas.
+/// Unlike the closure unions, such as `ClosureOneOf3<C, C, C, In, Out>`, the capture type is not duplicated in the type signature and the selected function can be changed without moving the captured data.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureArrayOf` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// struct Edge {
+///     length: f64,
+///     duration: f64,
+/// }
+/// let edges = vec![
+///     Edge { length: 10.0, duration: 3.0 },
+///     Edge { length: 4.0, duration: 5.0 },
+/// ];
+///
+/// // cost: ClosureArrayOf<3, Vec<Edge>, usize, f64>
+/// let mut cost = Capture(edges)
+///     .fun_array_of(
+///         [
+///             |e, i: usize| e[i].length,
+///             |e, i: usize| e[i].duration,
+///             |e, i: usize| e[i].length + 2.0 * e[i].duration,
+///         ],
+///         0,
+///     )
+///     .expect("0 is a valid selection among 3 functions");
+///
+/// assert_eq!(10.0, cost.call(0));
+///
+/// cost.select(1);
+/// assert_eq!(3.0, cost.call(0));
+///
+/// cost.select(2);
+/// assert_eq!(14.0, cost.call(1));
+/// ```
+#[derive(Clone)]
+pub struct ClosureArrayOf<const N: usize, Capture, In, Out> {
+    capture: Capture,
+    funs: [fn(&Capture, In) -> Out; N],
+    selected: usize,
+}
+
+impl<const N: usize, Capture: Debug, In, Out> Debug for ClosureArrayOf<N, Capture, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureArrayOf")
+            .field("capture", &self.capture)
+            .field("selected", &self.selected)
+            .finish()
+    }
+}
+
+impl<const N: usize, Capture, In, Out> ClosureArrayOf<N, Capture, In, Out> {
+    pub(super) fn new(
+        capture: Capture,
+        funs: [fn(&Capture, In) -> Out; N],
+        selected: usize,
+    ) -> Option<Self> {
+        match selected < N {
+            true => Some(Self {
+                capture,
+                funs,
+                selected,
+            }),
+            false => None,
+        }
+    }
+
+    /// Calls the selected function of the closure with the given `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let base = 3;
+    /// let fun = Capture(base)
+    ///     .fun_array_of([|b, x: i32| x * b, |b, x: i32| x + b], 1)
+    ///     .expect("valid selection");
+    ///
+    /// assert_eq!(10, fun.call(7));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input: In) -> Out {
+        (self.funs[self.selected])(&self.capture, input)
+    }
+
+    /// Returns the index of the selected function.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the function at the given `index` to be used in the subsequent calls and returns the previously selected index.
+    ///
+    /// Returns `None` and keeps the current selection if the `index` is out of bounds; i.e., `index >= N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let mut name = Capture(names)
+    ///     .fun_array_of([|n, i: usize| n[i].clone(), |n, i: usize| n[i].to_uppercase()], 0)
+    ///     .expect("valid selection");
+    ///
+    /// assert_eq!("doe", name.call(1));
+    ///
+    /// assert_eq!(Some(0), name.select(1));
+    /// assert_eq!("DOE", name.call(1));
+    ///
+    /// assert_eq!(None, name.select(2));
+    /// assert_eq!(1, name.selected());
+    /// ```
+    pub fn select(&mut self, index: usize) -> Option<usize> {
+        match index < N {
+            true => Some(std::mem::replace(&mut self.selected, index)),
+            false => None,
+        }
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let fun = Capture(2)
+    ///     .fun_array_of([|b, x: i32| x * b, |b, x: i32| x + b], 0)
+    ///     .expect("valid selection");
+    ///
+    /// let doubled: Vec<_> = [1, 2, 3].into_iter().map(fun.as_fn()).collect();
+    /// assert_eq!(vec![2, 4, 6], doubled);
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(In) -> Out + '_ {
+        move |x| self.call(x)
+    }
+}
+
+impl<const N: usize, Capture, In, Out> Fun<In, Out> for ClosureArrayOf<N, Capture, In, Out> {
+    fn call(&self, input: In) -> Out {
+        ClosureArrayOf::call(self, input)
+    }
+}
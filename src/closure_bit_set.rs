@@ -0,0 +1,199 @@
+use crate::closure_val::Closure;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A dense `num_rows x num_cols` boolean matrix packing each row into 64-bit words.
+///
+/// It is the captured data of [`ClosureBitSet`], a cache friendly and compact alternative to capturing a `Vec<HashSet<usize>>`
+/// to represent relations such as precedence or adjacency among `usize` indexed nodes.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::BitMatrix;
+///
+/// let mut matrix = BitMatrix::new(3, 100);
+/// assert!(matrix.insert(1, 99));
+/// assert!(!matrix.insert(1, 99));
+///
+/// assert!(matrix.contains(1, 99));
+/// assert!(!matrix.contains(0, 99));
+/// assert!(!matrix.contains(42, 42)); // out of bounds
+/// assert_eq!(1, matrix.count_ones());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitMatrix {
+    num_rows: usize,
+    num_cols: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitMatrix {
+    /// Creates a `num_rows x num_cols` matrix with all bits unset.
+    pub fn new(num_rows: usize, num_cols: usize) -> Self {
+        let words_per_row = num_cols.div_ceil(BITS);
+        Self {
+            num_rows,
+            num_cols,
+            words_per_row,
+            words: vec![0; num_rows * words_per_row],
+        }
+    }
+
+    /// Creates a square `n x n` matrix with the bits of the given `pairs` set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the pairs is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::BitMatrix;
+    ///
+    /// let matrix = BitMatrix::from_pairs(4, [(0, 1), (2, 3)]);
+    ///
+    /// assert!(matrix.contains(0, 1));
+    /// assert!(!matrix.contains(1, 0));
+    /// assert_eq!((4, 4), matrix.dimensions());
+    /// ```
+    pub fn from_pairs(n: usize, pairs: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut matrix = Self::new(n, n);
+        for (i, j) in pairs {
+            matrix.insert(i, j);
+        }
+        matrix
+    }
+
+    /// Returns the number of rows and columns of the matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// Sets the bit at (`i`, `j`); returns whether or not the bit was previously unset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if (`i`, `j`) is out of bounds.
+    pub fn insert(&mut self, i: usize, j: usize) -> bool {
+        let (word, mask) = self.position(i, j);
+        let was_unset = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        was_unset
+    }
+
+    /// Unsets the bit at (`i`, `j`); returns whether or not the bit was previously set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if (`i`, `j`) is out of bounds.
+    pub fn remove(&mut self, i: usize, j: usize) -> bool {
+        let (word, mask) = self.position(i, j);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    /// Returns whether or not the bit at (`i`, `j`) is set; returns false if (`i`, `j`) is out of bounds.
+    #[inline(always)]
+    pub fn contains(&self, i: usize, j: usize) -> bool {
+        match i < self.num_rows && j < self.num_cols {
+            true => {
+                let (word, mask) = self.position(i, j);
+                self.words[word] & mask != 0
+            }
+            false => false,
+        }
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    #[inline(always)]
+    fn position(&self, i: usize, j: usize) -> (usize, u64) {
+        assert!(
+            i < self.num_rows && j < self.num_cols,
+            "({}, {}) is out of bounds of the {}x{} bit matrix",
+            i,
+            j,
+            self.num_rows,
+            self.num_cols
+        );
+        (i * self.words_per_row + j / BITS, 1 << (j % BITS))
+    }
+}
+
+/// A predicate closure capturing a [`BitMatrix`] and returning whether or not the bit of the given `(i, j)` pair is set.
+///
+/// Since it is nothing but a `Closure<BitMatrix, (usize, usize), bool>`, it can be joined into the existing predicate unions
+/// such as `ClosureOneOf2<BitMatrix, C2, (usize, usize), bool>` together with closures capturing other data.
+///
+/// Each call is a constant time bit lookup, which is often faster and always more compact than capturing a `Vec<HashSet<usize>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::collections::HashSet;
+///
+/// // precedence relations among 4 tasks
+/// let precedes = ClosureBitSet::from_pairs(4, [(0, 1), (0, 2), (2, 3)]);
+///
+/// assert!(precedes.call((0, 2)));
+/// assert!(!precedes.call((2, 0)));
+///
+/// // joined into a union with a hash set based predicate
+/// type Precedes = ClosureOneOf2<BitMatrix, Vec<HashSet<usize>>, (usize, usize), bool>;
+///
+/// let packed: Precedes = precedes.into_oneof2_var1();
+/// let hashed: Precedes = Capture(vec![HashSet::from([1]), HashSet::new()])
+///     .fun(|sets, (i, j): (usize, usize)| sets.get(i).map(|s| s.contains(&j)).unwrap_or(false))
+///     .into_oneof2_var2();
+///
+/// assert_eq!(packed.call((0, 1)), hashed.call((0, 1)));
+/// ```
+pub type ClosureBitSet = Closure<BitMatrix, (usize, usize), bool>;
+
+impl Closure<BitMatrix, (usize, usize), bool> {
+    /// Creates a predicate closure over `n x n` pairs which returns true only for the given `pairs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the pairs is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let is_edge = ClosureBitSet::from_pairs(3, [(0, 1), (1, 2)]);
+    ///
+    /// assert!(is_edge.call((1, 2)));
+    /// assert!(!is_edge.call((2, 1)));
+    /// assert!(!is_edge.call((7, 7)));
+    /// ```
+    pub fn from_pairs(n: usize, pairs: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        Self::from_bit_matrix(BitMatrix::from_pairs(n, pairs))
+    }
+
+    /// Creates a predicate closure over the pairs of the given bit `matrix`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let mut matrix = BitMatrix::new(2, 3);
+    /// matrix.insert(1, 2);
+    ///
+    /// let is_set = ClosureBitSet::from_bit_matrix(matrix);
+    /// assert!(is_set.call((1, 2)));
+    /// assert!(!is_set.call((2, 1)));
+    /// ```
+    pub fn from_bit_matrix(matrix: BitMatrix) -> Self {
+        Closure::new(matrix, |m, (i, j)| m.contains(i, j))
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    call_value::CallValue, closure_ref::ClosureRef, closure_val::Closure, ClosureOneOf2,
+    ClosureOneOf3, ClosureOneOf4, ClosureRefOneOf2, ClosureRefOneOf3, ClosureRefOneOf4, OneOf2,
+};
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In) -> CallValue<Out>` is the transformation.
+///
+/// It represents the transformation `In -> CallValue<Out>`, where the output is either borrowed from the captured data or computed and owned.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureCallValue` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **This closure variant is useful when some outputs can be lent from the captured data while others must be computed; it avoids cloning the lent outputs.**
+///
+/// A closure lending references and a closure computing values can be unified in one type by converting a `OneOf2<ClosureRef<C1, In, Out>, Closure<C2, In, Out>>` into a `ClosureCallValue` with `From`.
+/// Similarly, the closure unions can be converted by `into_call_value`, such as `ClosureRefOneOf2::into_call_value` and `ClosureOneOf2::into_call_value`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// type Dense = Vec<Vec<String>>;
+/// type Computed = fn(usize, usize) -> String;
+///
+/// // label_of: ClosureCallValue<OneOf2<Dense, Computed>, (usize, usize), String>
+/// let dense: Dense = vec![vec!["a".to_string(), "b".to_string()]];
+/// let labels: OneOf2<Dense, Computed> = OneOf2::Variant1(dense);
+/// let label_of = Capture(labels).fun_call_value(|c, (i, j): (usize, usize)| match c {
+///     OneOf2::Variant1(dense) => CallValue::Borrowed(&dense[i][j]),
+///     OneOf2::Variant2(computed) => CallValue::Owned(computed(i, j)),
+/// });
+/// assert_eq!("b", label_of.call((0, 1)).as_str());
+/// assert!(label_of.call((0, 1)).is_borrowed());
+///
+/// let computed: Computed = |i, j| format!("{}-{}", i, j);
+/// let labels: OneOf2<Dense, Computed> = OneOf2::Variant2(computed);
+/// let label_of = Capture(labels).fun_call_value(|c, (i, j): (usize, usize)| match c {
+///     OneOf2::Variant1(dense) => CallValue::Borrowed(&dense[i][j]),
+///     OneOf2::Variant2(computed) => CallValue::Owned(computed(i, j)),
+/// });
+/// assert_eq!("0-1", label_of.call((0, 1)).as_str());
+/// assert!(label_of.call((0, 1)).is_owned());
+/// ```
+#[derive(Clone)]
+pub struct ClosureCallValue<Capture, In, Out> {
+    capture: Capture,
+    fun: fn(&Capture, In) -> CallValue<'_, Out>,
+}
+
+impl<Capture: Debug, In, Out> Debug for ClosureCallValue<Capture, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureCallValue")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, Out> ClosureCallValue<Capture, In, Out> {
+    pub(super) fn new(capture: Capture, fun: fn(&Capture, In) -> CallValue<'_, Out>) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let cache = vec![0, 1, 1, 2, 3, 5];
+    /// let fib = Capture(cache).fun_call_value(|c, n: usize| match c.get(n) {
+    ///     Some(x) => CallValue::Borrowed(x),
+    ///     None => CallValue::Owned((c.len()..=n).fold((c[c.len() - 2], c[c.len() - 1]), |(a, b), _| (b, a + b)).1),
+    /// });
+    ///
+    /// assert_eq!(5, *fib.call(5));
+    /// assert_eq!(8, *fib.call(6));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input: In) -> CallValue<'_, Out> {
+        (self.fun)(&self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(In) -> CallValue<Out>` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string()];
+    /// let name_of = Capture(names).fun_call_value(|n, i: usize| match n.get(i) {
+    ///     Some(x) => CallValue::Borrowed(x),
+    ///     None => CallValue::Owned(format!("guest-{}", i)),
+    /// });
+    ///
+    /// let names: Vec<_> = (0..2).map(name_of.as_fn()).collect();
+    /// assert_eq!(vec!["john", "guest-1"], names.iter().map(|x| x.as_str()).collect::<Vec<_>>());
+    /// ```
+    pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> CallValue<'a, Out> {
+        move |x| self.call(x)
+    }
+}
+
+impl<Capture, In, Out> Closure<Capture, In, Out> {
+    /// Transforms the closure into a `ClosureCallValue` which returns the computed outputs as `CallValue::Owned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let square = Capture(()).fun(|_, x: i32| x * x).into_call_value();
+    /// assert_eq!(CallValue::Owned(9), square.call(3));
+    /// ```
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Owned(fun.call(input)))
+    }
+}
+
+impl<Capture, In, Out> ClosureRef<Capture, In, Out> {
+    /// Transforms the closure into a `ClosureCallValue` which lends the outputs as `CallValue::Borrowed`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let name_of = Capture(vec!["john".to_string()]).fun_ref(|n, i: usize| &n[i]).into_call_value();
+    /// assert!(name_of.call(0).is_borrowed());
+    /// assert_eq!("john", name_of.call(0).as_str());
+    /// ```
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Borrowed(fun.call(input)))
+    }
+}
+
+impl<C1, C2, In, Out> From<OneOf2<ClosureRef<C1, In, Out>, Closure<C2, In, Out>>>
+    for ClosureCallValue<OneOf2<ClosureRef<C1, In, Out>, Closure<C2, In, Out>>, In, Out>
+{
+    /// Unifies a closure lending references and a closure computing values in a single `ClosureCallValue` type;
+    /// the first variant lends its outputs as `CallValue::Borrowed` while the second returns them as `CallValue::Owned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// type Dense = Vec<Vec<String>>;
+    /// type In = (usize, usize);
+    /// type Label = ClosureCallValue<OneOf2<ClosureRef<Dense, In, String>, Closure<(), In, String>>, In, String>;
+    ///
+    /// let dense = vec![vec!["a".to_string(), "b".to_string()]];
+    /// let label: Label = OneOf2::Variant1(Capture(dense).fun_ref(|d, (i, j): In| &d[i][j])).into();
+    /// assert_eq!("b", label.call((0, 1)).as_str());
+    /// assert!(label.call((0, 1)).is_borrowed());
+    ///
+    /// let label: Label = OneOf2::Variant2(Capture(()).fun(|_, (i, j): In| format!("{}-{}", i, j))).into();
+    /// assert_eq!("0-1", label.call((0, 1)).as_str());
+    /// assert!(label.call((0, 1)).is_owned());
+    /// ```
+    fn from(closure: OneOf2<ClosureRef<C1, In, Out>, Closure<C2, In, Out>>) -> Self {
+        ClosureCallValue::new(closure, |closure, input| match closure {
+            OneOf2::Variant1(lend) => CallValue::Borrowed(lend.call(input)),
+            OneOf2::Variant2(compute) => CallValue::Owned(compute.call(input)),
+        })
+    }
+}
+
+impl<C1, C2, In, Out> ClosureOneOf2<C1, C2, In, Out> {
+    /// Transforms the closure union into a `ClosureCallValue` which returns the computed outputs as `CallValue::Owned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let label: ClosureOneOf2<String, (), usize, String> =
+    ///     Capture(String::from("node")).fun(|p, i: usize| format!("{}-{}", p, i)).into_oneof2_var1();
+    ///
+    /// let label = label.into_call_value();
+    /// assert!(label.call(3).is_owned());
+    /// assert_eq!("node-3", label.call(3).as_str());
+    /// ```
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Owned(fun.call(input)))
+    }
+}
+
+impl<C1, C2, In, Out> ClosureRefOneOf2<C1, C2, In, Out> {
+    /// Transforms the closure union into a `ClosureCallValue` which lends the outputs as `CallValue::Borrowed`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let name: ClosureRefOneOf2<Vec<String>, String, usize, String> =
+    ///     Capture(vec![String::from("john")]).fun_ref(|n, i: usize| &n[i]).into_oneof2_var1();
+    ///
+    /// let name = name.into_call_value();
+    /// assert!(name.call(0).is_borrowed());
+    /// assert_eq!("john", name.call(0).as_str());
+    /// ```
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Borrowed(fun.call(input)))
+    }
+}
+
+impl<C1, C2, C3, In, Out> ClosureOneOf3<C1, C2, C3, In, Out> {
+    /// Transforms the closure union into a `ClosureCallValue` which returns the computed outputs as `CallValue::Owned`.
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Owned(fun.call(input)))
+    }
+}
+
+impl<C1, C2, C3, In, Out> ClosureRefOneOf3<C1, C2, C3, In, Out> {
+    /// Transforms the closure union into a `ClosureCallValue` which lends the outputs as `CallValue::Borrowed`.
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Borrowed(fun.call(input)))
+    }
+}
+
+impl<C1, C2, C3, C4, In, Out> ClosureOneOf4<C1, C2, C3, C4, In, Out> {
+    /// Transforms the closure union into a `ClosureCallValue` which returns the computed outputs as `CallValue::Owned`.
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Owned(fun.call(input)))
+    }
+}
+
+impl<C1, C2, C3, C4, In, Out> ClosureRefOneOf4<C1, C2, C3, C4, In, Out> {
+    /// Transforms the closure union into a `ClosureCallValue` which lends the outputs as `CallValue::Borrowed`.
+    pub fn into_call_value(self) -> ClosureCallValue<Self, In, Out> {
+        ClosureCallValue::new(self, |fun, input| CallValue::Borrowed(fun.call(input)))
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{fun::Fun, Closure};
+use std::{fmt::Debug, ops::Shr};
+
+/// Closure chaining two closures, and hence, owning both of their captures:
+///
+/// * `Closure<C1, In, Mid>` is the first stage,
+/// * `Closure<C2, Mid, Out>` is the second stage.
+///
+/// It represents the transformation `In -> Out` obtained by calling the second stage with the output of the first stage.
+///
+/// It is created by [`Closure::then`], and allows to build transformation pipelines keeping all captures explicit all the way through.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureChain` auto-implements `Clone` given that both captured data are cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let names = vec!["john", "doe"];
+/// let name_of = Capture(names).fun(|n, i: usize| n[i]);
+///
+/// let greeting = String::from("hello");
+/// let greet = Capture(greeting).fun(|g, name: &str| format!("{} {}", g, name));
+///
+/// // greet_id: ClosureChain<Vec<&str>, String, usize, &str, String>
+/// let greet_id = name_of.then(greet);
+///
+/// assert_eq!("hello doe", greet_id.call(1));
+/// ```
+#[derive(Clone)]
+pub struct ClosureChain<C1, C2, In, Mid, Out> {
+    first: Closure<C1, In, Mid>,
+    second: Closure<C2, Mid, Out>,
+}
+
+impl<C1: Debug, C2: Debug, In, Mid, Out> Debug for ClosureChain<C1, C2, In, Mid, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureChain")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+impl<C1, C2, In, Mid, Out> ClosureChain<C1, C2, In, Mid, Out> {
+    pub(crate) fn new(first: Closure<C1, In, Mid>, second: Closure<C2, Mid, Out>) -> Self {
+        Self { first, second }
+    }
+
+    /// Calls the first stage with the given `input`, and the second stage with its output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let celsius = Capture(vec![20.0, 30.0]).fun(|t, i: usize| t[i]);
+    /// let to_fahrenheit = Capture(32.0).fun(|offset, c: f64| c * 1.8 + offset);
+    ///
+    /// let fahrenheit = celsius.then(to_fahrenheit);
+    /// assert_eq!(86.0, fahrenheit.call(1));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input: In) -> Out {
+        self.second.call(self.first.call(input))
+    }
+
+    /// Returns references to the captured data of the first and second stages.
+    pub fn captured_data(&self) -> (&C1, &C2) {
+        (self.first.captured_data(), self.second.captured_data())
+    }
+
+    /// Consumes the chain and returns back the captured data of the first and second stages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let chain = Capture(2).fun(|k, x: i32| k * x).then(Capture(1).fun(|b, x: i32| x + b));
+    /// assert_eq!(7, chain.call(3));
+    ///
+    /// assert_eq!((2, 1), chain.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> (C1, C2) {
+        (
+            self.first.into_captured_data(),
+            self.second.into_captured_data(),
+        )
+    }
+
+    /// Consumes the chain and returns back the first and second stages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let chain = Capture(2).fun(|k, x: i32| k * x).then(Capture(1).fun(|b, x: i32| x + b));
+    ///
+    /// let (double, increment) = chain.into_stages();
+    /// assert_eq!(6, double.call(3));
+    /// assert_eq!(4, increment.call(3));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn into_stages(self) -> (Closure<C1, In, Mid>, Closure<C2, Mid, Out>) {
+        (self.first, self.second)
+    }
+
+    /// Chains this chain with the `next` closure into a longer chain owning all captures;
+    /// i.e., calling the resulting chain with `input` calls `next` with the output of this chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let parse = Capture(10).fun(|radix, s: &str| i64::from_str_radix(s, *radix).unwrap());
+    /// let scale = Capture(3).fun(|k, x: i64| k * x);
+    /// let label = Capture("x").fun(|unit, x: i64| format!("{}{}", x, unit));
+    ///
+    /// let chain = parse.then(scale).then(label);
+    /// assert_eq!("21x", chain.call("7"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn then<C3, Out2>(
+        self,
+        next: Closure<C3, Out, Out2>,
+    ) -> ClosureChain<Self, C3, In, Out, Out2> {
+        let first = Closure::new(self, |chain, input| chain.call(input));
+        ClosureChain::new(first, next)
+    }
+
+    /// Returns the chain as an `impl Fn(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let chain = Capture(2).fun(|k, x: i32| k * x).then(Capture(1).fun(|b, x: i32| x + b));
+    ///
+    /// let values: Vec<_> = (0..3).map(chain.as_fn()).collect();
+    /// assert_eq!(vec![1, 3, 5], values);
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(In) -> Out + '_ {
+        move |x| self.call(x)
+    }
+}
+
+impl<C1, C2, In, Mid, Out> Fun<In, Out> for ClosureChain<C1, C2, In, Mid, Out> {
+    fn call(&self, input: In) -> Out {
+        ClosureChain::call(self, input)
+    }
+}
+
+impl<C1, C2, In, Out, Out2> Shr<Closure<C2, Out, Out2>> for Closure<C1, In, Out> {
+    type Output = ClosureChain<C1, C2, In, Out, Out2>;
+
+    /// Chains the two closures, `a >> b` being equivalent to `a.then(b)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let name_of = Capture(vec!["john", "doe"]).fun(|n, i: usize| n[i]);
+    /// let greet = Capture("hello").fun(|g, name: &str| format!("{} {}", g, name));
+    /// let shout = Capture('!').fun(|e, s: String| format!("{}{}", s, e));
+    ///
+    /// let pipeline = name_of >> greet >> shout;
+    /// assert_eq!("hello doe!", pipeline.call(1));
+    /// ```
+    fn shr(self, next: Closure<C2, Out, Out2>) -> Self::Output {
+        self.then(next)
+    }
+}
+
+impl<C1, C2, C3, In, Mid, Out, Out2> Shr<Closure<C3, Out, Out2>>
+    for ClosureChain<C1, C2, In, Mid, Out>
+{
+    type Output = ClosureChain<Self, C3, In, Out, Out2>;
+
+    /// Chains the chain with the next closure, `a >> b` being equivalent to `a.then(b)`.
+    fn shr(self, next: Closure<C3, Out, Out2>) -> Self::Output {
+        self.then(next)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{closure_val::Closure, fun::Fun};
+
+/// A closure factory is a `Closure` which creates another `Closure` on each call:
+///
+/// * the outer closure captures `Capture` and maps `In` to the inner closure,
+/// * the inner closure captures `InnerCapture` and maps `InnerIn` to `Out`.
+///
+/// Since both closures own their captured data, the factory and the closures it creates can be stored without lifetime parameters.
+/// When the inner closures need to share the factory's data, the captured data can be an `Rc` or `Arc` which is cloned into the inner captures.
+///
+/// The two calls can be chained in one step with `flat_map_call`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::rc::Rc;
+///
+/// type Weights = Vec<Vec<i32>>;
+///
+/// // per scenario weight functions
+/// let scenarios: Vec<Rc<Weights>> = vec![
+///     Rc::new(vec![vec![0, 1], vec![2, 0]]),
+///     Rc::new(vec![vec![0, 10], vec![20, 0]]),
+/// ];
+///
+/// let weights_of: ClosureFactory<Vec<Rc<Weights>>, usize, Rc<Weights>, (usize, usize), i32> =
+///     Capture(scenarios).fun(|s, scenario: usize| {
+///         Capture(s[scenario].clone()).fun(|w, (i, j): (usize, usize)| w[i][j])
+///     });
+///
+/// let weight = weights_of.call(1);
+/// assert_eq!(20, weight.call((1, 0)));
+///
+/// assert_eq!(1, weights_of.flat_map_call(0, (0, 1)));
+/// ```
+pub type ClosureFactory<Capture, In, InnerCapture, InnerIn, Out> =
+    Closure<Capture, In, Closure<InnerCapture, InnerIn, Out>>;
+
+impl<Capture, In, Inner> Closure<Capture, In, Inner> {
+    /// Calls the closure with `input` to create the inner function, and immediately calls the inner function with `inner_input`.
+    ///
+    /// The inner function can be any `Fun<InnerIn, Out>` such as a `Closure`, a closure union or a `fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let factors = vec![1, 10, 100];
+    ///
+    /// let scale_by: ClosureFactory<Vec<i32>, usize, i32, i32, i32> =
+    ///     Capture(factors).fun(|f, i: usize| Capture(f[i]).fun(|f, x: i32| f * x));
+    ///
+    /// assert_eq!(7, scale_by.flat_map_call(0, 7));
+    /// assert_eq!(70, scale_by.flat_map_call(1, 7));
+    /// assert_eq!(700, scale_by.flat_map_call(2, 7));
+    ///
+    /// // inner functions may as well be unions
+    /// let scale_or_zero = Capture(vec![3]).fun(|f, i: usize| match f.get(i) {
+    ///     Some(f) => Capture(*f).fun(|f, x: i32| f * x).into_oneof2_var1(),
+    ///     None => Capture(()).fun(|_, _: i32| 0).into_oneof2_var2(),
+    /// });
+    ///
+    /// assert_eq!(21, scale_or_zero.flat_map_call(0, 7));
+    /// assert_eq!(0, scale_or_zero.flat_map_call(1, 7));
+    /// ```
+    pub fn flat_map_call<InnerIn, Out>(&self, input: In, inner_input: InnerIn) -> Out
+    where
+        Inner: Fun<InnerIn, Out>,
+    {
+        self.call(input).call(inner_input)
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the reducer function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, Acc, Item) -> Acc` is the reducer which folds an `Item` into the accumulated value `Acc`.
+///
+/// It represents the reduction `(Acc, Item) -> Acc` with access to the captured context, and can be driven over a stream of items by `fold`.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureFold` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let prices = vec![10, 25, 40];
+///
+/// // total_price: ClosureFold<Vec<i32>, (usize, i32), i32>
+/// let total_price = Capture(prices).fun_fold(|p, total, (item, count): (usize, i32)| total + p[item] * count);
+///
+/// let basket = [(0, 2), (2, 1)];
+/// assert_eq!(60, total_price.fold(0, basket));
+///
+/// // single step
+/// assert_eq!(85, total_price.call(60, (1, 1)));
+/// ```
+#[derive(Clone)]
+pub struct ClosureFold<Capture, Item, Acc> {
+    capture: Capture,
+    fun: fn(&Capture, Acc, Item) -> Acc,
+}
+
+impl<Capture: Debug, Item, Acc> Debug for ClosureFold<Capture, Item, Acc> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureFold")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, Item, Acc> ClosureFold<Capture, Item, Acc> {
+    pub(super) fn new(capture: Capture, fun: fn(&Capture, Acc, Item) -> Acc) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Folds the single `item` into the accumulated value `acc` and returns the new accumulated value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let separator = ", ".to_string();
+    /// let join = Capture(separator).fun_fold(|sep, mut acc: String, item: &str| {
+    ///     if !acc.is_empty() {
+    ///         acc.push_str(sep);
+    ///     }
+    ///     acc.push_str(item);
+    ///     acc
+    /// });
+    ///
+    /// let acc = join.call(String::new(), "a");
+    /// let acc = join.call(acc, "b");
+    /// assert_eq!("a, b", acc);
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, acc: Acc, item: Item) -> Acc {
+        (self.fun)(&self.capture, acc, item)
+    }
+
+    /// Folds all items of the `items` stream, starting from the `init`ial accumulated value, and returns the final accumulated value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let weights = vec![vec![0, 3, 7], vec![3, 0, 2], vec![7, 2, 0]];
+    ///
+    /// // length of a path given as a stream of edges
+    /// let path_length = Capture(weights).fun_fold(|w, len, (i, j): (usize, usize)| len + w[i][j]);
+    ///
+    /// let path = [0, 1, 2];
+    /// let edges = path.iter().zip(path.iter().skip(1)).map(|(i, j)| (*i, *j));
+    /// assert_eq!(5, path_length.fold(0, edges));
+    ///
+    /// assert_eq!(0, path_length.fold(0, std::iter::empty()));
+    /// ```
+    pub fn fold<Items>(&self, init: Acc, items: Items) -> Acc
+    where
+        Items: IntoIterator<Item = Item>,
+    {
+        items
+            .into_iter()
+            .fold(init, |acc, item| self.call(acc, item))
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(Acc, Item) -> Acc` struct, which can directly be passed to `Iterator::fold`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let modulo = 7;
+    /// let sum_mod = Capture(modulo).fun_fold(|m, acc: u32, x: u32| (acc + x) % m);
+    ///
+    /// let result = [3, 5, 6].into_iter().fold(0, sum_mod.as_fn());
+    /// assert_eq!(0, result);
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(Acc, Item) -> Acc + '_ {
+        move |acc, item| self.call(acc, item)
+    }
+}
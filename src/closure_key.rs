@@ -0,0 +1,159 @@
+use crate::fun::Fun;
+use std::{cmp::Ordering, fmt::Debug};
+
+/// Closure strictly separating the captured data from the key extraction function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, &T) -> K` is the key extraction.
+///
+/// It represents the transformation `&T -> K` for references of any lifetime, which makes it usable for sorting and comparisons.
+///
+/// Note that a `Closure<Capture, &'a T, K>` accepts only references with the particular lifetime `'a`; hence, it cannot be used in `sort_by` which compares short-lived borrows of the elements.
+/// `ClosureKey` does not have this limitation since its function is generic over the lifetime of the reference.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureKey` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// struct Task {
+///     name: &'static str,
+///     kind: usize,
+/// }
+///
+/// let priority_of_kind = vec![2, 0, 1];
+/// let priority = Capture(priority_of_kind).fun_key(|p, task: &Task| p[task.kind]);
+///
+/// let mut tasks = vec![
+///     Task { name: "deploy", kind: 0 },
+///     Task { name: "fix", kind: 1 },
+///     Task { name: "test", kind: 2 },
+/// ];
+/// sort_by_closure(&mut tasks, &priority);
+///
+/// let names: Vec<_> = tasks.iter().map(|t| t.name).collect();
+/// assert_eq!(vec!["fix", "test", "deploy"], names);
+/// ```
+#[derive(Clone)]
+pub struct ClosureKey<Capture, T: ?Sized, K> {
+    capture: Capture,
+    fun: fn(&Capture, &T) -> K,
+}
+
+impl<Capture: Debug, T: ?Sized, K> Debug for ClosureKey<Capture, T, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureKey")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, T: ?Sized, K> ClosureKey<Capture, T, K> {
+    pub(super) fn new(capture: Capture, fun: fn(&Capture, &T) -> K) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input` and returns its key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let reference = 10;
+    /// let distance = Capture(reference).fun_key(|r, x: &i32| (x - r).abs());
+    ///
+    /// assert_eq!(3, distance.call(&7));
+    /// assert_eq!(3, distance.call(&13));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input: &T) -> K {
+        (self.fun)(&self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(&T) -> K` struct, which can directly be passed to methods such as `sort_by_key` or `max_by_key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let reference = 10;
+    /// let distance = Capture(reference).fun_key(|r, x: &i32| (x - r).abs());
+    ///
+    /// let numbers = [4, 12, 20];
+    /// assert_eq!(Some(&12), numbers.iter().min_by_key(|x| distance.call(x)));
+    ///
+    /// let mut numbers = vec![4, 12, 20];
+    /// numbers.sort_by_key(distance.as_fn());
+    /// assert_eq!(vec![12, 4, 20], numbers);
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(&T) -> K + '_ {
+        move |x| self.call(x)
+    }
+
+    /// Returns a comparator of `&T` values which compares their keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    /// use std::cmp::Ordering;
+    ///
+    /// let lengths = Capture(()).fun_key(|_, x: &Vec<i32>| x.len());
+    /// let compare = lengths.as_comparator();
+    ///
+    /// assert_eq!(Ordering::Less, compare(&vec![1, 2], &vec![1, 2, 3]));
+    /// assert_eq!(Ordering::Equal, compare(&vec![1, 2], &vec![3, 4]));
+    ///
+    /// let mut lists = vec![vec![1, 2, 3], vec![1], vec![1, 2]];
+    /// lists.sort_by(lengths.as_comparator());
+    /// assert_eq!(vec![vec![1], vec![1, 2], vec![1, 2, 3]], lists);
+    /// ```
+    pub fn as_comparator(&self) -> impl Fn(&T, &T) -> Ordering + '_
+    where
+        K: Ord,
+    {
+        move |a, b| self.call(a).cmp(&self.call(b))
+    }
+}
+
+impl<'a, Capture, T: ?Sized, K> Fun<&'a T, K> for ClosureKey<Capture, T, K> {
+    fn call(&self, input: &'a T) -> K {
+        ClosureKey::call(self, input)
+    }
+}
+
+/// Sorts the `slice` in ascending order of the keys computed by the `closure`.
+///
+/// This sort is stable; i.e., it does not reorder elements with equal keys.
+/// The key of each element is computed `O(log n)` times, similar to `slice::sort_by_key`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let ranks = vec![3, 1, 2];
+/// let rank = Capture(ranks).fun_key(|r, i: &usize| r[*i]);
+///
+/// let mut ids = vec![0, 1, 2];
+/// sort_by_closure(&mut ids, &rank);
+/// assert_eq!(vec![1, 2, 0], ids);
+/// ```
+pub fn sort_by_closure<Capture, T, K: Ord>(slice: &mut [T], closure: &ClosureKey<Capture, T, K>) {
+    slice.sort_by(closure.as_comparator())
+}
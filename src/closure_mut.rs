@@ -0,0 +1,134 @@
+use crate::fun::FunMut;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&mut Capture, In) -> Out` is the transformation.
+///
+/// It represents the transformation `In -> Out` which is allowed to mutate its captured state on each call.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureMut` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **This is the mutable counterpart of `Closure`; it is useful for stateful transformations such as counters, caches or random number generators.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// // next_id: ClosureMut<usize, &str, String>
+/// let mut next_id = Capture(0).fun_mut(|count, prefix: &str| {
+///     *count += 1;
+///     format!("{}-{}", prefix, count)
+/// });
+///
+/// assert_eq!("node-1", next_id.call_mut("node"));
+/// assert_eq!("edge-2", next_id.call_mut("edge"));
+///
+/// // alternatively
+/// let ids: Vec<_> = ["a", "b"].into_iter().map(next_id.as_fn_mut()).collect();
+/// assert_eq!(vec!["a-3", "b-4"], ids);
+/// ```
+#[derive(Clone)]
+pub struct ClosureMut<Capture, In, Out> {
+    capture: Capture,
+    fun: fn(&mut Capture, In) -> Out,
+}
+
+impl<Capture: Debug, In, Out> Debug for ClosureMut<Capture, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureMut")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, Out> ClosureMut<Capture, In, Out> {
+    pub(super) fn new(capture: Capture, fun: fn(&mut Capture, In) -> Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input`, possibly mutating the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut running_sum = Capture(0).fun_mut(|sum, x: i32| {
+    ///     *sum += x;
+    ///     *sum
+    /// });
+    ///
+    /// assert_eq!(3, running_sum.call_mut(3));
+    /// assert_eq!(7, running_sum.call_mut(4));
+    /// ```
+    #[inline(always)]
+    pub fn call_mut(&mut self, input: In) -> Out {
+        (self.fun)(&mut self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut log_len = Capture(vec![]).fun_mut(|log, x: &str| {
+    ///     log.push(x.to_string());
+    ///     log.len()
+    /// });
+    ///
+    /// assert_eq!(1, log_len.call_mut("a"));
+    /// assert_eq!(2, log_len.call_mut("b"));
+    ///
+    /// assert_eq!(vec!["a", "b"], log_len.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl FnMut(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call_mut` method,
+    /// * or pass the closure to functions accepting a function generic over the `FnMut`, such as `Iterator::map` or `slice::sort_by_key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut num_calls = Capture(0).fun_mut(|count, name: &str| {
+    ///     *count += 1;
+    ///     name.len()
+    /// });
+    ///
+    /// let lengths: Vec<_> = ["john", "doe"].into_iter().map(num_calls.as_fn_mut()).collect();
+    /// assert_eq!(vec![4, 3], lengths);
+    ///
+    /// // std APIs passing references of any lifetime, such as sort_by_key, need a thin wrapper
+    /// let mut names = vec!["john", "doe", "jane"];
+    /// {
+    ///     let mut key = num_calls.as_fn_mut();
+    ///     names.sort_by_key(|name| key(name));
+    /// }
+    ///
+    /// assert_eq!(vec!["doe", "john", "jane"], names);
+    /// assert!(*num_calls.captured_data() > 2);
+    /// ```
+    pub fn as_fn_mut(&mut self) -> impl FnMut(In) -> Out + '_ {
+        move |x| self.call_mut(x)
+    }
+}
+
+impl<Capture, In, Out> FunMut<In, Out> for ClosureMut<Capture, In, Out> {
+    fn call_mut(&mut self, input: In) -> Out {
+        ClosureMut::call_mut(self, input)
+    }
+}
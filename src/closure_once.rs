@@ -0,0 +1,148 @@
+use crate::{fun::FunOnce, Closure};
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(Capture, In) -> Out` is the transformation.
+///
+/// It represents the transformation `In -> Out` which can be called only once, since the call consumes the captured data by value.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureOnce` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **This is the consuming counterpart of `Closure`; it is useful for initialization or teardown callbacks which must move their captured resources.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// struct Connection {
+///     log: Vec<String>,
+/// }
+///
+/// // close: ClosureOnce<Connection, &str, Vec<String>>
+/// let close = Capture(Connection { log: vec![] }).fun_once(|mut conn, reason: &str| {
+///     conn.log.push(format!("closed: {}", reason));
+///     conn.log
+/// });
+///
+/// let log = close.call_once("done");
+/// assert_eq!(vec!["closed: done"], log);
+/// ```
+#[derive(Clone)]
+pub struct ClosureOnce<Capture, In, Out> {
+    capture: Capture,
+    fun: fn(Capture, In) -> Out,
+}
+
+impl<Capture: Debug, In, Out> Debug for ClosureOnce<Capture, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureOnce")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, Out> ClosureOnce<Capture, In, Out> {
+    pub(super) fn new(capture: Capture, fun: fn(Capture, In) -> Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Consumes the closure and calls it with the given `input`, moving the captured data into the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let join = Capture(names).fun_once(|names, sep: &str| names.join(sep));
+    ///
+    /// assert_eq!("john-doe", join.call_once("-"));
+    /// ```
+    #[inline(always)]
+    pub fn call_once(self, input: In) -> Out {
+        (self.fun)(self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure without calling it and returns back the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let release = Capture(vec![1, 2, 3]).fun_once(|v, _: ()| v.len());
+    /// assert_eq!(&vec![1, 2, 3], release.captured_data());
+    ///
+    /// assert_eq!(vec![1, 2, 3], release.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl FnOnce(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call_once` method,
+    /// * or pass the closure to functions accepting a function generic over the `FnOnce`, such as `Option::map`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let into_greeting = Capture(String::from("hello")).fun_once(|mut g, name: &str| {
+    ///     g.push(' ');
+    ///     g.push_str(name);
+    ///     g
+    /// });
+    ///
+    /// let greeting = Some("john").map(into_greeting.into_fn_once());
+    /// assert_eq!(Some(String::from("hello john")), greeting);
+    /// ```
+    pub fn into_fn_once(self) -> impl FnOnce(In) -> Out {
+        move |x| self.call_once(x)
+    }
+}
+
+impl<Capture: Clone, In, Out> ClosureOnce<Capture, In, Out> {
+    /// Promotes the once-callable closure into a reusable `Closure` which calls the function with a clone of the captured data on each call.
+    ///
+    /// This is useful when the captured data turns out to be cheap to clone, such as a small configuration or an `Rc`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::rc::Rc;
+    ///
+    /// let greet = Capture(Rc::new(String::from("hello"))).fun_once(|g, name: &str| {
+    ///     format!("{} {}", g, name)
+    /// });
+    ///
+    /// // greet: Closure<(Rc<String>, fn(Rc<String>, &str) -> String), &str, String>
+    /// let greet = greet.into_reusable();
+    ///
+    /// assert_eq!("hello john", greet.call("john"));
+    /// assert_eq!("hello doe", greet.call("doe"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn into_reusable(self) -> Closure<(Capture, fn(Capture, In) -> Out), In, Out> {
+        Closure::new((self.capture, self.fun), |(capture, fun), input| {
+            fun(capture.clone(), input)
+        })
+    }
+}
+
+impl<Capture, In, Out> FunOnce<In, Out> for ClosureOnce<Capture, In, Out> {
+    fn call_once(self, input: In) -> Out {
+        ClosureOnce::call_once(self, input)
+    }
+}
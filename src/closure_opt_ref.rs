@@ -1,5 +1,8 @@
-use crate::fun::FunOptRef;
-use std::fmt::Debug;
+use crate::{
+    fun::{FunLookup, FunOptRef},
+    Closure,
+};
+use std::{fmt::Debug, ops::Range};
 
 /// Closure strictly separating the captured data from the function, and hence, having two components:
 ///
@@ -45,7 +48,7 @@ impl<Capture: Debug, In, Out: ?Sized> Debug for ClosureOptRef<Capture, In, Out>
 }
 
 impl<Capture, In, Out: ?Sized> ClosureOptRef<Capture, In, Out> {
-    pub(super) fn new(capture: Capture, fun: fn(&Capture, In) -> Option<&Out>) -> Self {
+    pub(super) const fn new(capture: Capture, fun: fn(&Capture, In) -> Option<&Out>) -> Self {
         Self { capture, fun }
     }
 
@@ -70,6 +73,26 @@ impl<Capture, In, Out: ?Sized> ClosureOptRef<Capture, In, Out> {
         (self.fun)(&self.capture, input)
     }
 
+    /// Calls the closure with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun_option_ref(|v, i: usize| v.get(i));
+    /// assert_eq!(Some(&2), fun.call_outlined(1));
+    /// assert_eq!(None, fun.call_outlined(3));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Option<&Out> {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> &Capture {
@@ -97,6 +120,195 @@ impl<Capture, In, Out: ?Sized> ClosureOptRef<Capture, In, Out> {
         self.capture
     }
 
+    /// Consumes the closure, takes out a part of the captured data and returns it together with the closure over the remaining data.
+    ///
+    /// The `take` function receives the captured data by value and returns the taken part `T` together with the captured data to be kept.
+    /// This allows for a staged teardown where a component of the capture is extracted while the closure remains usable, without requiring to rebuild it from scratch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let weights = vec![0, 1, 2];
+    /// let log = vec!["created".to_string()];
+    ///
+    /// let weight = Capture((weights, log)).fun_option_ref(|(weights, _), i: usize| weights.get(i));
+    ///
+    /// let (log, weight) = weight.take_map(|(weights, log)| (log, (weights, vec![])));
+    ///
+    /// assert_eq!(vec!["created".to_string()], log);
+    /// assert_eq!(Some(&0), weight.call(0));
+    /// assert!(weight.captured_data().1.is_empty());
+    /// ```
+    pub fn take_map<T>(self, take: impl FnOnce(Capture) -> (T, Capture)) -> (T, Self) {
+        let (taken, capture) = take(self.capture);
+        (taken, Self::new(capture, self.fun))
+    }
+
+    /// Applies `update` on the captured data in place, while keeping the function of the closure.
+    ///
+    /// This allows to patch the captured data, such as pushing a new row of weights, without the round trip of `into_captured_data` and re-building the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec![String::from("john")];
+    /// let mut name_of = Capture(names).fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()));
+    /// assert_eq!(None, name_of.call(1));
+    ///
+    /// name_of.update_capture(|n| n.push(String::from("doe")));
+    ///
+    /// assert_eq!(Some("doe"), name_of.call(1));
+    /// ```
+    pub fn update_capture(&mut self, update: impl FnOnce(&mut Capture)) {
+        update(&mut self.capture)
+    }
+
+    /// Replaces the function of the closure with the given `fun` and returns the previous function, while keeping the captured data.
+    ///
+    /// This allows to change the behavior of the closure, for instance between strict and lenient versions of a lookup, without moving the captured data out and back in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<String, i32> = HashMap::from_iter([("Apple".to_string(), 3)]);
+    ///
+    /// let mut lookup = Capture(map).fun_option_ref(|m, key: &str| m.get(key));
+    /// assert_eq!(None, lookup.call("apple"));
+    ///
+    /// // lenient lookup over the same map
+    /// let strict = lookup.swap_fun(|m, key| {
+    ///     m.iter()
+    ///         .find(|(k, _)| k.eq_ignore_ascii_case(key))
+    ///         .map(|(_, v)| v)
+    /// });
+    /// assert_eq!(Some(&3), lookup.call("apple"));
+    ///
+    /// // back to strict
+    /// lookup.swap_fun(strict);
+    /// assert_eq!(None, lookup.call("apple"));
+    /// ```
+    pub fn swap_fun(
+        &mut self,
+        fun: fn(&Capture, In) -> Option<&Out>,
+    ) -> fn(&Capture, In) -> Option<&Out> {
+        std::mem::replace(&mut self.fun, fun)
+    }
+
+    /// Consumes the closure and returns a new closure with the same captured data and the given `fun`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let numbers = vec![1, 2, 3];
+    /// let get = Capture(numbers).fun_option_ref(|x, i: usize| x.get(i));
+    /// let get_from_back = get.with_fun(|x, i| x.iter().rev().nth(i));
+    ///
+    /// assert_eq!(Some(&3), get_from_back.call(0));
+    /// assert_eq!(None, get_from_back.call(3));
+    /// ```
+    pub fn with_fun(self, fun: fn(&Capture, In) -> Option<&Out>) -> Self {
+        Self::new(self.capture, fun)
+    }
+
+    /// Transforms the closure into one returning references to `Target` rather than `Out`, by applying the `coerce` function on the returned references.
+    ///
+    /// This is mainly useful to widen the output into a trait object, such as `dyn Display` or `dyn Error`, without rebuilding the closure.
+    /// In such cases, `coerce` is simply the identity function `|x| x` where the compiler performs the unsizing coercion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::fmt::Debug;
+    ///
+    /// let numbers = vec![1, 2];
+    /// let number = Capture(numbers).fun_option_ref(|x, i: usize| x.get(i));
+    ///
+    /// // ClosureOptRef<_, usize, i32> -> ClosureOptRef<_, usize, dyn Debug>
+    /// let debug = number.upcast_out::<dyn Debug>(|x| x);
+    ///
+    /// assert_eq!(Some("2".to_string()), debug.call(1).map(|x| format!("{:?}", x)));
+    /// assert!(debug.call(2).is_none());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn upcast_out<Target: ?Sized>(
+        self,
+        coerce: fn(&Out) -> &Target,
+    ) -> ClosureOptRef<(Self, fn(&Out) -> &Target), In, Target> {
+        ClosureOptRef::new((self, coerce), |(fun, coerce), input| {
+            fun.call(input).map(*coerce)
+        })
+    }
+
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight = Capture(vec![vec![1, 2], vec![3]]).fun_option_ref(|m, (i, j): (usize, usize)| m.get(i).and_then(|r| r.get(j)));
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!(Some(&3), weight.call(Edge { from: 1, to: 0 }));
+    /// assert_eq!(None, weight.call(Edge { from: 1, to: 1 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> ClosureOptRef<(Self, fn(NewIn) -> In), NewIn, Out> {
+        ClosureOptRef::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
+    /// Transforms the closure into a `Closure` returning `Option<Out2>`, by applying `map_out` on the reference returned by this closure, if any;
+    /// i.e., calling the transformed closure with `input` returns `self.call(input).map(map_out)`.
+    ///
+    /// The transformed closure owns this closure, and hence, the same captured data.
+    /// This allows to clone or derive a value from the returned reference without wrapping the closure in an ad-hoc `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let prices = HashMap::from([("apple", 120), ("pear", 80)]);
+    /// let price = Capture(prices).fun_option_ref(|p, name: &str| p.get(name));
+    ///
+    /// let price_in_euros = price.map_ref_to_val(|cents| *cents as f64 / 100.0);
+    /// assert_eq!(Some(1.2), price_in_euros.call("apple"));
+    /// assert_eq!(None, price_in_euros.call("kiwi"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_ref_to_val<Out2>(
+        self,
+        map_out: fn(&Out) -> Out2,
+    ) -> Closure<(Self, fn(&Out) -> Out2), In, Option<Out2>> {
+        Closure::new((self, map_out), |(fun, map_out), input| {
+            fun.call(input).map(*map_out)
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> Option<&Out>` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -120,6 +332,64 @@ impl<Capture, In, Out: ?Sized> ClosureOptRef<Capture, In, Out> {
     pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> Option<&'a Out> {
         move |x| self.call(x)
     }
+
+    /// Returns an iterator over the given `inputs` which are present in the closure, yielding each input together with the reference returned for it;
+    /// i.e., inputs for which the closure returns `None` are skipped.
+    ///
+    /// This allows for a sparse scan over a captured sparse structure, where the yielded references borrow from the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let scores: HashMap<usize, String> = HashMap::from_iter([(1, "b".to_string()), (3, "d".to_string())]);
+    /// let score = Capture(scores).fun_option_ref(|s, i: usize| s.get(&i).map(|x| x.as_str()));
+    ///
+    /// let present: Vec<_> = score.iter_present(0..5).collect();
+    /// assert_eq!(vec![(1, "b"), (3, "d")], present);
+    /// ```
+    pub fn iter_present<'a, I>(&'a self, inputs: I) -> impl Iterator<Item = (In, &'a Out)> + 'a
+    where
+        I: IntoIterator<Item = In>,
+        I::IntoIter: 'a,
+        In: Clone + 'a,
+    {
+        inputs
+            .into_iter()
+            .filter_map(move |input| self.call(input.clone()).map(|out| (input, out)))
+    }
+
+    /// Consumes the closure and seals it into a closure with a compact type, capturing the closure itself as the trait object `Box<dyn FunOptRef<In, Out> + 'a>`.
+    ///
+    /// Closures built on top of other closures, such as the ones capturing another closure, have types which grow with each layer.
+    /// When the nesting gets deep, these types might become hard to read in compiler messages, slow to compile, or even hit the type length limit.
+    /// `seal` collapses the entire type into `ClosureOptRef<Box<dyn FunOptRef<In, Out> + 'a>, In, Out>`, no matter how deep the closure is.
+    ///
+    /// The price is a heap allocation once when sealing and a dynamic dispatch on each call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let name = Capture(names).fun_option_ref(|x, i: usize| x.get(i).map(|x| x.as_str()));
+    ///
+    /// let first_char = Capture(name).fun_option_ref(|name, i: usize| name.call(i).map(|x| &x[..1]));
+    ///
+    /// // ClosureOptRef<Box<dyn FunOptRef<usize, str>>, usize, str>
+    /// let sealed = first_char.seal();
+    /// assert_eq!(Some("d"), sealed.call(1));
+    /// assert_eq!(None, sealed.call(2));
+    /// ```
+    pub fn seal<'a>(self) -> ClosureOptRef<Box<dyn FunOptRef<In, Out> + 'a>, In, Out>
+    where
+        Self: 'a,
+    {
+        ClosureOptRef::new(Box::new(self), |fun, input| fun.call(input))
+    }
 }
 
 impl<Capture, In, Out: ?Sized> FunOptRef<In, Out> for ClosureOptRef<Capture, In, Out> {
@@ -127,3 +397,188 @@ impl<Capture, In, Out: ?Sized> FunOptRef<In, Out> for ClosureOptRef<Capture, In,
         ClosureOptRef::call(self, input)
     }
 }
+
+impl<Capture, K, V: ?Sized> FunLookup<K, V> for ClosureOptRef<Capture, K, V> {
+    fn get(&self, key: K) -> Option<&V> {
+        ClosureOptRef::call(self, key)
+    }
+}
+
+impl<Capture, In: Clone, Out: ?Sized> ClosureOptRef<Capture, In, Out> {
+    /// Combines this closure with the `other` optional lookup over the same input into a single closure.
+    ///
+    /// The resulting closure owns the captured data of both closures and, for each input:
+    /// * returns `None` if either of the lookups returns `None`,
+    /// * returns `Some(combine(a, b))` otherwise, where `a` and `b` are the references returned by this and the other closure, respectively.
+    ///
+    /// Since both lookups are performed on the same input, `In` is required to be `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let prices = HashMap::from([("apple", 2.0), ("banana", 0.5), ("cherry", 6.0)]);
+    /// let stocks = HashMap::from([("apple", 10), ("banana", 0)]);
+    ///
+    /// let price = Capture(prices).fun_option_ref(|p, fruit: &str| p.get(fruit));
+    /// let stock = Capture(stocks).fun_option_ref(|s, fruit: &str| s.get(fruit));
+    ///
+    /// // Closure<_, &str, Option<f64>>
+    /// let value = price.zip_with(stock, |p, s| p * *s as f64);
+    ///
+    /// assert_eq!(Some(20.0), value.call("apple"));
+    /// assert_eq!(Some(0.0), value.call("banana"));
+    /// assert_eq!(None, value.call("cherry"));
+    /// assert_eq!(None, value.call("durian"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn zip_with<Capture2, Out2: ?Sized, Combined>(
+        self,
+        other: ClosureOptRef<Capture2, In, Out2>,
+        combine: fn(&Out, &Out2) -> Combined,
+    ) -> Closure<
+        (
+            Self,
+            ClosureOptRef<Capture2, In, Out2>,
+            fn(&Out, &Out2) -> Combined,
+        ),
+        In,
+        Option<Combined>,
+    > {
+        Closure::new((self, other, combine), |(a, b, combine), input| {
+            let a = a.call(input.clone())?;
+            let b = b.call(input)?;
+            Some(combine(a, b))
+        })
+    }
+
+    /// Combines this closure with the `fallback` lookup over the same input into a single closure,
+    /// which tries this closure first and falls back to the `fallback` whenever this closure returns `None`.
+    ///
+    /// The resulting closure owns both closures, and hence, the captured data of both; they can be obtained back by `into_captured_data`.
+    ///
+    /// Since both lookups might be performed on the same input, `In` is required to be `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let user_config = HashMap::from([("theme", String::from("dark"))]);
+    /// let defaults = HashMap::from([("theme", String::from("light")), ("lang", String::from("en"))]);
+    ///
+    /// let user = Capture(user_config).fun_option_ref(|c, key: &str| c.get(key).map(|x| x.as_str()));
+    /// let default = Capture(defaults).fun_option_ref(|c, key: &str| c.get(key).map(|x| x.as_str()));
+    ///
+    /// let config = user.or_else(default);
+    /// assert_eq!(Some("dark"), config.call("theme"));
+    /// assert_eq!(Some("en"), config.call("lang"));
+    /// assert_eq!(None, config.call("font"));
+    ///
+    /// let (user, default) = config.into_captured_data();
+    /// assert_eq!(1, user.captured_data().len());
+    /// assert_eq!(2, default.captured_data().len());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn or_else<Capture2>(
+        self,
+        fallback: ClosureOptRef<Capture2, In, Out>,
+    ) -> ClosureOptRef<(Self, ClosureOptRef<Capture2, In, Out>), In, Out> {
+        ClosureOptRef::new((self, fallback), |(fun, fallback), input| {
+            fun.call(input.clone()).or_else(|| fallback.call(input))
+        })
+    }
+}
+
+impl<Capture, Out: ?Sized> ClosureOptRef<Capture, usize, Out> {
+    /// Creates a view of the closure over the sub-range `range` of its indices; i.e., calling the view with index `i`:
+    ///
+    /// * calls this closure with `range.start + i` if `i < range.len()`,
+    /// * returns `None` otherwise.
+    ///
+    /// This allows windowed access over an indexed capture, such as a `Vec`, without copying the data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let numbers = vec![0, 10, 20, 30, 40];
+    /// let get = Capture(numbers).fun_option_ref(|x, i: usize| x.get(i));
+    ///
+    /// let window = get.restrict(1..3);
+    /// assert_eq!(Some(&10), window.call(0));
+    /// assert_eq!(Some(&20), window.call(1));
+    /// assert_eq!(None, window.call(2)); // outside the window
+    ///
+    /// // windows can be nested
+    /// let inner = window.restrict(1..5);
+    /// assert_eq!(Some(&20), inner.call(0));
+    /// assert_eq!(None, inner.call(1));
+    /// ```
+    pub fn restrict(self, range: Range<usize>) -> ClosureOptRef<(Self, Range<usize>), usize, Out> {
+        ClosureOptRef::new((self, range), |(fun, range), i| match i < range.len() {
+            true => fun.call(range.start + i),
+            false => None,
+        })
+    }
+}
+
+impl<Capture: Clone, In, Out: ?Sized> ClosureOptRef<&Capture, In, Out> {
+    /// Consumes this closure borrowing its captured data and returns an equivalent closure owning a clone of the data.
+    ///
+    /// The stored function of the borrowing closure is defined over `&&Capture`; therefore, it cannot be reused over the owned `Capture`.
+    /// Instead, the function is re-bound with the given `fun`.
+    /// Thanks to auto-deref, the very same lambda used to build the borrowing closure can usually be passed in as it is.
+    ///
+    /// This allows a closure prototyped over borrowed data to be promoted into one that can be stored or sent elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let borrowing = Capture(&names).fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()));
+    /// assert_eq!(Some("doe"), borrowing.call(1));
+    ///
+    /// // same lambda, now over an owned copy of the data
+    /// let owned: ClosureOptRef<Vec<String>, usize, str> =
+    ///     borrowing.into_owning(|n, i| n.get(i).map(|x| x.as_str()));
+    /// drop(names);
+    /// assert_eq!(Some("doe"), owned.call(1));
+    /// assert_eq!(None, owned.call(2));
+    /// ```
+    pub fn into_owning(
+        self,
+        fun: fn(&Capture, In) -> Option<&Out>,
+    ) -> ClosureOptRef<Capture, In, Out> {
+        ClosureOptRef::new(self.capture.clone(), fun)
+    }
+
+    /// Returns a closure owning a clone of the captured data and using the given `fun`, leaving this borrowing closure intact.
+    ///
+    /// See [`into_owning`](Self::into_owning) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let borrowing = Capture(&names).fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()));
+    ///
+    /// let owned = borrowing.to_owned_capture(|n, i| n.get(i).map(|x| x.as_str()));
+    /// assert_eq!(borrowing.call(0), owned.call(0));
+    /// assert_eq!(&names, owned.captured_data());
+    /// ```
+    pub fn to_owned_capture(
+        &self,
+        fun: fn(&Capture, In) -> Option<&Out>,
+    ) -> ClosureOptRef<Capture, In, Out> {
+        Self::new(self.capture, self.fun).into_owning(fun)
+    }
+}
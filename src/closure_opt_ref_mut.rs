@@ -0,0 +1,105 @@
+use crate::fun::FunOptRefMut;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&mut Capture, In) -> Option<&mut Out>` is the transformation.
+///
+/// It represents the transformation `In -> Option<&mut Out>`.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureOptRefMut` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **This is the mutable counterpart of `ClosureOptRef`; it is useful when we capture the data by value and want to mutate an element of it in place if it exists.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+/// use std::collections::HashMap;
+///
+/// let stock: HashMap<&str, u32> = HashMap::from_iter([("apple", 3), ("pear", 1)]);
+///
+/// // stock_of: ClosureOptRefMut<HashMap<&str, u32>, &str, u32>
+/// let mut stock_of = Capture(stock).fun_option_ref_mut(|s, item| s.get_mut(item));
+///
+/// if let Some(count) = stock_of.call_mut("apple") {
+///     *count -= 1;
+/// }
+///
+/// assert_eq!(Some(&mut 2), stock_of.call_mut("apple"));
+/// assert_eq!(None, stock_of.call_mut("plum"));
+/// ```
+#[derive(Clone)]
+pub struct ClosureOptRefMut<Capture, In, Out: ?Sized> {
+    capture: Capture,
+    fun: fn(&mut Capture, In) -> Option<&mut Out>,
+}
+
+impl<Capture: Debug, In, Out: ?Sized> Debug for ClosureOptRefMut<Capture, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureOptRefMut")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, Out: ?Sized> ClosureOptRefMut<Capture, In, Out> {
+    pub(super) fn new(capture: Capture, fun: fn(&mut Capture, In) -> Option<&mut Out>) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input` and returns a mutable reference into the captured data; or `None` if there is no element corresponding to the `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let mut name_of = Capture(names).fun_option_ref_mut(|n, i: usize| n.get_mut(i));
+    ///
+    /// if let Some(name) = name_of.call_mut(0) {
+    ///     name.push_str("ny");
+    /// }
+    ///
+    /// assert_eq!(Some("johnny"), name_of.call_mut(0).map(|x| x.as_str()));
+    /// assert_eq!(None, name_of.call_mut(2));
+    /// ```
+    #[inline(always)]
+    pub fn call_mut(&mut self, input: In) -> Option<&mut Out> {
+        (self.fun)(&mut self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let counts = vec![0, 0];
+    /// let mut count_of = Capture(counts).fun_option_ref_mut(|c, i: usize| c.get_mut(i));
+    ///
+    /// for i in [0, 1, 1, 5] {
+    ///     if let Some(count) = count_of.call_mut(i) {
+    ///         *count += 1;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(vec![1, 2], count_of.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+}
+
+impl<Capture, In, Out: ?Sized> FunOptRefMut<In, Out> for ClosureOptRefMut<Capture, In, Out> {
+    fn call_mut(&mut self, input: In) -> Option<&mut Out> {
+        ClosureOptRefMut::call_mut(self, input)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::fun::FunRef;
-use std::fmt::Debug;
+use crate::{fun::FunRef, Closure};
+use std::{fmt::Debug, ops::Range};
 
 /// Closure strictly separating the captured data from the function, and hence, having two components:
 ///
@@ -68,6 +68,25 @@ impl<Capture, In, Out: ?Sized> ClosureRef<Capture, In, Out> {
         (self.fun)(&self.capture, input)
     }
 
+    /// Calls the closure with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![String::from("a"), String::from("b")]).fun_ref(|v, i: usize| v[i].as_str());
+    /// assert_eq!("b", fun.call_outlined(1));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> &Out {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> &Capture {
@@ -94,6 +113,249 @@ impl<Capture, In, Out: ?Sized> ClosureRef<Capture, In, Out> {
         self.capture
     }
 
+    /// Consumes the closure, takes out a part of the captured data and returns it together with the closure over the remaining data.
+    ///
+    /// The `take` function receives the captured data by value and returns the taken part `T` together with the captured data to be kept.
+    /// This allows for a staged teardown where a component of the capture is extracted while the closure remains usable, without requiring to rebuild it from scratch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let weights = vec![0, 1, 2];
+    /// let log = vec!["created".to_string()];
+    ///
+    /// let weight = Capture((weights, log)).fun_ref(|(weights, _), i: usize| &weights[i]);
+    ///
+    /// let (log, weight) = weight.take_map(|(weights, log)| (log, (weights, vec![])));
+    ///
+    /// assert_eq!(vec!["created".to_string()], log);
+    /// assert_eq!(&0, weight.call(0));
+    /// assert!(weight.captured_data().1.is_empty());
+    /// ```
+    pub fn take_map<T>(self, take: impl FnOnce(Capture) -> (T, Capture)) -> (T, Self) {
+        let (taken, capture) = take(self.capture);
+        (taken, Self::new(capture, self.fun))
+    }
+
+    /// Applies `update` on the captured data in place, while keeping the function of the closure.
+    ///
+    /// This allows to patch the captured data, such as pushing a new row of weights, without the round trip of `into_captured_data` and re-building the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec![String::from("john")];
+    /// let mut name_of = Capture(names).fun_ref(|n, i: usize| n[i].as_str());
+    ///
+    /// name_of.update_capture(|n| n.push(String::from("doe")));
+    ///
+    /// assert_eq!("john", name_of.call(0));
+    /// assert_eq!("doe", name_of.call(1));
+    /// ```
+    pub fn update_capture(&mut self, update: impl FnOnce(&mut Capture)) {
+        update(&mut self.capture)
+    }
+
+    /// Replaces the function of the closure with the given `fun` and returns the previous function, while keeping the captured data.
+    ///
+    /// This allows to change the behavior of the closure, for instance between strict and lenient versions of a lookup, without moving the captured data out and back in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let mut name = Capture(names).fun_ref(|n, i: usize| n[i].as_str());
+    /// assert_eq!("doe", name.call(1));
+    ///
+    /// let by_index = name.swap_fun(|n, i| n[n.len() - 1 - i].as_str());
+    /// assert_eq!("john", name.call(1));
+    ///
+    /// name.swap_fun(by_index);
+    /// assert_eq!("doe", name.call(1));
+    /// ```
+    pub fn swap_fun(&mut self, fun: fn(&Capture, In) -> &Out) -> fn(&Capture, In) -> &Out {
+        std::mem::replace(&mut self.fun, fun)
+    }
+
+    /// Consumes the closure and returns a new closure with the same captured data and the given `fun`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let first = Capture(names).fun_ref(|n, _: ()| n[0].as_str());
+    /// let last = first.with_fun(|n, _| n[n.len() - 1].as_str());
+    ///
+    /// assert_eq!("doe", last.call(()));
+    /// ```
+    pub fn with_fun(self, fun: fn(&Capture, In) -> &Out) -> Self {
+        Self::new(self.capture, fun)
+    }
+
+    /// Transforms the closure into one returning references to `Target` rather than `Out`, by applying the `coerce` function on the returned references.
+    ///
+    /// This is mainly useful to widen the output into a trait object, such as `dyn Display` or `dyn Error`, without rebuilding the closure.
+    /// In such cases, `coerce` is simply the identity function `|x| x` where the compiler performs the unsizing coercion.
+    ///
+    /// More generally, `coerce` can be any projection of the returned reference, such as to a field of the output.
+    /// [`then_ref`](Self::then_ref) is an alias of this method which reads better for such projections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::fmt::Display;
+    ///
+    /// let numbers = vec![1.5, 2.5];
+    /// let number = Capture(numbers).fun_ref(|x, i: usize| &x[i]);
+    ///
+    /// // ClosureRef<_, usize, f64> -> ClosureRef<_, usize, dyn Display>
+    /// let display = number.upcast_out::<dyn Display>(|x| x);
+    ///
+    /// assert_eq!("2.5", display.call(1).to_string());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn upcast_out<Target: ?Sized>(
+        self,
+        coerce: fn(&Out) -> &Target,
+    ) -> ClosureRef<(Self, fn(&Out) -> &Target), In, Target> {
+        ClosureRef::new((self, coerce), |(fun, coerce), input| {
+            coerce(fun.call(input))
+        })
+    }
+
+    /// Alias of [`upcast_out`](Self::upcast_out), named for the projection use case;
+    /// i.e., calling the transformed closure with `input` returns `project(self.call(input))`.
+    ///
+    /// This allows to narrow the output, such as from a `&Person` to the `&str` of its name, without any allocation.
+    /// The returned references still borrow from the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Person {
+    ///     name: String,
+    /// }
+    ///
+    /// let people = vec![Person { name: String::from("john") }, Person { name: String::from("doe") }];
+    /// let person = Capture(people).fun_ref(|p, i: usize| &p[i]);
+    ///
+    /// // ClosureRef<_, usize, Person> -> ClosureRef<_, usize, str>
+    /// let name = person.then_ref(|p| p.name.as_str());
+    /// assert_eq!("doe", name.call(1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn then_ref<Out2: ?Sized>(
+        self,
+        project: fn(&Out) -> &Out2,
+    ) -> ClosureRef<(Self, fn(&Out) -> &Out2), In, Out2> {
+        self.upcast_out(project)
+    }
+
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight = Capture(vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]]).fun_ref(|m, (i, j): (usize, usize)| m[i][j].as_str());
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!("c", weight.call(Edge { from: 1, to: 0 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> ClosureRef<(Self, fn(NewIn) -> In), NewIn, Out> {
+        ClosureRef::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
+    /// Transforms the closure into a `Closure` returning the value `Out2`, by applying `map_out` on the reference returned by this closure;
+    /// i.e., calling the transformed closure with `input` returns `map_out(self.call(input))`.
+    ///
+    /// The transformed closure owns this closure, and hence, the same captured data.
+    /// This allows to clone or derive a value from the returned reference without wrapping the closure in an ad-hoc `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let name = Capture(names).fun_ref(|n, i: usize| n[i].as_str());
+    ///
+    /// let name_len = name.map_ref_to_val(|n| n.len());
+    /// assert_eq!(4, name_len.call(0));
+    ///
+    /// let owned_name = Capture(vec![String::from("jane")])
+    ///     .fun_ref(|n, i: usize| &n[i])
+    ///     .map_ref_to_val(String::clone);
+    /// assert_eq!(String::from("jane"), owned_name.call(0));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_ref_to_val<Out2>(
+        self,
+        map_out: fn(&Out) -> Out2,
+    ) -> Closure<(Self, fn(&Out) -> Out2), In, Out2> {
+        Closure::new((self, map_out), |(fun, map_out), input| {
+            map_out(fun.call(input))
+        })
+    }
+
+    /// Transforms the closure into a `Closure` returning the value `R` computed from both the input and the reference returned by this closure;
+    /// i.e., calling the transformed closure with `input` returns `map_out(input, self.call(input))`.
+    ///
+    /// Since the input is consumed by the call of this closure and then passed to `map_out`, `In` is required to be `Clone`.
+    /// The returned reference borrows from the captured data only during the call of `map_out`, which computes an owned value;
+    /// hence, the adapter does not require any lifetime choreography on the caller side.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let name = Capture(names).fun_ref(|n, i: usize| n[i].as_str());
+    ///
+    /// let labeled = name.map_with_input(|i, name| format!("{}: {}", i, name));
+    /// assert_eq!("0: john", labeled.call(0));
+    /// assert_eq!("1: doe", labeled.call(1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_with_input<R>(
+        self,
+        map_out: fn(In, &Out) -> R,
+    ) -> Closure<(Self, fn(In, &Out) -> R), In, R>
+    where
+        In: Clone,
+    {
+        Closure::new((self, map_out), |(fun, map_out), input| {
+            map_out(input.clone(), fun.call(input))
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> &Out` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -114,6 +376,35 @@ impl<Capture, In, Out: ?Sized> ClosureRef<Capture, In, Out> {
     pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> &'a Out {
         move |x| self.call(x)
     }
+
+    /// Consumes the closure and seals it into a closure with a compact type, capturing the closure itself as the trait object `Box<dyn FunRef<In, Out> + 'a>`.
+    ///
+    /// Closures built on top of other closures, such as the ones capturing another closure, have types which grow with each layer.
+    /// When the nesting gets deep, these types might become hard to read in compiler messages, slow to compile, or even hit the type length limit.
+    /// `seal` collapses the entire type into `ClosureRef<Box<dyn FunRef<In, Out> + 'a>, In, Out>`, no matter how deep the closure is.
+    ///
+    /// The price is a heap allocation once when sealing and a dynamic dispatch on each call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let name = Capture(names).fun_ref(|x, i: usize| x[i].as_str());
+    ///
+    /// let first_char = Capture(name).fun_ref(|name, i: usize| &name.call(i)[..1]);
+    ///
+    /// // ClosureRef<Box<dyn FunRef<usize, str>>, usize, str>
+    /// let sealed = first_char.seal();
+    /// assert_eq!("d", sealed.call(1));
+    /// ```
+    pub fn seal<'a>(self) -> ClosureRef<Box<dyn FunRef<In, Out> + 'a>, In, Out>
+    where
+        Self: 'a,
+    {
+        ClosureRef::new(Box::new(self), |fun, input| fun.call(input))
+    }
 }
 
 impl<Capture, In, Out: ?Sized> FunRef<In, Out> for ClosureRef<Capture, In, Out> {
@@ -121,3 +412,88 @@ impl<Capture, In, Out: ?Sized> FunRef<In, Out> for ClosureRef<Capture, In, Out>
         ClosureRef::call(self, input)
     }
 }
+
+impl<Capture, Out: ?Sized> ClosureRef<Capture, usize, Out> {
+    /// Creates a view of the closure over the sub-range `range` of its indices; i.e., calling the view with index `i` calls this closure with `range.start + i`.
+    ///
+    /// This allows windowed access over an indexed capture, such as a `Vec`, without copying the data.
+    ///
+    /// Since the view cannot return a reference for indices outside of the window, calling it with such an index panics.
+    /// See `ClosureOptRef::restrict` for a view returning `None` outside of the window.
+    ///
+    /// # Panics
+    ///
+    /// Calling the view panics if `i >= range.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string(), "jane".to_string()];
+    /// let name = Capture(names).fun_ref(|x, i: usize| x[i].as_str());
+    ///
+    /// let window = name.restrict(1..3);
+    /// assert_eq!("doe", window.call(0));
+    /// assert_eq!("jane", window.call(1));
+    /// ```
+    pub fn restrict(self, range: Range<usize>) -> ClosureRef<(Self, Range<usize>), usize, Out> {
+        ClosureRef::new((self, range), |(fun, range), i| {
+            assert!(
+                i < range.len(),
+                "index {} is out of the restricted window {:?}",
+                i,
+                range
+            );
+            fun.call(range.start + i)
+        })
+    }
+}
+
+impl<Capture: Clone, In, Out: ?Sized> ClosureRef<&Capture, In, Out> {
+    /// Consumes this closure borrowing its captured data and returns an equivalent closure owning a clone of the data.
+    ///
+    /// The stored function of the borrowing closure is defined over `&&Capture`; therefore, it cannot be reused over the owned `Capture`.
+    /// Instead, the function is re-bound with the given `fun`.
+    /// Thanks to auto-deref, the very same lambda used to build the borrowing closure can usually be passed in as it is.
+    ///
+    /// This allows a closure prototyped over borrowed data to be promoted into one that can be stored or sent elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let borrowing = Capture(&names).fun_ref(|n, i: usize| n[i].as_str());
+    /// assert_eq!("doe", borrowing.call(1));
+    ///
+    /// // same lambda, now over an owned copy of the data
+    /// let owned: ClosureRef<Vec<String>, usize, str> = borrowing.into_owning(|n, i| n[i].as_str());
+    /// drop(names);
+    /// assert_eq!("doe", owned.call(1));
+    /// ```
+    pub fn into_owning(self, fun: fn(&Capture, In) -> &Out) -> ClosureRef<Capture, In, Out> {
+        ClosureRef::new(self.capture.clone(), fun)
+    }
+
+    /// Returns a closure owning a clone of the captured data and using the given `fun`, leaving this borrowing closure intact.
+    ///
+    /// See [`into_owning`](Self::into_owning) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let borrowing = Capture(&names).fun_ref(|n, i: usize| n[i].as_str());
+    ///
+    /// let owned = borrowing.to_owned_capture(|n, i| n[i].as_str());
+    /// assert_eq!(borrowing.call(0), owned.call(0));
+    /// assert_eq!(&names, owned.captured_data());
+    /// ```
+    pub fn to_owned_capture(&self, fun: fn(&Capture, In) -> &Out) -> ClosureRef<Capture, In, Out> {
+        Self::new(self.capture, self.fun).into_owning(fun)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::fun::FunRefMut;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&mut Capture, In) -> &mut Out` is the transformation.
+///
+/// It represents the transformation `In -> &mut Out`.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureRefMut` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **This is the mutable counterpart of `ClosureRef`; it is useful when we capture the data by value and want to mutate a selected element of it in place.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// struct Account { balance: i64 }
+/// let accounts = vec![Account { balance: 10 }, Account { balance: 20 }];
+///
+/// // balance_of: ClosureRefMut<Vec<Account>, usize, i64>
+/// let mut balance_of = Capture(accounts).fun_ref_mut(|acc, id: usize| &mut acc[id].balance);
+///
+/// *balance_of.call_mut(0) += 5;
+/// *balance_of.call_mut(1) -= 5;
+///
+/// assert_eq!(&15, balance_of.call_mut(0));
+/// assert_eq!(&15, balance_of.call_mut(1));
+/// ```
+#[derive(Clone)]
+pub struct ClosureRefMut<Capture, In, Out: ?Sized> {
+    capture: Capture,
+    fun: fn(&mut Capture, In) -> &mut Out,
+}
+
+impl<Capture: Debug, In, Out: ?Sized> Debug for ClosureRefMut<Capture, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureRefMut")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, Out: ?Sized> ClosureRefMut<Capture, In, Out> {
+    pub(super) fn new(capture: Capture, fun: fn(&mut Capture, In) -> &mut Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input` and returns a mutable reference into the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let mut name_of = Capture(names).fun_ref_mut(|n, i: usize| &mut n[i]);
+    ///
+    /// name_of.call_mut(1).push_str("-smith");
+    ///
+    /// assert_eq!("doe-smith", name_of.call_mut(1));
+    /// ```
+    #[inline(always)]
+    pub fn call_mut(&mut self, input: In) -> &mut Out {
+        (self.fun)(&mut self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let counts = vec![0, 0, 0];
+    /// let mut count_of = Capture(counts).fun_ref_mut(|c, i: usize| &mut c[i]);
+    ///
+    /// for i in [0, 2, 2] {
+    ///     *count_of.call_mut(i) += 1;
+    /// }
+    ///
+    /// assert_eq!(vec![1, 0, 2], count_of.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+}
+
+impl<Capture, In, Out: ?Sized> FunRefMut<In, Out> for ClosureRefMut<Capture, In, Out> {
+    fn call_mut(&mut self, input: In) -> &mut Out {
+        ClosureRefMut::call_mut(self, input)
+    }
+}
@@ -1,5 +1,12 @@
-use crate::fun::FunResRef;
-use std::fmt::Debug;
+use crate::{
+    fun::{FunResRef, TryFun},
+    Closure,
+};
+use std::{
+    error::Error as StdError,
+    fmt::{Debug, Display},
+    ops::Range,
+};
 
 /// Closure strictly separating the captured data from the function, and hence, having two components:
 ///
@@ -76,6 +83,26 @@ impl<Capture, In, Out: ?Sized, Error> ClosureResRef<Capture, In, Out, Error> {
         (self.fun)(&self.capture, input)
     }
 
+    /// Calls the closure with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun_result_ref(|v, i: usize| v.get(i).ok_or("out of bounds"));
+    /// assert_eq!(Ok(&2), fun.call_outlined(1));
+    /// assert_eq!(Err("out of bounds"), fun.call_outlined(3));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Result<&Out, Error> {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> &Capture {
@@ -102,6 +129,228 @@ impl<Capture, In, Out: ?Sized, Error> ClosureResRef<Capture, In, Out, Error> {
         self.capture
     }
 
+    /// Consumes the closure, takes out a part of the captured data and returns it together with the closure over the remaining data.
+    ///
+    /// The `take` function receives the captured data by value and returns the taken part `T` together with the captured data to be kept.
+    /// This allows for a staged teardown where a component of the capture is extracted while the closure remains usable, without requiring to rebuild it from scratch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let weights = vec![0, 1, 2];
+    /// let log = vec!["created".to_string()];
+    ///
+    /// let weight = Capture((weights, log)).fun_result_ref(|(weights, _), i: usize| weights.get(i).ok_or("unknown"));
+    ///
+    /// let (log, weight) = weight.take_map(|(weights, log)| (log, (weights, vec![])));
+    ///
+    /// assert_eq!(vec!["created".to_string()], log);
+    /// assert_eq!(Ok(&0), weight.call(0));
+    /// assert!(weight.captured_data().1.is_empty());
+    /// ```
+    pub fn take_map<T>(self, take: impl FnOnce(Capture) -> (T, Capture)) -> (T, Self) {
+        let (taken, capture) = take(self.capture);
+        (taken, Self::new(capture, self.fun))
+    }
+
+    /// Applies `update` on the captured data in place, while keeping the function of the closure.
+    ///
+    /// This allows to patch the captured data, such as pushing a new row of weights, without the round trip of `into_captured_data` and re-building the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec![String::from("john")];
+    /// let mut name_of = Capture(names).fun_result_ref(|n, i: usize| n.get(i).map(|x| x.as_str()).ok_or(i));
+    /// assert_eq!(Err(1), name_of.call(1));
+    ///
+    /// name_of.update_capture(|n| n.push(String::from("doe")));
+    ///
+    /// assert_eq!(Ok("doe"), name_of.call(1));
+    /// ```
+    pub fn update_capture(&mut self, update: impl FnOnce(&mut Capture)) {
+        update(&mut self.capture)
+    }
+
+    /// Replaces the function of the closure with the given `fun` and returns the previous function, while keeping the captured data.
+    ///
+    /// This allows to change the behavior of the closure, for instance between strict and lenient versions of a lookup, without moving the captured data out and back in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let numbers = vec![1, 2, 3];
+    /// let mut get = Capture(numbers).fun_result_ref(|x, i: usize| x.get(i).ok_or("out of bounds"));
+    /// assert_eq!(Err("out of bounds"), get.call(3));
+    ///
+    /// // cyclic access over the same data
+    /// let strict = get.swap_fun(|x, i| x.get(i % x.len()).ok_or("empty"));
+    /// assert_eq!(Ok(&1), get.call(3));
+    ///
+    /// get.swap_fun(strict);
+    /// assert_eq!(Err("out of bounds"), get.call(3));
+    /// ```
+    pub fn swap_fun(
+        &mut self,
+        fun: fn(&Capture, In) -> Result<&Out, Error>,
+    ) -> fn(&Capture, In) -> Result<&Out, Error> {
+        std::mem::replace(&mut self.fun, fun)
+    }
+
+    /// Consumes the closure and returns a new closure with the same captured data and the given `fun`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let numbers = vec![1, 2, 3];
+    /// let get = Capture(numbers).fun_result_ref(|x, i: usize| x.get(i).ok_or(i));
+    /// let get_last = get.with_fun(|x, _| x.last().ok_or(0));
+    ///
+    /// assert_eq!(Ok(&3), get_last.call(42));
+    /// ```
+    pub fn with_fun(self, fun: fn(&Capture, In) -> Result<&Out, Error>) -> Self {
+        Self::new(self.capture, fun)
+    }
+
+    /// Transforms the closure into one returning references to `Target` rather than `Out`, by applying the `coerce` function on the returned references.
+    ///
+    /// This is mainly useful to widen the output into a trait object, such as `dyn Display` or `dyn Error`, without rebuilding the closure.
+    /// In such cases, `coerce` is simply the identity function `|x| x` where the compiler performs the unsizing coercion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct Invalid(usize);
+    /// impl fmt::Display for Invalid {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "invalid record {}", self.0)
+    ///     }
+    /// }
+    /// impl Error for Invalid {}
+    ///
+    /// let failures = vec![Invalid(3), Invalid(7)];
+    /// let failure = Capture(failures).fun_result_ref(|x, i: usize| x.get(i).ok_or("no failure"));
+    ///
+    /// // ClosureResRef<_, usize, Invalid, _> -> ClosureResRef<_, usize, dyn Error, _>
+    /// let error = failure.upcast_out::<dyn Error>(|x| x);
+    ///
+    /// assert_eq!(Ok("invalid record 7".to_string()), error.call(1).map(|e| e.to_string()));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn upcast_out<Target: ?Sized>(
+        self,
+        coerce: fn(&Out) -> &Target,
+    ) -> ClosureResRef<(Self, fn(&Out) -> &Target), In, Target, Error> {
+        ClosureResRef::new((self, coerce), |(fun, coerce), input| {
+            fun.call(input).map(*coerce)
+        })
+    }
+
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight = Capture(vec![vec![1, 2], vec![3]]).fun_result_ref(|m, (i, j): (usize, usize)| m.get(i).and_then(|r| r.get(j)).ok_or("no edge"));
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!(Ok(&3), weight.call(Edge { from: 1, to: 0 }));
+    /// assert_eq!(Err("no edge"), weight.call(Edge { from: 1, to: 1 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> ClosureResRef<(Self, fn(NewIn) -> In), NewIn, Out, Error> {
+        ClosureResRef::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
+    /// Transforms the closure into one returning errors of type `E2`, by applying `map_err` on the error returned by this closure, if any;
+    /// i.e., calling the transformed closure with `input` returns `self.call(input).map_err(map_err)`.
+    ///
+    /// This allows to adapt closures with simple errors, such as `&'static str`, to APIs requiring a domain error type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum LookupError {
+    ///     Missing(&'static str),
+    /// }
+    ///
+    /// let price = Capture(vec![1, 3]).fun_result_ref(|p, i: usize| p.get(i).ok_or("no price"));
+    ///
+    /// let price = price.map_err(LookupError::Missing);
+    /// assert_eq!(Ok(&3), price.call(1));
+    /// assert_eq!(Err(LookupError::Missing("no price")), price.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_err<E2>(
+        self,
+        map_err: fn(Error) -> E2,
+    ) -> ClosureResRef<(Self, fn(Error) -> E2), In, Out, E2> {
+        ClosureResRef::new((self, map_err), |(fun, map_err), input| {
+            fun.call(input).map_err(*map_err)
+        })
+    }
+
+    /// Transforms the closure into a `Closure` returning `Result<Out2, Error>`, by applying `map_out` on the reference returned by this closure, if any;
+    /// i.e., calling the transformed closure with `input` returns `self.call(input).map(map_out)`.
+    ///
+    /// The transformed closure owns this closure, and hence, the same captured data.
+    /// This allows to clone or derive a value from the returned reference without wrapping the closure in an ad-hoc `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let distances = vec![vec![0, 1200], vec![800, 0]];
+    /// let distance = Capture(distances).fun_result_ref(|d, (i, j): (usize, usize)| {
+    ///     d.get(i).and_then(|row| row.get(j)).ok_or("out of bounds")
+    /// });
+    ///
+    /// let kilometers = distance.map_ref_to_val(|m| *m as f64 / 1000.0);
+    /// assert_eq!(Ok(1.2), kilometers.call((0, 1)));
+    /// assert_eq!(Err("out of bounds"), kilometers.call((2, 0)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_ref_to_val<Out2>(
+        self,
+        map_out: fn(&Out) -> Out2,
+    ) -> Closure<(Self, fn(&Out) -> Out2), In, Result<Out2, Error>> {
+        Closure::new((self, map_out), |(fun, map_out), input| {
+            fun.call(input).map(*map_out)
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> Result<&Out, String>` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -127,6 +376,91 @@ impl<Capture, In, Out: ?Sized, Error> ClosureResRef<Capture, In, Out, Error> {
     pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> Result<&'a Out, Error> {
         move |x| self.call(x)
     }
+
+    /// Consumes the closure and seals it into a closure with a compact type, capturing the closure itself as the trait object `Box<dyn FunResRef<In, Out, Error> + 'a>`.
+    ///
+    /// Closures built on top of other closures, such as the ones capturing another closure, have types which grow with each layer.
+    /// When the nesting gets deep, these types might become hard to read in compiler messages, slow to compile, or even hit the type length limit.
+    /// `seal` collapses the entire type into `ClosureResRef<Box<dyn FunResRef<In, Out, Error> + 'a>, In, Out, Error>`, no matter how deep the closure is.
+    ///
+    /// The price is a heap allocation once when sealing and a dynamic dispatch on each call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let name = Capture(names).fun_result_ref(|x, i: usize| x.get(i).map(|x| x.as_str()).ok_or(i));
+    ///
+    /// let first_char = Capture(name).fun_result_ref(|name, i: usize| name.call(i).map(|x| &x[..1]));
+    ///
+    /// // ClosureResRef<Box<dyn FunResRef<usize, str, usize>>, usize, str, usize>
+    /// let sealed = first_char.seal();
+    /// assert_eq!(Ok("d"), sealed.call(1));
+    /// assert_eq!(Err(2), sealed.call(2));
+    /// ```
+    pub fn seal<'a>(self) -> ClosureResRef<Box<dyn FunResRef<In, Out, Error> + 'a>, In, Out, Error>
+    where
+        Self: 'a,
+    {
+        ClosureResRef::new(Box::new(self), |fun, input| fun.call(input))
+    }
+
+    /// Consumes the closure and converts its errors into `String`s, using their `Display` implementation.
+    ///
+    /// This allows to store closures with different error types behind a single uniform signature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2]).fun_result_ref(|v, i: usize| v.get(i).ok_or_else(|| "x".parse::<i32>().unwrap_err()));
+    ///
+    /// // ClosureResRef<Vec<i32>, usize, i32, ParseIntError> -> error type String
+    /// let fun = fun.errors_to_string();
+    ///
+    /// assert_eq!(Ok(&2), fun.call(1));
+    /// assert_eq!(Err(String::from("invalid digit found in string")), fun.call(7));
+    /// ```
+    pub fn errors_to_string(self) -> ClosureResRef<Self, In, Out, String>
+    where
+        Error: Display,
+    {
+        ClosureResRef::new(self, |fun, input| {
+            fun.call(input).map_err(|e| e.to_string())
+        })
+    }
+
+    /// Consumes the closure and boxes its errors into `Box<dyn Error + Send + Sync>`.
+    ///
+    /// The boxed errors are compatible with the `?` operator of functions returning `Box<dyn Error>`,
+    /// as well as the error types of crates such as `anyhow` or `eyre`.
+    ///
+    /// Any error convertible into the box can be boxed, including the types implementing `Error + Send + Sync` as well as `&str` and `String` messages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::error::Error;
+    ///
+    /// let fun = Capture(vec![1, 2]).fun_result_ref(|v, i: usize| v.get(i).ok_or_else(|| "x".parse::<i32>().unwrap_err()));
+    ///
+    /// let fun = fun.errors_boxed();
+    ///
+    /// fn parse_all(fun: &impl FunResRef<usize, i32, Box<dyn Error + Send + Sync>>) -> Result<i32, Box<dyn Error + Send + Sync>> {
+    ///     Ok(fun.call(0)? + fun.call(7)?)
+    /// }
+    /// assert_eq!("invalid digit found in string", parse_all(&fun).unwrap_err().to_string());
+    /// ```
+    pub fn errors_boxed(self) -> ClosureResRef<Self, In, Out, Box<dyn StdError + Send + Sync>>
+    where
+        Error: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        ClosureResRef::new(self, |fun, input| fun.call(input).map_err(|e| e.into()))
+    }
 }
 
 impl<Capture, In, Out: ?Sized, Error> FunResRef<In, Out, Error>
@@ -136,3 +470,105 @@ impl<Capture, In, Out: ?Sized, Error> FunResRef<In, Out, Error>
         ClosureResRef::call(self, input)
     }
 }
+
+impl<Capture, In, Out: ?Sized, Error> TryFun<In, Out, Error>
+    for ClosureResRef<Capture, In, Out, Error>
+{
+    fn try_call(&self, input: In) -> Result<&Out, Error> {
+        ClosureResRef::call(self, input)
+    }
+}
+
+impl<Capture, Out: ?Sized, Error> ClosureResRef<Capture, usize, Out, Error> {
+    /// Creates a view of the closure over the sub-range `range` of its indices; i.e., calling the view with index `i` calls this closure with `range.start + i`.
+    ///
+    /// This allows windowed access over an indexed capture, such as a `Vec`, without copying the data.
+    ///
+    /// Calling the view with an index outside of the window, `i >= range.len()`, does not call this closure;
+    /// instead, it returns the error created by `out_of_window` from the corresponding index of this closure, `range.start + i`.
+    /// This mirrors `ClosureOptRef::restrict` which returns `None` outside of the window.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let numbers = vec![0, 10, 20, 30, 40];
+    /// let get = Capture(numbers).fun_result_ref(|x, i: usize| x.get(i).ok_or(i));
+    ///
+    /// let window = get.restrict(3..5, |i| i);
+    /// assert_eq!(Ok(&30), window.call(0));
+    /// assert_eq!(Ok(&40), window.call(1));
+    /// assert_eq!(Err(5), window.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn restrict(
+        self,
+        range: Range<usize>,
+        out_of_window: fn(usize) -> Error,
+    ) -> ClosureResRef<(Self, Range<usize>, fn(usize) -> Error), usize, Out, Error> {
+        ClosureResRef::new(
+            (self, range, out_of_window),
+            |(fun, range, out_of_window), i| match i < range.len() {
+                true => fun.call(range.start + i),
+                false => Err(out_of_window(range.start.saturating_add(i))),
+            },
+        )
+    }
+}
+
+impl<Capture: Clone, In, Out: ?Sized, Error> ClosureResRef<&Capture, In, Out, Error> {
+    /// Consumes this closure borrowing its captured data and returns an equivalent closure owning a clone of the data.
+    ///
+    /// The stored function of the borrowing closure is defined over `&&Capture`; therefore, it cannot be reused over the owned `Capture`.
+    /// Instead, the function is re-bound with the given `fun`.
+    /// Thanks to auto-deref, the very same lambda used to build the borrowing closure can usually be passed in as it is.
+    ///
+    /// This allows a closure prototyped over borrowed data to be promoted into one that can be stored or sent elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let borrowing = Capture(&names).fun_result_ref(|n, i: usize| n.get(i).map(|x| x.as_str()).ok_or(i));
+    /// assert_eq!(Ok("doe"), borrowing.call(1));
+    ///
+    /// // same lambda, now over an owned copy of the data
+    /// let owned: ClosureResRef<Vec<String>, usize, str, usize> =
+    ///     borrowing.into_owning(|n, i| n.get(i).map(|x| x.as_str()).ok_or(i));
+    /// drop(names);
+    /// assert_eq!(Ok("doe"), owned.call(1));
+    /// assert_eq!(Err(2), owned.call(2));
+    /// ```
+    pub fn into_owning(
+        self,
+        fun: fn(&Capture, In) -> Result<&Out, Error>,
+    ) -> ClosureResRef<Capture, In, Out, Error> {
+        ClosureResRef::new(self.capture.clone(), fun)
+    }
+
+    /// Returns a closure owning a clone of the captured data and using the given `fun`, leaving this borrowing closure intact.
+    ///
+    /// See [`into_owning`](Self::into_owning) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec![String::from("john"), String::from("doe")];
+    /// let borrowing = Capture(&names).fun_result_ref(|n, i: usize| n.get(i).map(|x| x.as_str()).ok_or(i));
+    ///
+    /// let owned = borrowing.to_owned_capture(|n, i| n.get(i).map(|x| x.as_str()).ok_or(i));
+    /// assert_eq!(borrowing.call(0), owned.call(0));
+    /// assert_eq!(&names, owned.captured_data());
+    /// ```
+    pub fn to_owned_capture(
+        &self,
+        fun: fn(&Capture, In) -> Result<&Out, Error>,
+    ) -> ClosureResRef<Capture, In, Out, Error> {
+        Self::new(self.capture, self.fun).into_owning(fun)
+    }
+}
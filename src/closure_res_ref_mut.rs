@@ -0,0 +1,119 @@
+use crate::fun::FunResRefMut;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&mut Capture, In) -> Result<&mut Out, Error>` is the transformation.
+///
+/// It represents the transformation `In -> Result<&mut Out, Error>`.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureResRefMut` auto-implements `Clone` given that captured data is cloneable.
+///
+/// **This is the mutable counterpart of `ClosureResRef`; it is useful when we capture the data by value and want to mutate an element of it in place, which might fail.**
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::Capture;
+///
+/// struct Account { balance: u64, frozen: bool }
+/// let accounts = vec![
+///     Account { balance: 10, frozen: false },
+///     Account { balance: 20, frozen: true },
+/// ];
+///
+/// // balance_of: ClosureResRefMut<Vec<Account>, usize, u64, &'static str>
+/// let mut balance_of = Capture(accounts).fun_result_ref_mut(|acc, id: usize| match acc.get_mut(id) {
+///     Some(a) if a.frozen => Err("account is frozen"),
+///     Some(a) => Ok(&mut a.balance),
+///     None => Err("no such account"),
+/// });
+///
+/// *balance_of.call_mut(0).unwrap_or(&mut 0) += 5;
+///
+/// assert_eq!(Ok(&mut 15), balance_of.call_mut(0));
+/// assert_eq!(Err("account is frozen"), balance_of.call_mut(1));
+/// assert_eq!(Err("no such account"), balance_of.call_mut(2));
+/// ```
+#[derive(Clone)]
+pub struct ClosureResRefMut<Capture, In, Out: ?Sized, Error> {
+    capture: Capture,
+    fun: fn(&mut Capture, In) -> Result<&mut Out, Error>,
+}
+
+impl<Capture: Debug, In, Out: ?Sized, Error> Debug for ClosureResRefMut<Capture, In, Out, Error> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureResRefMut")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, Out: ?Sized, Error> ClosureResRefMut<Capture, In, Out, Error> {
+    pub(super) fn new(
+        capture: Capture,
+        fun: fn(&mut Capture, In) -> Result<&mut Out, Error>,
+    ) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input` and returns a mutable reference into the captured data, or the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let mut name_of = Capture(names).fun_result_ref_mut(|n, i: usize| n.get_mut(i).ok_or(i));
+    ///
+    /// if let Ok(name) = name_of.call_mut(1) {
+    ///     name.push('!');
+    /// }
+    ///
+    /// assert_eq!(Ok("doe!"), name_of.call_mut(1).map(|x| x.as_str()));
+    /// assert_eq!(Err(2), name_of.call_mut(2));
+    /// ```
+    #[inline(always)]
+    pub fn call_mut(&mut self, input: In) -> Result<&mut Out, Error> {
+        (self.fun)(&mut self.capture, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let counts = vec![0, 0];
+    /// let mut count_of = Capture(counts).fun_result_ref_mut(|c, i: usize| c.get_mut(i).ok_or(i));
+    ///
+    /// let mut failed = vec![];
+    /// for i in [0, 1, 1, 5] {
+    ///     match count_of.call_mut(i) {
+    ///         Ok(count) => *count += 1,
+    ///         Err(i) => failed.push(i),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(vec![5], failed);
+    /// assert_eq!(vec![1, 2], count_of.into_captured_data());
+    /// ```
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+}
+
+impl<Capture, In, Out: ?Sized, Error> FunResRefMut<In, Out, Error>
+    for ClosureResRefMut<Capture, In, Out, Error>
+{
+    fn call_mut(&mut self, input: In) -> Result<&mut Out, Error> {
+        ClosureResRefMut::call_mut(self, input)
+    }
+}
@@ -0,0 +1,158 @@
+use crate::scratch_pool::ScratchPool;
+use std::fmt::Debug;
+
+/// Closure strictly separating the captured data from the function, and additionally receiving a caller-provided scratch space on each call; and hence, having three components:
+///
+/// * `Capture` is any captured data,
+/// * `Scratch` is any temporary workspace provided by the caller, such as a buffer, which is reused across calls,
+/// * `fn(&Capture, &mut Scratch, In) -> Out` is the transformation.
+///
+/// It represents the transformation `In -> Out` which is allowed to use the scratch space during the computation.
+///
+/// This is useful when each call requires temporary allocations, such as a `Vec` which is filled and cleared within the call.
+/// Allocating these inside the function might dominate the run time; whereas, the scratch space allows to allocate once and reuse.
+/// Since the scratch is provided by the caller rather than captured, the closure itself remains immutable and can be shared.
+/// In multi-threaded code, `ScratchPool` can be used to provide each concurrent call its own scratch space.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureScratch` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let successors = vec![vec![1, 2], vec![2], vec![0]];
+///
+/// // number of distinct nodes reachable within two steps
+/// let two_step_reach =
+///     Capture(successors).fun_with_scratch(|succ, visited: &mut Vec<usize>, node: usize| {
+///         visited.clear();
+///         for &a in &succ[node] {
+///             visited.push(a);
+///             visited.extend(succ[a].iter().copied());
+///         }
+///         visited.sort();
+///         visited.dedup();
+///         visited.len()
+///     });
+///
+/// let mut scratch = Vec::new(); // allocated once, reused for all calls
+/// assert_eq!(3, two_step_reach.call(&mut scratch, 0));
+/// assert_eq!(2, two_step_reach.call(&mut scratch, 1));
+/// assert_eq!(3, two_step_reach.call(&mut scratch, 2));
+/// ```
+#[derive(Clone)]
+pub struct ClosureScratch<Capture, Scratch, In, Out> {
+    capture: Capture,
+    fun: fn(&Capture, &mut Scratch, In) -> Out,
+}
+
+impl<Capture: Debug, Scratch, In, Out> Debug for ClosureScratch<Capture, Scratch, In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClosureScratch")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, Scratch, In, Out> ClosureScratch<Capture, Scratch, In, Out> {
+    pub(super) fn new(capture: Capture, fun: fn(&Capture, &mut Scratch, In) -> Out) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Calls the closure with the given `input` using the given `scratch` space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let words = vec!["graph".to_string(), "closure".to_string()];
+    /// let sorted_letters = Capture(words).fun_with_scratch(|words, buffer: &mut Vec<char>, i: usize| {
+    ///     buffer.clear();
+    ///     buffer.extend(words[i].chars());
+    ///     buffer.sort();
+    ///     buffer.iter().collect::<String>()
+    /// });
+    ///
+    /// let mut buffer = vec![];
+    /// assert_eq!("aghpr", sorted_letters.call(&mut buffer, 0));
+    /// assert_eq!("celorsu", sorted_letters.call(&mut buffer, 1));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, scratch: &mut Scratch, input: In) -> Out {
+        (self.fun)(&self.capture, scratch, input)
+    }
+
+    /// Calls the closure with the given `input` using a scratch space borrowed from the `pool`.
+    ///
+    /// The scratch space is returned back to the pool once the call is completed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::thread;
+    ///
+    /// let numbers: Vec<u64> = (0..100).collect();
+    /// let sum_of_divisors = Capture(numbers).fun_with_scratch(|n, buf: &mut Vec<u64>, d: u64| {
+    ///     buf.clear();
+    ///     buf.extend(n.iter().filter(|x| *x % d == 0));
+    ///     buf.iter().sum::<u64>()
+    /// });
+    ///
+    /// let pool = ScratchPool::new(Vec::new);
+    ///
+    /// let sums: Vec<u64> = thread::scope(|s| {
+    ///     let handles: Vec<_> = (1..5)
+    ///         .map(|d| {
+    ///             let (fun, pool) = (&sum_of_divisors, &pool);
+    ///             s.spawn(move || fun.call_pooled(pool, d))
+    ///         })
+    ///         .collect();
+    ///     handles.into_iter().map(|h| h.join().unwrap_or_default()).collect()
+    /// });
+    ///
+    /// assert_eq!(vec![4950, 2450, 1683, 1200], sums);
+    /// ```
+    pub fn call_pooled(&self, pool: &ScratchPool<Scratch>, input: In) -> Out {
+        let mut scratch = pool.get();
+        self.call(&mut scratch, input)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+
+    /// Returns the closure as an `impl Fn(&mut Scratch, In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let offset = 10;
+    /// let add_all = Capture(offset).fun_with_scratch(|o, buf: &mut Vec<i32>, x: &[i32]| {
+    ///     buf.clear();
+    ///     buf.extend(x.iter().map(|x| x + o));
+    ///     buf.iter().sum::<i32>()
+    /// });
+    ///
+    /// let fun = add_all.as_fn();
+    /// let mut buf = vec![];
+    /// assert_eq!(23, fun(&mut buf, &[1, 2]));
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(&mut Scratch, In) -> Out + '_ {
+        move |scratch, x| self.call(scratch, x)
+    }
+}
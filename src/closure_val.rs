@@ -1,5 +1,8 @@
-use crate::fun::Fun;
-use std::fmt::Debug;
+use crate::{
+    fun::{Fun, FunLookup, FunResRef, TryFun, TryFunVal},
+    ClosureChain, ClosureResRef,
+};
+use std::{fmt::Debug, ops::Range};
 
 /// Closure strictly separating the captured data from the function, and hence, having two components:
 ///
@@ -41,7 +44,7 @@ impl<Capture: Debug, In, Out> Debug for Closure<Capture, In, Out> {
 }
 
 impl<Capture, In, Out> Closure<Capture, In, Out> {
-    pub(super) fn new(capture: Capture, fun: fn(&Capture, In) -> Out) -> Self {
+    pub(super) const fn new(capture: Capture, fun: fn(&Capture, In) -> Out) -> Self {
         Self { capture, fun }
     }
 
@@ -63,6 +66,25 @@ impl<Capture, In, Out> Closure<Capture, In, Out> {
         (self.fun)(&self.capture, input)
     }
 
+    /// Calls the closure with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]);
+    /// assert_eq!(2, fun.call_outlined(1));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Out {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> &Capture {
@@ -89,6 +111,246 @@ impl<Capture, In, Out> Closure<Capture, In, Out> {
         self.capture
     }
 
+    /// Consumes the closure, takes out a part of the captured data and returns it together with the closure over the remaining data.
+    ///
+    /// The `take` function receives the captured data by value and returns the taken part `T` together with the captured data to be kept.
+    /// This allows for a staged teardown where a component of the capture is extracted while the closure remains usable, without requiring to rebuild it from scratch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let weights = vec![0, 1, 2];
+    /// let log = vec!["created".to_string()];
+    ///
+    /// let weight = Capture((weights, log)).fun(|(weights, _), i: usize| weights[i]);
+    ///
+    /// let (log, weight) = weight.take_map(|(weights, log)| (log, (weights, vec![])));
+    ///
+    /// assert_eq!(vec!["created".to_string()], log);
+    /// assert_eq!(0, weight.call(0));
+    /// assert!(weight.captured_data().1.is_empty());
+    /// ```
+    pub fn take_map<T>(self, take: impl FnOnce(Capture) -> (T, Capture)) -> (T, Self) {
+        let (taken, capture) = take(self.capture);
+        (taken, Self::new(capture, self.fun))
+    }
+
+    /// Applies `update` on the captured data in place, while keeping the function of the closure.
+    ///
+    /// This allows to patch the captured data, such as pushing a new row of weights, without the round trip of `into_captured_data` and re-building the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let weights = vec![vec![0, 3], vec![3, 0]];
+    /// let mut weight = Capture(weights).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    ///
+    /// // add a new node
+    /// weight.update_capture(|w| {
+    ///     w.iter_mut().for_each(|row| row.push(7));
+    ///     w.push(vec![7, 7, 0]);
+    /// });
+    ///
+    /// assert_eq!(3, weight.call((0, 1)));
+    /// assert_eq!(7, weight.call((2, 1)));
+    /// ```
+    pub fn update_capture(&mut self, update: impl FnOnce(&mut Capture)) {
+        update(&mut self.capture)
+    }
+
+    /// Replaces the function of the closure with the given `fun` and returns the previous function, while keeping the captured data.
+    ///
+    /// This allows to change the behavior of the closure, for instance between strict and lenient versions of a lookup, without moving the captured data out and back in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let tolerance = 2;
+    /// let mut is_close = Capture(tolerance).fun(|t, (a, b): (i32, i32)| (a - b).abs() <= *t);
+    /// assert!(is_close.call((3, 5)));
+    ///
+    /// // strict behavior over the same captured data
+    /// let lenient = is_close.swap_fun(|t, (a, b)| (a - b).abs() < *t);
+    /// assert!(!is_close.call((3, 5)));
+    ///
+    /// // and back
+    /// is_close.swap_fun(lenient);
+    /// assert!(is_close.call((3, 5)));
+    /// ```
+    pub fn swap_fun(&mut self, fun: fn(&Capture, In) -> Out) -> fn(&Capture, In) -> Out {
+        std::mem::replace(&mut self.fun, fun)
+    }
+
+    /// Consumes the closure and returns a new closure with the same captured data and the given `fun`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let offset = 10;
+    /// let add = Capture(offset).fun(|o, x: i32| x + o);
+    /// let subtract = add.clone().with_fun(|o, x| x - o);
+    ///
+    /// assert_eq!(15, add.call(5));
+    /// assert_eq!(-5, subtract.call(5));
+    /// ```
+    pub fn with_fun(self, fun: fn(&Capture, In) -> Out) -> Self {
+        Self::new(self.capture, fun)
+    }
+
+    /// Transforms the closure into one mapping `In2` to `Out2`, by applying `map_in` on the input before and `map_out` on the output after calling this closure.
+    ///
+    /// Both adapters are fused into a single closure with one additional capture slot.
+    /// Compared to stacking an input adapter and an output adapter, the resulting type is shallower and each call goes through a single level of indirection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let jagged = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    /// let weight = Capture(jagged).fun(|x, (i, j): (usize, usize)| x[i][j]);
+    ///
+    /// // flat index -> pair; and weight -> cost
+    /// let cost = weight.map_in_out(|k: usize| (k / 3, k % 3), |w| w as f64 * 0.5);
+    ///
+    /// assert_eq!(0.5, cost.call(1));
+    /// assert_eq!(2.0, cost.call(4));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_in_out<In2, Out2>(
+        self,
+        map_in: fn(In2) -> In,
+        map_out: fn(Out) -> Out2,
+    ) -> Closure<(Self, fn(In2) -> In, fn(Out) -> Out2), In2, Out2> {
+        Closure::new((self, map_in, map_out), |(fun, map_in, map_out), input| {
+            map_out(fun.call(map_in(input)))
+        })
+    }
+
+    /// Chains this closure with the `next` closure into a `ClosureChain` owning both captures;
+    /// i.e., calling the chain with `input` calls `next` with the output of this closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let weights = vec![vec![0, 3], vec![5, 0]];
+    /// let weight = Capture(weights).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    ///
+    /// let unit_cost = 1.5;
+    /// let cost = Capture(unit_cost).fun(|c, w: i32| w as f64 * c);
+    ///
+    /// let edge_cost = weight.then(cost);
+    /// assert_eq!(4.5, edge_cost.call((0, 1)));
+    /// assert_eq!(7.5, edge_cost.call((1, 0)));
+    /// ```
+    pub fn then<C2, Out2>(
+        self,
+        next: Closure<C2, Out, Out2>,
+    ) -> ClosureChain<Capture, C2, In, Out, Out2> {
+        ClosureChain::new(self, next)
+    }
+
+    /// Chains this infallible closure with the fallible `next` stage returning a reference, and returns a single fallible closure;
+    /// i.e., calling the chained closure with `input` calls `next` with the output of this closure, and returns its result.
+    ///
+    /// The reference returned by the chained closure borrows from the captured data of `next`, which is captured by the chained closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let ids: HashMap<&str, usize> = HashMap::from_iter([("john", 0), ("doe", 1)]);
+    /// let id_of = Capture(ids).fun(|ids, name: &str| ids.get(name).copied());
+    ///
+    /// let profiles = vec![String::from("john's profile")];
+    /// let profile_of = Capture(profiles).fun_result_ref(|p, id: Option<usize>| {
+    ///     let id = id.ok_or("unknown user")?;
+    ///     p.get(id).map(|x| x.as_str()).ok_or("missing profile")
+    /// });
+    ///
+    /// let profile = id_of.then_try(profile_of);
+    /// assert_eq!(Ok("john's profile"), profile.call("john"));
+    /// assert_eq!(Err("missing profile"), profile.call("doe"));
+    /// assert_eq!(Err("unknown user"), profile.call("jane"));
+    /// ```
+    pub fn then_try<Next, Out2: ?Sized, Error>(
+        self,
+        next: Next,
+    ) -> ClosureResRef<(Self, Next), In, Out2, Error>
+    where
+        Next: FunResRef<Out, Out2, Error>,
+    {
+        ClosureResRef::new((self, next), |(fun, next), input| {
+            FunResRef::call(next, fun.call(input))
+        })
+    }
+
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight = Capture(vec![vec![1, 2], vec![3, 4]]).fun(|m, (i, j): (usize, usize)| m[i][j]);
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!(3, weight.call(Edge { from: 1, to: 0 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> Closure<(Self, fn(NewIn) -> In), NewIn, Out> {
+        Closure::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
+    /// Transforms the closure into one returning `Out2`, by applying `map_out` on the output of this closure;
+    /// i.e., calling the transformed closure with `input` returns `map_out(self.call(input))`.
+    ///
+    /// The transformed closure owns this closure, and hence, the same captured data.
+    /// This allows post-processing of the output, such as unit conversion, without wrapping the closure in an ad-hoc `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let meters = Capture(vec![1200, 800]).fun(|d, i: usize| d[i]);
+    ///
+    /// let kilometers = meters.map(|m| m as f64 / 1000.0);
+    /// assert_eq!(1.2, kilometers.call(0));
+    /// assert_eq!(0.8, kilometers.call(1));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map<Out2>(self, map_out: fn(Out) -> Out2) -> Closure<(Self, fn(Out) -> Out2), In, Out2> {
+        Closure::new((self, map_out), |(fun, map_out), input| {
+            map_out(fun.call(input))
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> Out` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -114,6 +376,67 @@ impl<Capture, In, Out> Closure<Capture, In, Out> {
     pub fn as_fn(&self) -> impl Fn(In) -> Out + '_ {
         |x| (self.fun)(&self.capture, x)
     }
+
+    /// Consumes the closure and returns it as an `impl Fn(In) -> Out` struct which owns the captured data.
+    ///
+    /// Unlike `as_fn`, the returned function does not borrow the closure; therefore, it can be returned from functions or stored,
+    /// and it is `'static` whenever the captured data is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// fn modulo(base: i32) -> impl Fn(i32) -> i32 {
+    ///     Capture(base).fun(|b, n: i32| n % b).into_fn()
+    /// }
+    ///
+    /// let modulo3 = modulo(3);
+    /// assert_eq!(1, modulo3(7));
+    ///
+    /// let boxed: Box<dyn Fn(i32) -> i32> = Box::new(modulo(4));
+    /// assert_eq!(3, boxed(7));
+    /// ```
+    pub fn into_fn(self) -> impl Fn(In) -> Out {
+        move |x| self.call(x)
+    }
+
+    /// Consumes the closure and seals it into a closure with a compact type, capturing the closure itself as the trait object `Box<dyn Fun<In, Out> + 'a>`.
+    ///
+    /// Closures built on top of other closures, such as the ones capturing another closure, have types which grow with each layer.
+    /// When the nesting gets deep, these types might become hard to read in compiler messages, slow to compile, or even hit the type length limit.
+    /// `seal` collapses the entire type into `Closure<Box<dyn Fun<In, Out> + 'a>, In, Out>`, no matter how deep the closure is.
+    ///
+    /// The price is a heap allocation once when sealing and a dynamic dispatch on each call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// fn add_one<C>(inner: Closure<C, i32, i32>) -> Closure<Closure<C, i32, i32>, i32, i32> {
+    ///     Capture(inner).fun(|inner, x| inner.call(x) + 1)
+    /// }
+    ///
+    /// let base = Capture(10).fun(|b, x: i32| b * x);
+    ///
+    /// // deeply nested type: Closure<Closure<Closure<i32, i32, i32>, i32, i32>, i32, i32>
+    /// let nested = add_one(add_one(base.clone()));
+    /// // compact type: Closure<Box<dyn Fun<i32, i32>>, i32, i32>
+    /// let sealed = nested.seal();
+    /// assert_eq!(42, sealed.call(4));
+    ///
+    /// // sealed closures of different depths share the same type
+    /// let sealed_closures = vec![sealed, base.clone().seal(), add_one(add_one(add_one(base))).seal()];
+    /// let results: Vec<_> = sealed_closures.iter().map(|f| f.call(1)).collect();
+    /// assert_eq!(vec![12, 10, 13], results);
+    /// ```
+    pub fn seal<'a>(self) -> Closure<Box<dyn Fun<In, Out> + 'a>, In, Out>
+    where
+        Self: 'a,
+    {
+        Closure::new(Box::new(self), |fun, input| fun.call(input))
+    }
 }
 
 impl<Capture, In, Out> Fun<In, Out> for Closure<Capture, In, Out> {
@@ -121,3 +444,415 @@ impl<Capture, In, Out> Fun<In, Out> for Closure<Capture, In, Out> {
         Closure::call(self, input)
     }
 }
+
+impl<Capture, K, V: ?Sized> FunLookup<K, V> for Closure<Capture, K, Option<&V>> {
+    fn get(&self, key: K) -> Option<&V> {
+        Closure::call(self, key)
+    }
+}
+
+impl<Capture, In, Out: ?Sized, Error> TryFun<In, Out, Error>
+    for Closure<Capture, In, Result<&Out, Error>>
+{
+    fn try_call(&self, input: In) -> Result<&Out, Error> {
+        Closure::call(self, input)
+    }
+}
+
+impl<Capture, In, Out, Error> TryFunVal<In, Out, Error>
+    for Closure<Capture, In, Result<Out, Error>>
+{
+    fn try_call_val(&self, input: In) -> Result<Out, Error> {
+        Closure::call(self, input)
+    }
+}
+
+impl<Capture, In, Mid, Error> Closure<Capture, In, Result<Mid, Error>> {
+    /// Chains this fallible closure with the infallible `next` stage, and returns a single fallible closure;
+    /// i.e., calling the chained closure with `input` returns the error of this closure if any, or maps the successful output by `next` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let parse = Capture(10).fun(|radix, text: &str| u32::from_str_radix(text, *radix).map_err(|e| e.to_string()));
+    /// let price = Capture(vec![5, 7]).fun(|prices, i: u32| prices.get(i as usize).copied().unwrap_or(0));
+    ///
+    /// let price_of = parse.try_then(price);
+    /// assert_eq!(Ok(7), price_of.call("1"));
+    /// assert_eq!(Ok(0), price_of.call("42"));
+    /// assert!(price_of.call("x").is_err());
+    /// ```
+    pub fn try_then<Next, Out>(self, next: Next) -> Closure<(Self, Next), In, Result<Out, Error>>
+    where
+        Next: Fun<Mid, Out>,
+    {
+        Closure::new((self, next), |(fun, next), input| {
+            fun.call(input).map(|mid| Fun::call(next, mid))
+        })
+    }
+}
+
+impl<Capture, In, Mid> Closure<Capture, In, Option<Mid>> {
+    /// Chains this optional lookup with the optional `next` lookup, and returns a single short-circuiting closure;
+    /// i.e., calling the chained closure with `input` returns `None` if this closure returns `None`, and calls `next` with the found value otherwise.
+    ///
+    /// The resulting closure owns both closures, and hence, the captured data of both.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let user_ids = HashMap::from([("john", 0), ("jane", 2)]);
+    /// let user_id = Capture(user_ids).fun(|ids, name: &str| ids.get(name).copied());
+    ///
+    /// let emails = vec![Some("john@x.com"), None, Some("jane@x.com")];
+    /// let email = Capture(emails).fun(|emails, id: usize| emails.get(id).copied().flatten());
+    ///
+    /// let email_of = user_id.and_then(email);
+    /// assert_eq!(Some("jane@x.com"), email_of.call("jane"));
+    /// assert_eq!(None, email_of.call("doe"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn and_then<D, Out>(
+        self,
+        next: Closure<D, Mid, Option<Out>>,
+    ) -> Closure<(Self, Closure<D, Mid, Option<Out>>), In, Option<Out>> {
+        Closure::new((self, next), |(fun, next), input| {
+            fun.call(input).and_then(|mid| next.call(mid))
+        })
+    }
+}
+
+impl<Capture, In: Clone, Out> Closure<Capture, In, Out> {
+    /// Combines this closure with the `other` closure over the same input into a single closure.
+    ///
+    /// The resulting closure owns both closures, and hence, the captured data of both; and, for each input,
+    /// returns `merge(a, b)` where `a` and `b` are the outputs of this and the other closure, respectively.
+    ///
+    /// Since both closures are called with the same input, `In` is required to be `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let base_costs = vec![vec![0, 4], vec![2, 0]];
+    /// let base_cost = Capture(base_costs).fun(|c, (i, j): (usize, usize)| c[i][j]);
+    ///
+    /// let congested = vec![false, true];
+    /// let penalty = Capture(congested).fun(|c, (_, j): (usize, usize)| if c[j] { 10 } else { 0 });
+    ///
+    /// let cost = base_cost.zip_with(penalty, |c, p| c + p);
+    /// assert_eq!(14, cost.call((0, 1)));
+    /// assert_eq!(2, cost.call((1, 0)));
+    ///
+    /// // both parts can be reclaimed
+    /// let (base_cost, penalty, _) = cost.into_captured_data();
+    /// assert_eq!(vec![vec![0, 4], vec![2, 0]], base_cost.into_captured_data());
+    /// assert_eq!(vec![false, true], penalty.into_captured_data());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn zip_with<Capture2, Out2, Merged>(
+        self,
+        other: Closure<Capture2, In, Out2>,
+        merge: fn(Out, Out2) -> Merged,
+    ) -> Closure<(Self, Closure<Capture2, In, Out2>, fn(Out, Out2) -> Merged), In, Merged> {
+        Closure::new((self, other, merge), |(a, b, merge), input| {
+            merge(a.call(input.clone()), b.call(input))
+        })
+    }
+
+    /// Combines this closure with the `other` closure over the same input into a single closure returning both outputs as a tuple.
+    ///
+    /// The resulting closure owns both closures, and hence, the captured data of both; and, for each input,
+    /// returns `(a, b)` where `a` and `b` are the outputs of this and the other closure, respectively.
+    ///
+    /// Since both closures are called with the same input, `In` is required to be `Clone`.
+    ///
+    /// It is equivalent to `zip_with(other, |a, b| (a, b))`; and it calls the pair through its `Fun<In, (Out, Out2)>` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let weights = vec![vec![0, 4], vec![2, 0]];
+    /// let weight = Capture(weights).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    ///
+    /// let capacities = vec![vec![0, 10], vec![20, 0]];
+    /// let capacity = Capture(capacities).fun(|c, (i, j): (usize, usize)| c[i][j]);
+    ///
+    /// let edge = weight.fanout(capacity);
+    /// assert_eq!((4, 10), edge.call((0, 1)));
+    /// assert_eq!((2, 20), edge.call((1, 0)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn fanout<Capture2, Out2>(
+        self,
+        other: Closure<Capture2, In, Out2>,
+    ) -> Closure<(Self, Closure<Capture2, In, Out2>), In, (Out, Out2)> {
+        Closure::new((self, other), |pair, input| Fun::call(pair, input))
+    }
+}
+
+impl<Capture, In1, In2, Out> Closure<Capture, (In1, In2), Out> {
+    /// Calls the closure over a pair input with the two elements given as separate arguments; i.e., `call2(a, b)` is equivalent to `call((a, b))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let jagged = vec![vec![0, 4], vec![2, 0]];
+    /// let weights = Capture(jagged).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    ///
+    /// assert_eq!(4, weights.call2(0, 1));
+    /// assert_eq!(weights.call((1, 0)), weights.call2(1, 0));
+    /// ```
+    #[inline(always)]
+    pub fn call2(&self, input1: In1, input2: In2) -> Out {
+        self.call((input1, input2))
+    }
+
+    /// Fixes the first element of the pair input to `input1` and returns a closure over the second element;
+    /// i.e., calling the curried closure with `input2` calls this closure with `(input1.clone(), input2)`.
+    ///
+    /// The curried closure captures both this closure and the bound argument.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let at = Capture(matrix).fun(|m, (i, j): (usize, usize)| m[i][j]);
+    ///
+    /// // second_row: Closure<(Closure<Vec<Vec<i32>>, (usize, usize), i32>, usize), usize, i32>
+    /// let second_row = at.curry(1);
+    /// assert_eq!(4, second_row.call(0));
+    /// assert_eq!(6, second_row.call(2));
+    ///
+    /// // original closure is still available
+    /// let (at, i) = second_row.into_captured_data();
+    /// assert_eq!(1, i);
+    /// assert_eq!(2, at.call((0, 1)));
+    /// ```
+    pub fn curry(self, input1: In1) -> Closure<(Self, In1), In2, Out>
+    where
+        In1: Clone,
+    {
+        Closure::new((self, input1), |(fun, input1), input2| {
+            fun.call((input1.clone(), input2))
+        })
+    }
+}
+
+impl<Capture, Inner, In1, In2, Out> Closure<Capture, In1, Closure<Inner, In2, Out>> {
+    /// Flattens a closure returning a closure into a single closure over the pair input;
+    /// i.e., calling the uncurried closure with `(input1, input2)` calls this closure with `input1` and then calls the returned closure with `input2`.
+    ///
+    /// This is the inverse of `curry`, and it is useful to flatten layered captures created by higher-order constructors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// // row_of: Closure<Vec<Vec<i32>>, usize, Closure<Vec<i32>, usize, i32>>
+    /// let row_of = Capture(matrix).fun(|m, i: usize| Capture(m[i].clone()).fun(|row, j: usize| row[j]));
+    /// assert_eq!(5, row_of.call(1).call(1));
+    ///
+    /// let at = row_of.uncurry();
+    /// assert_eq!(3, at.call((0, 2)));
+    /// assert_eq!(4, at.call2(1, 0));
+    /// ```
+    pub fn uncurry(self) -> Closure<Self, (In1, In2), Out> {
+        Closure::new(self, |fun, (input1, input2)| fun.call(input1).call(input2))
+    }
+}
+
+impl<Capture, In1, In2, In3, Out> Closure<Capture, (In1, In2, In3), Out> {
+    /// Calls the closure over a triple input with the three elements given as separate arguments; i.e., `call3(a, b, c)` is equivalent to `call((a, b, c))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let offsets = vec![100, 200];
+    /// let cost = Capture(offsets).fun(|o, (i, j, t): (usize, usize, u32)| o[i] + 10 * j as u32 + t);
+    ///
+    /// assert_eq!(213, cost.call3(1, 1, 3));
+    /// assert_eq!(cost.call((0, 2, 5)), cost.call3(0, 2, 5));
+    /// ```
+    #[inline(always)]
+    pub fn call3(&self, input1: In1, input2: In2, input3: In3) -> Out {
+        self.call((input1, input2, input3))
+    }
+}
+
+impl<Capture, Out> Closure<Capture, (usize, usize), Out> {
+    /// Evaluates the closure on every `(i, j)` pair of the `rows` x `cols` grid, and collects the results in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells `rows * cols` overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let offset = 10;
+    /// let fun = Capture(offset).fun(|o, (i, j): (usize, usize)| o + 10 * i + j);
+    ///
+    /// assert_eq!(vec![10, 11, 12, 20, 21, 22], fun.eval_grid(2, 3));
+    /// assert!(fun.eval_grid(0, 3).is_empty());
+    /// ```
+    pub fn eval_grid(&self, rows: usize, cols: usize) -> Vec<Out> {
+        let mut values = Vec::with_capacity(grid_len(rows, cols));
+        for i in 0..rows {
+            for j in 0..cols {
+                values.push(self.call((i, j)));
+            }
+        }
+        values
+    }
+
+    /// Returns an iterator yielding the results of evaluating the closure on every `(i, j)` pair of the `rows` x `cols` grid in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let jagged = vec![vec![1, 2], vec![3, 4]];
+    /// let fun = Capture(jagged).fun(|x, (i, j): (usize, usize)| x[i][j]);
+    ///
+    /// assert_eq!(Some(4), fun.eval_grid_iter(2, 2).max());
+    /// ```
+    pub fn eval_grid_iter(&self, rows: usize, cols: usize) -> impl Iterator<Item = Out> + '_ {
+        (0..rows).flat_map(move |i| (0..cols).map(move |j| self.call((i, j))))
+    }
+
+    /// Evaluates the closure on every `(i, j)` pair of the `rows` x `cols` grid in parallel, and collects the results in row-major order.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells `rows * cols` overflows `usize`.
+    #[cfg(feature = "rayon")]
+    pub fn par_eval_grid(&self, rows: usize, cols: usize) -> Vec<Out>
+    where
+        Capture: Sync,
+        Out: Send,
+    {
+        use rayon::prelude::*;
+        (0..grid_len(rows, cols))
+            .into_par_iter()
+            .map(|k| self.call((k / cols, k % cols)))
+            .collect()
+    }
+}
+
+impl<Capture, Out> Closure<Capture, usize, Out> {
+    /// Creates a view of the closure over the sub-range `range` of its indices; i.e., calling the view with index `i` calls this closure with `range.start + i`.
+    ///
+    /// This allows windowed access over an indexed capture, such as a `Vec`, without copying the data.
+    ///
+    /// Since the view cannot return a value for indices outside of the window, calling it with such an index panics.
+    /// See `ClosureOptRef::restrict` for a view returning `None` outside of the window.
+    ///
+    /// # Panics
+    ///
+    /// Calling the view panics if `i >= range.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let numbers = vec![0, 10, 20, 30, 40];
+    /// let double = Capture(numbers).fun(|x, i: usize| 2 * x[i]);
+    ///
+    /// let window = double.restrict(2..4);
+    /// assert_eq!(40, window.call(0));
+    /// assert_eq!(60, window.call(1));
+    ///
+    /// // original closure is still available
+    /// let (double, range) = window.into_captured_data();
+    /// assert_eq!(2..4, range);
+    /// assert_eq!(20, double.call(1));
+    /// ```
+    pub fn restrict(self, range: Range<usize>) -> Closure<(Self, Range<usize>), usize, Out> {
+        Closure::new((self, range), |(fun, range), i| {
+            assert!(
+                i < range.len(),
+                "index {} is out of the restricted window {:?}",
+                i,
+                range
+            );
+            fun.call(range.start + i)
+        })
+    }
+}
+
+impl<Capture: Clone, In, Out> Closure<&Capture, In, Out> {
+    /// Consumes this closure borrowing its captured data and returns an equivalent closure owning a clone of the data.
+    ///
+    /// The stored function of the borrowing closure is defined over `&&Capture`; therefore, it cannot be reused over the owned `Capture`.
+    /// Instead, the function is re-bound with the given `fun`.
+    /// Thanks to auto-deref, the very same lambda used to build the borrowing closure can usually be passed in as it is.
+    ///
+    /// This allows a closure prototyped over borrowed data to be promoted into one that can be stored or sent elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let weights = vec![vec![1, 2], vec![3, 4]];
+    /// let borrowing = Capture(&weights).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    /// assert_eq!(3, borrowing.call((1, 0)));
+    ///
+    /// // same lambda, now over an owned copy of the data
+    /// let owned: Closure<Vec<Vec<i32>>, (usize, usize), i32> = borrowing.into_owning(|w, (i, j)| w[i][j]);
+    /// drop(weights);
+    /// assert_eq!(3, owned.call((1, 0)));
+    /// ```
+    pub fn into_owning(self, fun: fn(&Capture, In) -> Out) -> Closure<Capture, In, Out> {
+        Closure::new(self.capture.clone(), fun)
+    }
+
+    /// Returns a closure owning a clone of the captured data and using the given `fun`, leaving this borrowing closure intact.
+    ///
+    /// See [`into_owning`](Self::into_owning) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let weights = vec![vec![1, 2], vec![3, 4]];
+    /// let borrowing = Capture(&weights).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    ///
+    /// let owned = borrowing.to_owned_capture(|w, (i, j)| w[i][j]);
+    /// assert_eq!(borrowing.call((0, 1)), owned.call((0, 1)));
+    /// assert_eq!(&weights, owned.captured_data());
+    /// ```
+    pub fn to_owned_capture(&self, fun: fn(&Capture, In) -> Out) -> Closure<Capture, In, Out> {
+        Self::new(self.capture, self.fun).into_owning(fun)
+    }
+}
+
+fn grid_len(rows: usize, cols: usize) -> usize {
+    rows.checked_mul(cols)
+        .expect("number of grid cells rows * cols overflows usize")
+}
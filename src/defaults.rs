@@ -0,0 +1,197 @@
+//! Closures with empty captures which are commonly used as default values.
+//!
+//! Each function takes no arguments and returns a closure; therefore, it can be used as the body of a `Default` implementation
+//! or directly as a serde default such as `#[serde(default = "orx_closure::defaults::always_true")]` for structs holding closures.
+//!
+//! Constants are provided for the frequent case of `usize` indexed closures, which can additionally be used in `const` and `static` items.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orx_closure::*;
+//!
+//! struct Filter {
+//!     is_allowed: Closure<(), usize, bool>,
+//!     weight_of: Closure<(), usize, f64>,
+//! }
+//!
+//! impl Default for Filter {
+//!     fn default() -> Self {
+//!         Self {
+//!             is_allowed: defaults::always_true(),
+//!             weight_of: defaults::always_one(),
+//!         }
+//!     }
+//! }
+//!
+//! let filter = Filter::default();
+//! assert!(filter.is_allowed.call(42));
+//! assert_eq!(1.0, filter.weight_of.call(42));
+//!
+//! static NONE_ALLOWED: Closure<(), usize, bool> = defaults::USIZE_ALWAYS_FALSE;
+//! assert!(!NONE_ALLOWED.call(0));
+//! ```
+
+use crate::{Closure, ClosureOptRef};
+
+/// Primitive numeric types having the constants zero and one, which are returned by `always_zero` and `always_one`.
+///
+/// It is implemented by all primitive integer types, from `i8` to `u128` including `isize` and `usize`, and by `f32` and `f64`.
+/// The trait is sealed; i.e., it cannot be implemented outside of this crate.
+pub trait Number: sealed::Sealed {
+    /// Zero value of the type.
+    const ZERO: Self;
+    /// One value of the type.
+    const ONE: Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_number {
+    ($zero:literal, $one:literal, $($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Number for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+impl_number!(0, 1, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number!(0.0, 1.0, f32, f64);
+
+/// Closure returning `true` for any `usize` input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::defaults;
+///
+/// assert!(defaults::USIZE_ALWAYS_TRUE.call(7));
+/// ```
+pub const USIZE_ALWAYS_TRUE: Closure<(), usize, bool> = Closure::new((), |_, _| true);
+
+/// Closure returning `false` for any `usize` input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::defaults;
+///
+/// assert!(!defaults::USIZE_ALWAYS_FALSE.call(7));
+/// ```
+pub const USIZE_ALWAYS_FALSE: Closure<(), usize, bool> = Closure::new((), |_, _| false);
+
+/// Closure returning the `usize` input itself.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::defaults;
+///
+/// assert_eq!(7, defaults::USIZE_IDENTITY.call(7));
+/// ```
+pub const USIZE_IDENTITY: Closure<(), usize, usize> = Closure::new((), |_, i| i);
+
+/// Returns a closure returning `true` for any input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let accept_all: Closure<(), &str, bool> = defaults::always_true();
+/// assert!(accept_all.call("anything"));
+/// ```
+pub fn always_true<In>() -> Closure<(), In, bool> {
+    Closure::new((), |_, _| true)
+}
+
+/// Returns a closure returning `false` for any input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let reject_all: Closure<(), &str, bool> = defaults::always_false();
+/// assert!(!reject_all.call("anything"));
+/// ```
+pub fn always_false<In>() -> Closure<(), In, bool> {
+    Closure::new((), |_, _| false)
+}
+
+/// Returns a closure returning zero for any input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let no_cost: Closure<(), (usize, usize), f64> = defaults::always_zero();
+/// assert_eq!(0.0, no_cost.call((1, 2)));
+/// ```
+pub fn always_zero<In, Out: Number>() -> Closure<(), In, Out> {
+    Closure::new((), |_, _| Out::ZERO)
+}
+
+/// Returns a closure returning one for any input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let unit_cost: Closure<(), (usize, usize), u64> = defaults::always_one();
+/// assert_eq!(1, unit_cost.call((1, 2)));
+/// ```
+pub fn always_one<In, Out: Number>() -> Closure<(), In, Out> {
+    Closure::new((), |_, _| Out::ONE)
+}
+
+/// Returns a closure returning the default value of `Out` for any input.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let no_label: Closure<(), usize, String> = defaults::always_default();
+/// assert_eq!("", no_label.call(3));
+/// ```
+pub fn always_default<In, Out: Default>() -> Closure<(), In, Out> {
+    Closure::new((), |_, _| Out::default())
+}
+
+/// Returns a closure returning its input as it is.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let same: Closure<(), char, char> = defaults::identity();
+/// assert_eq!('x', same.call('x'));
+/// ```
+pub fn identity<T>() -> Closure<(), T, T> {
+    Closure::new((), |_, x| x)
+}
+
+/// Returns a closure returning `None` for any input; i.e., a lookup over an empty collection.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let no_names: ClosureOptRef<(), usize, str> = defaults::always_none();
+/// assert_eq!(None, no_names.call(0));
+/// ```
+pub fn always_none<In, Out: ?Sized>() -> ClosureOptRef<(), In, Out> {
+    ClosureOptRef::new((), |_, _| None)
+}
@@ -0,0 +1,79 @@
+use crate::ClosureOnce;
+use std::fmt::Debug;
+
+/// Wraps the `closure` into a scope guard which calls it when the guard is dropped, unless the guard is cancelled.
+///
+/// It is an explicit-capture alternative to scope guards built on anonymous closures:
+/// the data needed by the deferred action is visible in the type as `Capture`, and it can be inspected or taken back before the guard runs.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::cell::RefCell;
+///
+/// let log = RefCell::new(vec![]);
+/// {
+///     let _guard = defer(Capture(&log).fun_once(|log, _: ()| log.borrow_mut().push("cleanup")));
+///     log.borrow_mut().push("work");
+/// }
+/// assert_eq!(vec!["work", "cleanup"], log.into_inner());
+/// ```
+pub fn defer<Capture>(closure: ClosureOnce<Capture, (), ()>) -> Defer<Capture> {
+    Defer {
+        closure: Some(closure),
+    }
+}
+
+/// A scope guard created by [`defer`] which calls its once-closure when dropped, unless it is cancelled.
+pub struct Defer<Capture> {
+    closure: Option<ClosureOnce<Capture, (), ()>>,
+}
+
+impl<Capture: Debug> Debug for Defer<Capture> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Defer")
+            .field("capture", self.captured_data())
+            .finish()
+    }
+}
+
+impl<Capture> Defer<Capture> {
+    /// Returns a reference to the data captured by the deferred closure.
+    pub fn captured_data(&self) -> &Capture {
+        match &self.closure {
+            Some(closure) => closure.captured_data(),
+            None => unreachable!("closure is only taken on drop or cancel"),
+        }
+    }
+
+    /// Cancels the deferred call and returns back the captured data, without calling the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let mut files = vec!["tmp.txt"];
+    /// {
+    ///     let guard = defer(Capture(&mut files).fun_once(|f, _: ()| f.clear()));
+    ///     let files = guard.cancel(); // e.g., the operation succeeded, temporary files are kept
+    ///     files.push("out.txt");
+    /// }
+    /// assert_eq!(vec!["tmp.txt", "out.txt"], files);
+    /// ```
+    pub fn cancel(mut self) -> Capture {
+        match self.closure.take() {
+            Some(closure) => closure.into_captured_data(),
+            None => unreachable!("closure is only taken on drop or cancel"),
+        }
+    }
+}
+
+impl<Capture> Drop for Defer<Capture> {
+    fn drop(&mut self) {
+        if let Some(closure) = self.closure.take() {
+            closure.call_once(());
+        }
+    }
+}
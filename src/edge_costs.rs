@@ -0,0 +1,154 @@
+//! Adapters allowing closures of this crate to be used directly as edge cost functions of `petgraph` algorithms.
+//!
+//! Algorithms such as `petgraph::algo::dijkstra` or `petgraph::algo::astar` expect the edge cost as an `FnMut(EdgeRef) -> K`.
+//! The adapters in this module create such functions from any `Fun` implementation, such as `Closure` or `ClosureOneOf2`,
+//! by mapping the edge reference to the input of the closure:
+//!
+//! * `by_endpoints(&fun)` calls `fun` with `(source, target)` node ids of the edge,
+//! * `by_weight(&fun)` calls `fun` with a clone of the edge weight,
+//! * `by_id(&fun)` calls `fun` with the edge id,
+//! * `by(&fun, map)` calls `fun` with `map(edge)`.
+//!
+//! Requires the `petgraph` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orx_closure::{edge_costs, *};
+//! use petgraph::{algo::dijkstra, graph::NodeIndex, Graph};
+//!
+//! let mut graph = Graph::<(), ()>::new();
+//! let [a, b, c] = [(); 3].map(|_| graph.add_node(()));
+//! graph.extend_with_edges([(a, b), (b, c), (a, c)]);
+//!
+//! // costs are kept in a captured matrix rather than as edge weights
+//! let costs = vec![vec![0, 1, 5], vec![0, 0, 2], vec![0, 0, 0]];
+//! let cost = Capture(costs).fun(|c, (i, j): (NodeIndex, NodeIndex)| c[i.index()][j.index()]);
+//!
+//! let distances = dijkstra(&graph, a, None, edge_costs::by_endpoints(&cost));
+//! assert_eq!(Some(&3), distances.get(&c));
+//! ```
+
+use crate::fun::Fun;
+use ::petgraph::visit::EdgeRef;
+
+/// Creates an edge cost function calling `fun` with the `(source, target)` node ids of the edge.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::{edge_costs, *};
+/// use petgraph::{algo::astar, graph::NodeIndex, Graph};
+///
+/// let mut graph = Graph::<(), ()>::new();
+/// let [a, b, c] = [(); 3].map(|_| graph.add_node(()));
+/// graph.extend_with_edges([(a, b), (b, c), (a, c)]);
+///
+/// let cost = Capture(10).fun(|k, (i, j): (NodeIndex, NodeIndex)| k * (i.index() + j.index()));
+///
+/// let (total, path) = astar(&graph, a, |n| n == c, edge_costs::by_endpoints(&cost), |_| 0).unwrap();
+/// assert_eq!(20, total);
+/// assert_eq!(vec![a, c], path);
+/// ```
+pub fn by_endpoints<'a, E, F, K>(fun: &'a F) -> impl FnMut(E) -> K + 'a
+where
+    E: EdgeRef,
+    F: Fun<(E::NodeId, E::NodeId), K>,
+{
+    move |edge| fun.call((edge.source(), edge.target()))
+}
+
+/// Creates an edge cost function calling `fun` with a clone of the weight of the edge.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::{edge_costs, *};
+/// use petgraph::{algo::dijkstra, Graph};
+///
+/// #[derive(Clone, Copy)]
+/// enum Road {
+///     Highway(u32),
+///     Street(u32),
+/// }
+///
+/// let mut graph = Graph::<(), Road>::new();
+/// let [a, b, c] = [(); 3].map(|_| graph.add_node(()));
+/// graph.extend_with_edges([(a, b, Road::Highway(10)), (b, c, Road::Highway(10)), (a, c, Road::Street(10))]);
+///
+/// let speeds = (2, 1); // highway, street
+/// let duration = Capture(speeds).fun(|s, road: Road| match road {
+///     Road::Highway(km) => km / s.0,
+///     Road::Street(km) => km / s.1,
+/// });
+///
+/// let durations = dijkstra(&graph, a, None, edge_costs::by_weight(&duration));
+/// assert_eq!(Some(&10), durations.get(&c));
+/// ```
+pub fn by_weight<'a, E, F, K>(fun: &'a F) -> impl FnMut(E) -> K + 'a
+where
+    E: EdgeRef,
+    E::Weight: Clone,
+    F: Fun<E::Weight, K>,
+{
+    move |edge| fun.call(edge.weight().clone())
+}
+
+/// Creates an edge cost function calling `fun` with the id of the edge.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::{edge_costs, *};
+/// use petgraph::{algo::dijkstra, graph::EdgeIndex, Graph};
+///
+/// let mut graph = Graph::<(), ()>::new();
+/// let [a, b, c] = [(); 3].map(|_| graph.add_node(()));
+/// graph.extend_with_edges([(a, b), (b, c), (a, c)]);
+///
+/// let tolls = vec![1, 1, 7]; // indexed by edge index
+/// let toll = Capture(tolls).fun(|t, e: EdgeIndex| t[e.index()]);
+///
+/// let costs = dijkstra(&graph, a, None, edge_costs::by_id(&toll));
+/// assert_eq!(Some(&2), costs.get(&c));
+/// ```
+pub fn by_id<'a, E, F, K>(fun: &'a F) -> impl FnMut(E) -> K + 'a
+where
+    E: EdgeRef,
+    F: Fun<E::EdgeId, K>,
+{
+    move |edge| fun.call(edge.id())
+}
+
+/// Creates an edge cost function calling `fun` with the input obtained by applying `map` on the edge reference.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::{edge_costs, *};
+/// use petgraph::{algo::dijkstra, graph::EdgeReference, visit::EdgeRef, Graph};
+///
+/// let mut graph = Graph::<(), u32>::new();
+/// let [a, b, c] = [(); 3].map(|_| graph.add_node(()));
+/// graph.extend_with_edges([(a, b, 2), (b, c, 2), (a, c, 3)]);
+///
+/// // cost depends on both the target node and the edge weight
+/// let penalties = vec![0, 0, 10];
+/// let cost = Capture(penalties).fun(|p, (target, w): (usize, u32)| w + p[target]);
+///
+/// let costs = dijkstra(
+///     &graph,
+///     a,
+///     None,
+///     edge_costs::by(&cost, |e: EdgeReference<u32>| (e.target().index(), *e.weight())),
+/// );
+/// assert_eq!(Some(&13), costs.get(&c));
+/// ```
+pub fn by<'a, E, In, F, K>(fun: &'a F, map: fn(E) -> In) -> impl FnMut(E) -> K + 'a
+where
+    E: EdgeRef + 'a,
+    In: 'a,
+    F: Fun<In, K>,
+{
+    move |edge| fun.call(map(edge))
+}
@@ -0,0 +1,105 @@
+use std::{fmt::Debug, ops::Deref, sync::Arc};
+
+/// A captured resource guarded by a cleanup function which runs exactly once, when the last handle to the resource is dropped.
+///
+/// It attaches RAII semantics to the captured data of closures, such as deregistering a listener tied to a captured handle:
+///
+/// * cloning the closure, or the `EnvCapture` itself, shares the same resource through reference counting;
+/// * converting the closure into a union or wrapping it into other closures moves the resource without running the cleanup;
+/// * the cleanup runs once the closure and all of its clones are dropped.
+///
+/// `EnvCapture<T>` dereferences to `T`; therefore, the function of the closure is written exactly as it would be over a `T` capture.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static NUM_LISTENERS: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn register(threshold: u32) -> EnvCapture<u32> {
+///     NUM_LISTENERS.fetch_add(1, Ordering::SeqCst);
+///     EnvCapture::new(threshold, |_| {
+///         NUM_LISTENERS.fetch_sub(1, Ordering::SeqCst);
+///     })
+/// }
+///
+/// let is_hot = Capture(register(30)).fun(|t, x: u32| x > **t);
+/// assert_eq!(1, NUM_LISTENERS.load(Ordering::SeqCst));
+/// assert!(is_hot.call(35));
+///
+/// let clone = is_hot.clone();
+/// let union: ClosureOneOf2<EnvCapture<u32>, (), u32, bool> = is_hot.into_oneof2_var1();
+///
+/// drop(union);
+/// assert_eq!(1, NUM_LISTENERS.load(Ordering::SeqCst)); // clone is still alive
+///
+/// drop(clone);
+/// assert_eq!(0, NUM_LISTENERS.load(Ordering::SeqCst));
+/// ```
+pub struct EnvCapture<T> {
+    resource: Arc<Guarded<T>>,
+}
+
+struct Guarded<T> {
+    value: T,
+    cleanup: fn(&mut T),
+}
+
+impl<T> Drop for Guarded<T> {
+    fn drop(&mut self) {
+        (self.cleanup)(&mut self.value)
+    }
+}
+
+impl<T> EnvCapture<T> {
+    /// Creates a guarded capture of the `value` which calls `cleanup` on the value once all handles to it are dropped.
+    pub fn new(value: T, cleanup: fn(&mut T)) -> Self {
+        Self {
+            resource: Arc::new(Guarded { value, cleanup }),
+        }
+    }
+
+    /// Returns the number of handles sharing the resource, such as the clones of the closure capturing it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let env = EnvCapture::new(vec![1, 2], |v| v.clear());
+    /// let len = Capture(env).fun(|v, _: ()| v.len());
+    /// assert_eq!(1, len.captured_data().num_handles());
+    ///
+    /// let clone = len.clone();
+    /// assert_eq!(2, clone.captured_data().num_handles());
+    /// ```
+    pub fn num_handles(&self) -> usize {
+        Arc::strong_count(&self.resource)
+    }
+}
+
+impl<T> Clone for EnvCapture<T> {
+    fn clone(&self) -> Self {
+        Self {
+            resource: Arc::clone(&self.resource),
+        }
+    }
+}
+
+impl<T> Deref for EnvCapture<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.resource.value
+    }
+}
+
+impl<T: Debug> Debug for EnvCapture<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnvCapture")
+            .field("value", &self.resource.value)
+            .finish()
+    }
+}
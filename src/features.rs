@@ -0,0 +1,43 @@
+//! Compile-time flags describing the optional features the crate was built with.
+//!
+//! Downstream code can use these constants to introspect the build, for instance to pick a parallel code path only when `rayon` support is available.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orx_closure::features;
+//!
+//! for (name, enabled) in features::ALL {
+//!     println!("{}: {}", name, enabled);
+//! }
+//!
+//! assert_eq!(features::RAYON, cfg!(feature = "rayon"));
+//! ```
+
+/// Whether the crate is built with the `either` feature, providing conversions between `OneOf2` and `either::Either`.
+pub const EITHER: bool = cfg!(feature = "either");
+
+/// Whether the crate is built with the `mmap` feature, providing `MmapSlice` to capture memory-mapped files as slices.
+pub const MMAP: bool = cfg!(feature = "mmap");
+
+/// Whether the crate is built with the `petgraph` feature, providing the `edge_costs` adapters for `petgraph` algorithms.
+pub const PETGRAPH: bool = cfg!(feature = "petgraph");
+
+/// Whether the crate is built with the `rayon` feature, providing parallel evaluation methods such as `par_eval_grid`.
+pub const RAYON: bool = cfg!(feature = "rayon");
+
+/// Whether the crate is built with the `serde` feature, providing `Serialize` and `Deserialize` implementations for `OneOf2`, `OneOf3` and `OneOf4`.
+pub const SERDE: bool = cfg!(feature = "serde");
+
+/// Whether the crate is built with the `testing` feature, providing the test doubles of the `testing` module such as `MockFun` and `AllocTracked`.
+pub const TESTING: bool = cfg!(feature = "testing");
+
+/// Names of all optional features of the crate together with whether or not they are enabled in the current build.
+pub const ALL: [(&str, bool); 6] = [
+    ("either", EITHER),
+    ("mmap", MMAP),
+    ("petgraph", PETGRAPH),
+    ("rayon", RAYON),
+    ("serde", SERDE),
+    ("testing", TESTING),
+];
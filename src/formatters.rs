@@ -0,0 +1,123 @@
+//! Closures capturing string templates and rendering them into `String`s.
+//!
+//! Templates are captured as `Cow<'static, str>`, so that both static string literals and runtime-built templates can be captured without additional type parameters.
+//!
+//! * `template(text)` creates a `Closure<Template, Args, String>` replacing each `{}` placeholder of the template with the next argument,
+//! * `constant(text)` creates a closure of the very same type which ignores its arguments,
+//! * `segments(text)` creates a `ClosureOptRef<Template, usize, str>` returning the literal segments between the placeholders.
+//!
+//! Since `template` and `constant` closures have the same type, they can be stored in the same field or collection without requiring a union.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orx_closure::formatters::*;
+//!
+//! struct EdgeLabeler {
+//!     label: Formatter<[usize; 2]>,
+//! }
+//!
+//! let verbose = EdgeLabeler { label: template("edge from {} to {}") };
+//! assert_eq!("edge from 3 to 7", verbose.label.call([3, 7]));
+//!
+//! let silent = EdgeLabeler { label: constant("edge") };
+//! assert_eq!("edge", silent.label.call([3, 7]));
+//! ```
+
+use crate::{Capture, Closure, ClosureOptRef};
+use std::{borrow::Cow, fmt::Display};
+
+/// Captured template text.
+pub type Template = Cow<'static, str>;
+
+/// A closure capturing a `Template` and rendering it into a `String` with the given `Args`.
+pub type Formatter<Args> = Closure<Template, Args, String>;
+
+const PLACEHOLDER: &str = "{}";
+
+/// Creates a closure capturing the `template` and rendering it with the given arguments.
+///
+/// Each `{}` placeholder in the template is replaced by the next argument in order:
+///
+/// * placeholders for which there does not exist an argument are kept as they are,
+/// * arguments for which there does not exist a placeholder are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::formatters::*;
+///
+/// let label = template("{} -> {}");
+/// assert_eq!("a -> b", label.call(vec!["a", "b"]));
+/// assert_eq!("a -> {}", label.call(vec!["a"]));
+///
+/// let label = template(String::from("node #{}"));
+/// assert_eq!("node #42", label.call(vec![42]));
+/// ```
+pub fn template<Args>(template: impl Into<Template>) -> Formatter<Args>
+where
+    Args: IntoIterator,
+    Args::Item: Display,
+{
+    Capture(template.into()).fun(render)
+}
+
+/// Creates a closure capturing the `text` and returning it as is, ignoring its arguments.
+///
+/// It has the same type as the closures created by `template`; and hence, is useful as a constant alternative of a formatter.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::formatters::*;
+///
+/// let labels: Vec<Formatter<[i32; 1]>> = vec![template("value={}"), constant("hidden")];
+///
+/// let rendered: Vec<_> = labels.iter().map(|l| l.call([42])).collect();
+/// assert_eq!(vec!["value=42", "hidden"], rendered);
+/// ```
+pub fn constant<Args>(text: impl Into<Template>) -> Formatter<Args> {
+    Capture(text.into()).fun(|text, _| text.to_string())
+}
+
+/// Creates a closure capturing the `template` and returning a reference to its `i`-th literal segment, which are the pieces of the template between the `{}` placeholders.
+///
+/// Returns `None` if the template has less than `i + 1` segments.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::formatters::*;
+///
+/// let segment = segments("from {} to {}!");
+/// assert_eq!(Some("from "), segment.call(0));
+/// assert_eq!(Some(" to "), segment.call(1));
+/// assert_eq!(Some("!"), segment.call(2));
+/// assert_eq!(None, segment.call(3));
+/// ```
+pub fn segments(template: impl Into<Template>) -> ClosureOptRef<Template, usize, str> {
+    Capture(template.into()).fun_option_ref(|template, i| template.split(PLACEHOLDER).nth(i))
+}
+
+fn render<Args>(template: &Template, args: Args) -> String
+where
+    Args: IntoIterator,
+    Args::Item: Display,
+{
+    let mut args = args.into_iter();
+    let mut rendered = String::with_capacity(template.len());
+    let mut pieces = template.split(PLACEHOLDER);
+
+    if let Some(first) = pieces.next() {
+        rendered.push_str(first);
+    }
+    for piece in pieces {
+        match args.next() {
+            Some(arg) => rendered.push_str(&arg.to_string()),
+            None => rendered.push_str(PLACEHOLDER),
+        }
+        rendered.push_str(piece);
+    }
+
+    rendered
+}
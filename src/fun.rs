@@ -1,3 +1,10 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+    rc::Rc,
+    sync::Arc,
+};
+
 /// Function trait representing `In -> Out` transformation.
 ///
 /// It provides the common interface for closures, such as `Closure<Capture, In, Out>`, over all capture types.
@@ -10,9 +17,65 @@
 /// The reason it co-exists is that it is not possible to implement `fn_traits` in stable version.
 ///
 /// However, all that implements `Fn(In) -> Out` also auto-implements `Fun<In, Out>`.
+///
+/// # Tuples
+///
+/// Tuples of two, three or four functions sharing the same input type are also functions.
+/// Given that `In: Clone`, `(F1, F2)` implements `Fun<In, (O1, O2)>` whenever `F1: Fun<In, O1>` and `F2: Fun<In, O2>`; and similarly for the larger tuples.
+/// Each function is called with a clone of the input and the outputs are collected in the same order.
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn describe<F: Fun<usize, (i32, bool)>>(fun: &F, i: usize) -> String {
+///     let (value, is_tabu) = fun.call(i);
+///     format!("{}{}", value, if is_tabu { "*" } else { "" })
+/// }
+///
+/// let values = vec![10, 20, 30];
+/// let tabu = vec![1];
+///
+/// let value = Capture(values).fun(|x, i: usize| x[i]);
+/// let is_tabu = Capture(tabu).fun(|x, i: usize| x.contains(&i));
+///
+/// let fun = (value, is_tabu);
+/// assert_eq!("10", describe(&fun, 0));
+/// assert_eq!("20*", describe(&fun, 1));
+/// ```
+///
+/// # Newtype inputs
+///
+/// `call_into` accepts any `impl Into<In>`; hence, a newtype index can be passed directly whenever it converts into the input type.
+/// The same convenience is provided by all function traits of the crate, such as `FunRef::call_into`, `FunMut::call_mut_into` or `FunOnce::call_once_into`.
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// #[derive(Clone, Copy)]
+/// struct NodeId(usize);
+///
+/// impl From<NodeId> for usize {
+///     fn from(id: NodeId) -> usize {
+///         id.0
+///     }
+/// }
+///
+/// let demand = Capture(vec![5, 0, 3]).fun(|d, i: usize| d[i]);
+///
+/// assert_eq!(3, demand.call_into(NodeId(2)));
+/// assert_eq!(5, demand.call_into(0usize));
+/// ```
 pub trait Fun<In, Out> {
     /// Calls the function with the given `input` and returns the produced output.
     fn call(&self, input: In) -> Out;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_into(&self, input: impl Into<In>) -> Out
+    where
+        Self: Sized,
+    {
+        self.call(input.into())
+    }
 }
 impl<In, Out, F: Fn(In) -> Out> Fun<In, Out> for F {
     fn call(&self, input: In) -> Out {
@@ -20,6 +83,48 @@ impl<In, Out, F: Fn(In) -> Out> Fun<In, Out> for F {
     }
 }
 
+// tuples: element-wise evaluation
+
+impl<In: Clone, O1, O2, F1: Fun<In, O1>, F2: Fun<In, O2>> Fun<In, (O1, O2)> for (F1, F2) {
+    fn call(&self, input: In) -> (O1, O2) {
+        (self.0.call(input.clone()), self.1.call(input))
+    }
+}
+
+impl<In: Clone, O1, O2, O3, F1: Fun<In, O1>, F2: Fun<In, O2>, F3: Fun<In, O3>> Fun<In, (O1, O2, O3)>
+    for (F1, F2, F3)
+{
+    fn call(&self, input: In) -> (O1, O2, O3) {
+        (
+            self.0.call(input.clone()),
+            self.1.call(input.clone()),
+            self.2.call(input),
+        )
+    }
+}
+
+impl<
+        In: Clone,
+        O1,
+        O2,
+        O3,
+        O4,
+        F1: Fun<In, O1>,
+        F2: Fun<In, O2>,
+        F3: Fun<In, O3>,
+        F4: Fun<In, O4>,
+    > Fun<In, (O1, O2, O3, O4)> for (F1, F2, F3, F4)
+{
+    fn call(&self, input: In) -> (O1, O2, O3, O4) {
+        (
+            self.0.call(input.clone()),
+            self.1.call(input.clone()),
+            self.2.call(input.clone()),
+            self.3.call(input),
+        )
+    }
+}
+
 /// Function trait representing `In -> &Out` transformation.
 ///
 /// It provides the common interface for closures, such as `ClosureRef<Capture, In, Out>`, over all capture types.
@@ -32,9 +137,37 @@ impl<In, Out, F: Fn(In) -> Out> Fun<In, Out> for F {
 ///
 /// However, it appears to be impossible to have an instance of the latter due to lifetime errors.
 /// Therefore, `FunRef<In, Out>` is required.
+///
+/// # Shared ownership
+///
+/// `Rc<F>` and `Arc<F>` implement `FunRef<In, Out>` whenever `F` does, including `F = dyn FunRef<In, Out>`.
+/// This allows to share a closure owning its captured data while still returning references into the shared capture.
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::rc::Rc;
+///
+/// let names = vec!["john".to_string(), "doe".to_string()];
+/// let name_of = Capture(names).fun_ref(|names, i: usize| names[i].as_str());
+///
+/// // 'static, cheaply cloneable, and still returns references into the capture
+/// let shared: Rc<dyn FunRef<usize, str>> = Rc::new(name_of);
+/// let other = shared.clone();
+///
+/// assert_eq!("john", shared.call(0));
+/// assert_eq!("doe", other.call(1));
+/// ```
 pub trait FunRef<In, Out: ?Sized> {
     /// Calls the function with the given `input` and returns the produced output.
     fn call(&self, input: In) -> &Out;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_into(&self, input: impl Into<In>) -> &Out
+    where
+        Self: Sized,
+    {
+        self.call(input.into())
+    }
 }
 
 /// Function trait representing `In -> Option<&Out>` transformation.
@@ -49,9 +182,21 @@ pub trait FunRef<In, Out: ?Sized> {
 ///
 /// However, it appears to be impossible to have an instance of the latter due to lifetime errors.
 /// Therefore, `FunOptRef<In, Out>` is required.
+///
+/// # Shared ownership
+///
+/// `Rc<F>` and `Arc<F>` implement `FunOptRef<In, Out>` whenever `F` does, including `F = dyn FunOptRef<In, Out>`.
 pub trait FunOptRef<In, Out: ?Sized> {
     /// Calls the function with the given `input` and returns the produced output.
     fn call(&self, input: In) -> Option<&Out>;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_into(&self, input: impl Into<In>) -> Option<&Out>
+    where
+        Self: Sized,
+    {
+        self.call(input.into())
+    }
 }
 
 /// Function trait representing `In -> Result<&Out, Error>` transformation.
@@ -66,7 +211,510 @@ pub trait FunOptRef<In, Out: ?Sized> {
 ///
 /// However, it appears to be impossible to have an instance of the latter due to lifetime errors.
 /// Therefore, `FunResRef<In, Out, Error>` is required.
+///
+/// # Shared ownership
+///
+/// `Rc<F>` and `Arc<F>` implement `FunResRef<In, Out, Error>` whenever `F` does, including `F = dyn FunResRef<In, Out, Error>`.
 pub trait FunResRef<In, Out: ?Sized, Error> {
     /// Calls the function with the given `input` and returns the produced output.
     fn call(&self, input: In) -> Result<&Out, Error>;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_into(&self, input: impl Into<In>) -> Result<&Out, Error>
+    where
+        Self: Sized,
+    {
+        self.call(input.into())
+    }
+}
+
+impl<In, Out: ?Sized, F: FunRef<In, Out> + ?Sized> FunRef<In, Out> for Rc<F> {
+    fn call(&self, input: In) -> &Out {
+        (**self).call(input)
+    }
+}
+
+impl<In, Out: ?Sized, F: FunRef<In, Out> + ?Sized> FunRef<In, Out> for Arc<F> {
+    fn call(&self, input: In) -> &Out {
+        (**self).call(input)
+    }
+}
+
+impl<In, Out: ?Sized, F: FunOptRef<In, Out> + ?Sized> FunOptRef<In, Out> for Rc<F> {
+    fn call(&self, input: In) -> Option<&Out> {
+        (**self).call(input)
+    }
+}
+
+impl<In, Out: ?Sized, F: FunOptRef<In, Out> + ?Sized> FunOptRef<In, Out> for Arc<F> {
+    fn call(&self, input: In) -> Option<&Out> {
+        (**self).call(input)
+    }
+}
+
+impl<In, Out: ?Sized, Error, F: FunResRef<In, Out, Error> + ?Sized> FunResRef<In, Out, Error>
+    for Rc<F>
+{
+    fn call(&self, input: In) -> Result<&Out, Error> {
+        (**self).call(input)
+    }
+}
+
+impl<In, Out: ?Sized, Error, F: FunResRef<In, Out, Error> + ?Sized> FunResRef<In, Out, Error>
+    for Arc<F>
+{
+    fn call(&self, input: In) -> Result<&Out, Error> {
+        (**self).call(input)
+    }
+}
+
+/// Lookup trait representing a read-only map view `K -> Option<&V>`.
+///
+/// Optional-returning closures are very often nothing but a view over a map-like captured data, such as a `HashMap`, a `Vec` or a sparse matrix.
+/// `FunLookup` formalizes this pattern with the richer semantics of a map: `get`, `contains` and `len_hint`.
+///
+/// It is implemented by:
+///
+/// * `ClosureOptRef<Capture, K, V>` and its unions `ClosureOptRefOneOf2`, `ClosureOptRefOneOf3` and `ClosureOptRefOneOf4`;
+/// * `Closure<Capture, K, Option<&V>>`, which is the natural choice when the map is captured by reference;
+/// * the map-like collections which are commonly captured, the slice `[V]` with `usize` keys, `HashMap<K, V>` and `BTreeMap<K, V>`.
+///
+/// A `Vec<V>` is used as a lookup through its slice, such as `vec.as_slice()`;
+/// implementing the trait on `Vec<V>` directly would shadow the slice methods `get` and `contains` wherever the trait is in scope.
+///
+/// `len_hint` returns the number of entries for the collections.
+/// The closures return `None` since their functions may expose only a part of, or may not even be backed by, the captured data.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::collections::HashMap;
+///
+/// fn describe<L: FunLookup<char, usize>>(lookup: &L, key: char) -> String {
+///     match lookup.get(key) {
+///         Some(value) => format!("{} -> {}", key, value),
+///         None => format!("{} is missing", key),
+///     }
+/// }
+///
+/// let map: HashMap<char, usize> = HashMap::from_iter([('a', 1), ('b', 2)]);
+///
+/// // captured by reference
+/// let lookup = Capture(&map).fun(|m, c| m.get(&c));
+/// assert_eq!("a -> 1", describe(&lookup, 'a'));
+/// assert!(!lookup.contains('z'));
+///
+/// // captured by value
+/// let lookup = Capture(map).fun_option_ref(|m, c| m.get(&c));
+/// assert_eq!("z is missing", describe(&lookup, 'z'));
+/// assert!(lookup.contains('b'));
+/// ```
+pub trait FunLookup<K, V: ?Sized> {
+    /// Returns a reference to the value corresponding to the `key`; `None` if the `key` is absent.
+    fn get(&self, key: K) -> Option<&V>;
+
+    /// Returns whether or not there exists a value corresponding to the `key`.
+    fn contains(&self, key: K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of present entries if it is known; `None` otherwise.
+    ///
+    /// Closures cannot inspect their captured data; therefore, the default implementation returns `None`.
+    /// Implementations backed by a collection, such as `[V]` or `HashMap<K, V>`, override it to return the number of entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let names: HashMap<usize, &str> = HashMap::from_iter([(0, "john"), (7, "doe")]);
+    /// assert_eq!(Some(2), names.len_hint());
+    ///
+    /// let lookup = Capture(names).fun_option_ref(|n, i: usize| n.get(&i));
+    /// assert_eq!(None, lookup.len_hint());
+    /// ```
+    fn len_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<V> FunLookup<usize, V> for [V] {
+    fn get(&self, key: usize) -> Option<&V> {
+        <[V]>::get(self, key)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> FunLookup<K, V> for HashMap<K, V, S> {
+    fn get(&self, key: K) -> Option<&V> {
+        HashMap::get(self, &key)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K: Ord, V> FunLookup<K, V> for BTreeMap<K, V> {
+    fn get(&self, key: K) -> Option<&V> {
+        BTreeMap::get(self, &key)
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Fallible function trait representing `In -> Result<&Out, Error>` transformation, allowing generic code to bound on a single trait and use `?` on the result uniformly.
+///
+/// It is implemented by:
+///
+/// * `ClosureResRef<Capture, In, Out, Error>` and its unions `ClosureResRefOneOf2`, `ClosureResRefOneOf3` and `ClosureResRefOneOf4`;
+/// * `Closure<Capture, In, Result<&Out, Error>>`, which is the natural choice when the data is captured by reference;
+/// * `Rc<F>` and `Arc<F>` whenever `F` implements it, including `F = dyn TryFun<In, Out, Error>`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Missing(usize);
+///
+/// fn path_length<F: TryFun<usize, i32, Missing>>(lengths: &F, path: &[usize]) -> Result<i32, Missing> {
+///     let mut total = 0;
+///     for i in path {
+///         total += lengths.try_call(*i)?;
+///     }
+///     Ok(total)
+/// }
+///
+/// let lengths = vec![3, 5, 7];
+///
+/// // captured by reference
+/// let by_ref = Capture(&lengths).fun(|x, i: usize| x.get(i).ok_or(Missing(i)));
+/// assert_eq!(Ok(8), path_length(&by_ref, &[0, 1]));
+///
+/// // captured by value
+/// let by_val = Capture(lengths.clone()).fun_result_ref(|x, i: usize| x.get(i).ok_or(Missing(i)));
+/// assert_eq!(Err(Missing(3)), path_length(&by_val, &[2, 3, 0]));
+/// ```
+pub trait TryFun<In, Out: ?Sized, Error> {
+    /// Calls the function with the given `input` and returns the result, which is ready to be used with the `?` operator.
+    fn try_call(&self, input: In) -> Result<&Out, Error>;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn try_call_into(&self, input: impl Into<In>) -> Result<&Out, Error>
+    where
+        Self: Sized,
+    {
+        self.try_call(input.into())
+    }
+}
+
+impl<In, Out: ?Sized, Error, F: TryFun<In, Out, Error> + ?Sized> TryFun<In, Out, Error> for Rc<F> {
+    fn try_call(&self, input: In) -> Result<&Out, Error> {
+        (**self).try_call(input)
+    }
+}
+
+impl<In, Out: ?Sized, Error, F: TryFun<In, Out, Error> + ?Sized> TryFun<In, Out, Error> for Arc<F> {
+    fn try_call(&self, input: In) -> Result<&Out, Error> {
+        (**self).try_call(input)
+    }
+}
+
+/// Fallible function trait representing `In -> Result<Out, Error>` transformation with an owned successful output,
+/// which is the counterpart of `TryFun` for the closures computing their outputs rather than lending them.
+///
+/// It is implemented by:
+///
+/// * `Closure<Capture, In, Result<Out, Error>>` and its unions `ClosureOneOf2`, `ClosureOneOf3` and `ClosureOneOf4`;
+/// * `Rc<F>` and `Arc<F>` whenever `F` implements it, including `F = dyn TryFunVal<In, Out, Error>`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Missing(usize);
+///
+/// fn total_cost<F: TryFunVal<usize, u32, Missing>>(cost: &F, path: &[usize]) -> Result<u32, Missing> {
+///     let mut total = 0;
+///     for i in path {
+///         total += cost.try_call_val(*i)?;
+///     }
+///     Ok(total)
+/// }
+///
+/// let distances = vec![3, 5, 7];
+/// let cost = Capture(distances).fun(|d, i: usize| d.get(i).map(|x| 2 * x).ok_or(Missing(i)));
+///
+/// assert_eq!(Ok(16), total_cost(&cost, &[0, 1]));
+/// assert_eq!(Err(Missing(3)), total_cost(&cost, &[2, 3]));
+/// ```
+pub trait TryFunVal<In, Out, Error> {
+    /// Calls the function with the given `input` and returns the result, which is ready to be used with the `?` operator.
+    fn try_call_val(&self, input: In) -> Result<Out, Error>;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn try_call_val_into(&self, input: impl Into<In>) -> Result<Out, Error>
+    where
+        Self: Sized,
+    {
+        self.try_call_val(input.into())
+    }
+}
+
+impl<In, Out, Error, F: TryFunVal<In, Out, Error> + ?Sized> TryFunVal<In, Out, Error> for Rc<F> {
+    fn try_call_val(&self, input: In) -> Result<Out, Error> {
+        (**self).try_call_val(input)
+    }
+}
+
+impl<In, Out, Error, F: TryFunVal<In, Out, Error> + ?Sized> TryFunVal<In, Out, Error> for Arc<F> {
+    fn try_call_val(&self, input: In) -> Result<Out, Error> {
+        (**self).try_call_val(input)
+    }
+}
+
+/// Function trait representing `In -> &mut Out` transformation, which mutably borrows its captured data on each call.
+///
+/// It provides the common interface for closures, such as `ClosureRefMut<Capture, In, Out>`, over all capture types.
+///
+/// Furthermore, this trait enables to forget about the capture, or equivalently drop the `Capture` generic parameter, by using `dyn FunRefMut<In, Out>` trait object.
+///
+/// # Relation with `FnMut`
+///
+/// `FunRefMut<In, Out>` can be considered equivalent to `FnMut(In) -> &mut Out` where the returned reference borrows from the function itself.
+///
+/// However, it is not possible to express the latter with the `FnMut` trait due to lifetime errors.
+/// Therefore, `FunRefMut<In, Out>` is required.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn reset_all<F: FunRefMut<usize, i32>>(fun: &mut F, indices: &[usize]) {
+///     for i in indices {
+///         *fun.call_mut(*i) = 0;
+///     }
+/// }
+///
+/// let mut values = Capture(vec![1, 2, 3]).fun_ref_mut(|v, i: usize| &mut v[i]);
+/// reset_all(&mut values, &[0, 2]);
+/// assert_eq!(&vec![0, 2, 0], values.captured_data());
+/// ```
+pub trait FunRefMut<In, Out: ?Sized> {
+    /// Calls the function with the given `input` and returns the produced mutable reference.
+    fn call_mut(&mut self, input: In) -> &mut Out;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_mut_into(&mut self, input: impl Into<In>) -> &mut Out
+    where
+        Self: Sized,
+    {
+        self.call_mut(input.into())
+    }
+}
+
+/// Function trait representing `In -> Option<&mut Out>` transformation, which mutably borrows its captured data on each call.
+///
+/// It provides the common interface for closures, such as `ClosureOptRefMut<Capture, In, Out>`, over all capture types.
+///
+/// Furthermore, this trait enables to forget about the capture, or equivalently drop the `Capture` generic parameter, by using `dyn FunOptRefMut<In, Out>` trait object.
+///
+/// # Relation with `FnMut`
+///
+/// `FunOptRefMut<In, Out>` can be considered equivalent to `FnMut(In) -> Option<&mut Out>` where the returned reference borrows from the function itself.
+///
+/// However, it is not possible to express the latter with the `FnMut` trait due to lifetime errors.
+/// Therefore, `FunOptRefMut<In, Out>` is required.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn reset_present<F: FunOptRefMut<usize, i32>>(fun: &mut F, indices: &[usize]) -> usize {
+///     let mut num_reset = 0;
+///     for i in indices {
+///         if let Some(x) = fun.call_mut(*i) {
+///             *x = 0;
+///             num_reset += 1;
+///         }
+///     }
+///     num_reset
+/// }
+///
+/// let mut values = Capture(vec![1, 2, 3]).fun_option_ref_mut(|v, i: usize| v.get_mut(i));
+/// assert_eq!(2, reset_present(&mut values, &[0, 2, 4]));
+/// assert_eq!(&vec![0, 2, 0], values.captured_data());
+/// ```
+pub trait FunOptRefMut<In, Out: ?Sized> {
+    /// Calls the function with the given `input` and returns the produced optional mutable reference.
+    fn call_mut(&mut self, input: In) -> Option<&mut Out>;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_mut_into(&mut self, input: impl Into<In>) -> Option<&mut Out>
+    where
+        Self: Sized,
+    {
+        self.call_mut(input.into())
+    }
+}
+
+/// Function trait representing `In -> Result<&mut Out, Error>` transformation, which mutably borrows its captured data on each call.
+///
+/// It provides the common interface for closures, such as `ClosureResRefMut<Capture, In, Out, Error>`, over all capture types.
+///
+/// Furthermore, this trait enables to forget about the capture, or equivalently drop the `Capture` generic parameter, by using `dyn FunResRefMut<In, Out, Error>` trait object.
+///
+/// # Relation with `FnMut`
+///
+/// `FunResRefMut<In, Out, Error>` can be considered equivalent to `FnMut(In) -> Result<&mut Out, Error>` where the returned reference borrows from the function itself.
+///
+/// However, it is not possible to express the latter with the `FnMut` trait due to lifetime errors.
+/// Therefore, `FunResRefMut<In, Out, Error>` is required.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn reset_all<F: FunResRefMut<usize, i32, usize>>(fun: &mut F, indices: &[usize]) -> Result<(), usize> {
+///     for i in indices {
+///         *fun.call_mut(*i)? = 0;
+///     }
+///     Ok(())
+/// }
+///
+/// let mut values = Capture(vec![1, 2, 3]).fun_result_ref_mut(|v, i: usize| v.get_mut(i).ok_or(i));
+/// assert_eq!(Ok(()), reset_all(&mut values, &[0, 2]));
+/// assert_eq!(Err(7), reset_all(&mut values, &[1, 7]));
+/// assert_eq!(&vec![0, 0, 0], values.captured_data());
+/// ```
+pub trait FunResRefMut<In, Out: ?Sized, Error> {
+    /// Calls the function with the given `input` and returns the produced mutable reference or the error.
+    fn call_mut(&mut self, input: In) -> Result<&mut Out, Error>;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_mut_into(&mut self, input: impl Into<In>) -> Result<&mut Out, Error>
+    where
+        Self: Sized,
+    {
+        self.call_mut(input.into())
+    }
+}
+
+/// Function trait representing `In -> Out` transformation, which is allowed to mutate its captured data on each call.
+///
+/// It provides the common interface for closures, such as `ClosureMut<Capture, In, Out>`, over all capture types.
+///
+/// Furthermore, this trait enables to forget about the capture, or equivalently drop the `Capture` generic parameter, by using `dyn FunMut<In, Out>` trait object.
+///
+/// # Relation with `FnMut`
+///
+/// `FunMut<In, Out>` can be considered equivalent to `FnMut(In) -> Out`.
+/// The reason it co-exists is that it is not possible to implement `fn_traits` in stable version.
+///
+/// However, all that implements `FnMut(In) -> Out` also auto-implements `FunMut<In, Out>`.
+/// Therefore, ordinary Rust closures can be passed to the APIs written against `FunMut`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn take_ids<F: FunMut<(), usize>>(fun: &mut F, n: usize) -> Vec<usize> {
+///     (0..n).map(|_| fun.call_mut(())).collect()
+/// }
+///
+/// let mut next_id = Capture(10).fun_mut(|next, _: ()| {
+///     *next += 1;
+///     *next - 1
+/// });
+///
+/// assert_eq!(vec![10, 11], take_ids(&mut next_id, 2));
+/// assert_eq!(vec![12], take_ids(&mut next_id, 1));
+///
+/// // or any FnMut
+/// let mut next = 0;
+/// let mut std_closure = |_: ()| {
+///     next += 2;
+///     next
+/// };
+/// assert_eq!(vec![2, 4], take_ids(&mut std_closure, 2));
+/// ```
+pub trait FunMut<In, Out> {
+    /// Calls the function with the given `input` and returns the produced output.
+    fn call_mut(&mut self, input: In) -> Out;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_mut_into(&mut self, input: impl Into<In>) -> Out
+    where
+        Self: Sized,
+    {
+        self.call_mut(input.into())
+    }
+}
+impl<In, Out, F: FnMut(In) -> Out> FunMut<In, Out> for F {
+    fn call_mut(&mut self, input: In) -> Out {
+        self(input)
+    }
+}
+
+/// Function trait representing `In -> Out` transformation, which can be called only once since it consumes itself.
+///
+/// It provides the common interface for closures, such as `ClosureOnce<Capture, In, Out>`, over all capture types.
+///
+/// # Relation with `FnOnce`
+///
+/// `FunOnce<In, Out>` can be considered equivalent to `FnOnce(In) -> Out`.
+/// The reason it co-exists is that it is not possible to implement `fn_traits` in stable version.
+///
+/// However, all that implements `FnOnce(In) -> Out` also auto-implements `FunOnce<In, Out>`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn on_shutdown<F: FunOnce<u32, String>>(teardown: F) -> String {
+///     teardown.call_once(0)
+/// }
+///
+/// let teardown = Capture(vec!["db", "cache"]).fun_once(|resources, code: u32| {
+///     format!("released {} with code {}", resources.join(" & "), code)
+/// });
+/// assert_eq!("released db & cache with code 0", on_shutdown(teardown));
+///
+/// // or any FnOnce
+/// let name = String::from("server");
+/// assert_eq!("server:0", on_shutdown(move |code| format!("{}:{}", name, code)));
+/// ```
+pub trait FunOnce<In, Out> {
+    /// Consumes the function, calls it with the given `input` and returns the produced output.
+    fn call_once(self, input: In) -> Out;
+
+    /// Calls the function with the `input` converted into `In`, which allows to pass newtype wrappers such as `NodeId(usize)` without unwrapping.
+    fn call_once_into(self, input: impl Into<In>) -> Out
+    where
+        Self: Sized,
+    {
+        self.call_once(input.into())
+    }
+}
+impl<In, Out, F: FnOnce(In) -> Out> FunOnce<In, Out> for F {
+    fn call_once(self, input: In) -> Out {
+        self(input)
+    }
 }
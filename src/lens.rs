@@ -0,0 +1,102 @@
+use std::fmt::Debug;
+
+/// Lens pairing a read and a write accessor over the same captured data, and hence, having three components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&Capture, In) -> &V` is the getter reading the value at the position `In`,
+/// * `fn(&mut Capture, In, V)` is the setter writing the value at the position `In`.
+///
+/// It extends the idea of storing a function as a field to read/write accessors of a captured store.
+///
+/// Note that, unlike trait objects of fn-traits, `Lens` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// struct Grid {
+///     cols: usize,
+///     cells: Vec<char>,
+/// }
+///
+/// // cell: Lens<Grid, (usize, usize), char>
+/// let mut cell = Capture(Grid { cols: 2, cells: vec!['.'; 4] }).lens(
+///     |g, (i, j): (usize, usize)| &g.cells[i * g.cols + j],
+///     |g, (i, j), value| g.cells[i * g.cols + j] = value,
+/// );
+///
+/// cell.set((1, 0), '#');
+///
+/// assert_eq!(&'#', cell.get((1, 0)));
+/// assert_eq!(&'.', cell.get((0, 1)));
+/// ```
+#[derive(Clone)]
+pub struct Lens<Capture, In, V> {
+    capture: Capture,
+    get: fn(&Capture, In) -> &V,
+    set: fn(&mut Capture, In, V),
+}
+
+impl<Capture: Debug, In, V> Debug for Lens<Capture, In, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lens")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, V> Lens<Capture, In, V> {
+    pub(super) fn new(
+        capture: Capture,
+        get: fn(&Capture, In) -> &V,
+        set: fn(&mut Capture, In, V),
+    ) -> Self {
+        Self { capture, get, set }
+    }
+
+    /// Reads the value at the position `input` of the captured data.
+    #[inline(always)]
+    pub fn get(&self, input: In) -> &V {
+        (self.get)(&self.capture, input)
+    }
+
+    /// Writes the `value` at the position `input` of the captured data.
+    #[inline(always)]
+    pub fn set(&mut self, input: In, value: V) {
+        (self.set)(&mut self.capture, input, value)
+    }
+
+    /// Replaces the value at the position `input` with the result of `update` applied on the current value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    ///
+    /// let mut score = Capture(vec![10, 20]).lens(|s, i: usize| &s[i], |s, i, value| s[i] = value);
+    ///
+    /// score.update(1, |x| x + 5);
+    ///
+    /// assert_eq!(&25, score.get(1));
+    /// assert_eq!(vec![10, 25], score.into_captured_data());
+    /// ```
+    pub fn update(&mut self, input: In, update: impl FnOnce(&V) -> V)
+    where
+        In: Clone,
+    {
+        let value = update(self.get(input.clone()));
+        self.set(input, value)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the lens and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+}
@@ -242,6 +242,31 @@
 //! assert_eq!(closure_res_ref.call(()), Ok(&42));
 //! ```
 //!
+//! ### A.4. Capturing by Mutable Reference
+//!
+//! A closure can also lend mutable access to existing data for its lifetime by capturing `&mut T`. The mutable closure variants `fun_mut`, `fun_ref_mut`, `fun_option_ref_mut` and `fun_result_ref_mut` work with such captures out of the box; thanks to auto-deref, the function is written exactly as it would be over an owned capture. The borrow ends when the closure is dropped, or it can be handed back with `into_captured_data`.
+//!
+//! ```rust
+//! use orx_closure::*;
+//!
+//! let mut log = vec![];
+//! {
+//!     let mut record = Capture(&mut log).fun_mut(|log, event: &str| {
+//!         log.push(event.to_string());
+//!         log.len()
+//!     });
+//!     assert_eq!(1, record.call_mut("start"));
+//!     assert_eq!(2, record.call_mut("stop"));
+//! }
+//! assert_eq!(vec!["start", "stop"], log);
+//!
+//! let mut counts = vec![0, 0];
+//! let mut count_of = Capture(&mut counts).fun_ref_mut(|c, i: usize| &mut c[i]);
+//! *count_of.call_mut(1) += 5;
+//! let counts: &mut Vec<i32> = count_of.into_captured_data();
+//! assert_eq!(&vec![0, 5], counts);
+//! ```
+//!
 //!
 //! ## B. Abstraction over the Captured Data
 //!
@@ -339,25 +364,57 @@
 //!
 //! We are not able to implement `fn_traits` in stable rust; however as discussed, abstraction over the captured data type is the core power of closures. In order to achieve this flexibility, this crate provides the required traits `Fun`, `FunRef`, `FunOptRef` and `FunResRef`. The following table provides the complete list of traits and types implementing them.
 //!
-//! | Trait                       | Transformation              | Struct                                                |
-//! |-----------------------------|-----------------------------|-------------------------------------------------------|
-//! | `Fun<In, Out>`              | `In -> Out`                 | `T where T: Fn(In) -> Out`                            |
-//! |                             |                             | `Closure<Capture, In, Out>`                           |
-//! |                             |                             | `ClosureOneOf2<C1, C2, In, Out>`                      |
-//! |                             |                             | `ClosureOneOf3<C1, C2, C3, In, Out>`                  |
-//! |                             |                             | `ClosureOneOf4<C1, C2, C3, C4, In, Out>`              |
-//! | `FunRef<In, Out>`           | `In -> &Out`                | `ClosureRef<Capture, In, Out>`                        |
-//! |                             |                             | `ClosureRefOneOf2<C1, C2, In, Out>`                   |
-//! |                             |                             | `ClosureRefOneOf3<C1, C2, C3, In, Out>`               |
-//! |                             |                             | `ClosureRefOneOf4<C1, C2, C3, C4, In, Out>`           |
-//! | `FunOptRef<In, Out>`        | `In -> Option<&Out>`        | `ClosureOptRef<Capture, In, Out>`                     |
-//! |                             |                             | `ClosureOptRefOneOf2<C1, C2, In, Out>`                |
-//! |                             |                             | `ClosureOptRefOneOf3<C1, C2, C3, In, Out>`            |
-//! |                             |                             | `ClosureOptRefOneOf4<C1, C2, C3, C4, In, Out>`        |
-//! | `FunResRef<In, Out, Error>` | `In -> Result<&Out, Error>` | `ClosureResRef<Capture, In, Out, Error>`              |
-//! |                             |                             | `ClosureResRefOneOf2<C1, C2, In, Out, Error>`         |
-//! |                             |                             | `ClosureResRefOneOf3<C1, C2, C3, In, Out, Error>`     |
-//! |                             |                             | `ClosureResRefOneOf4<C1, C2, C3, C4, In, Out, Error>` |
+//! | Trait                          | Transformation                  | Struct                                                |
+//! | ------------------------------ | ------------------------------- | ----------------------------------------------------- |
+//! | `Fun<In, Out>`                 | `In -> Out`                     | `T where T: Fn(In) -> Out`                            |
+//! |                                |                                 | `Closure<Capture, In, Out>`                           |
+//! |                                |                                 | `ClosureOneOf1<C, In, Out>`                           |
+//! |                                |                                 | `ClosureOneOf2<C1, C2, In, Out>`                      |
+//! |                                |                                 | `ClosureOneOf3<C1, C2, C3, In, Out>`                  |
+//! |                                |                                 | `ClosureOneOf4<C1, C2, C3, C4, In, Out>`              |
+//! |                                |                                 | `Closure2<Capture, In1, In2, Out>`                    |
+//! |                                |                                 | `Closure3<Capture, In1, In2, In3, Out>`               |
+//! |                                |                                 | `ClosureChain<C1, C2, In, Mid, Out>`                  |
+//! | `FunMut<In, Out>`              | `In -> Out`                     | `T where T: FnMut(In) -> Out`                         |
+//! |                                |                                 | `ClosureMut<Capture, In, Out>`                        |
+//! |                                |                                 | `ClosureMutOneOf2<C1, C2, In, Out>`                   |
+//! |                                |                                 | `ClosureMutOneOf3<C1, C2, C3, In, Out>`               |
+//! |                                |                                 | `ClosureMutOneOf4<C1, C2, C3, C4, In, Out>`           |
+//! | `FunOnce<In, Out>`             | `In -> Out`                     | `T where T: FnOnce(In) -> Out`                        |
+//! |                                |                                 | `ClosureOnce<Capture, In, Out>`                       |
+//! |                                |                                 | `ClosureOnceOneOf2<C1, C2, In, Out>`                  |
+//! | `FunRef<In, Out>`              | `In -> &Out`                    | `ClosureRef<Capture, In, Out>`                        |
+//! |                                |                                 | `ClosureRefOneOf2<C1, C2, In, Out>`                   |
+//! |                                |                                 | `ClosureRefOneOf3<C1, C2, C3, In, Out>`               |
+//! |                                |                                 | `ClosureRefOneOf4<C1, C2, C3, C4, In, Out>`           |
+//! |                                |                                 | `ClosureRef2<Capture, In1, In2, Out>`                 |
+//! | `FunRefMut<In, Out>`           | `In -> &mut Out`                | `ClosureRefMut<Capture, In, Out>`                     |
+//! | `FunOptRef<In, Out>`           | `In -> Option<&Out>`            | `ClosureOptRef<Capture, In, Out>`                     |
+//! |                                |                                 | `ClosureOptRefOneOf2<C1, C2, In, Out>`                |
+//! |                                |                                 | `ClosureOptRefOneOf3<C1, C2, C3, In, Out>`            |
+//! |                                |                                 | `ClosureOptRefOneOf4<C1, C2, C3, C4, In, Out>`        |
+//! |                                |                                 | `ClosureOptRef2<Capture, In1, In2, Out>`              |
+//! | `FunOptRefMut<In, Out>`        | `In -> Option<&mut Out>`        | `ClosureOptRefMut<Capture, In, Out>`                  |
+//! | `FunResRef<In, Out, Error>`    | `In -> Result<&Out, Error>`     | `ClosureResRef<Capture, In, Out, Error>`              |
+//! |                                |                                 | `ClosureResRefOneOf2<C1, C2, In, Out, Error>`         |
+//! |                                |                                 | `ClosureResRefOneOf3<C1, C2, C3, In, Out, Error>`     |
+//! |                                |                                 | `ClosureResRefOneOf4<C1, C2, C3, C4, In, Out, Error>` |
+//! |                                |                                 | `ClosureResRef2<Capture, In1, In2, Out, Error>`       |
+//! | `FunResRefMut<In, Out, Error>` | `In -> Result<&mut Out, Error>` | `ClosureResRefMut<Capture, In, Out, Error>`           |
+//! | `FunLookup<K, V>`              | `K -> Option<&V>`               | `Closure<Capture, K, Option<&V>>`                     |
+//! |                                |                                 | `ClosureOptRef<Capture, K, V>`                        |
+//! |                                |                                 | `ClosureOptRefOneOf2<C1, C2, K, V>`                   |
+//! |                                |                                 | `ClosureOptRefOneOf3<C1, C2, C3, K, V>`               |
+//! |                                |                                 | `ClosureOptRefOneOf4<C1, C2, C3, C4, K, V>`           |
+//! | `TryFun<In, Out, Error>`       | `In -> Result<&Out, Error>`     | `Closure<Capture, In, Result<&Out, Error>>`           |
+//! |                                |                                 | `ClosureResRef<Capture, In, Out, Error>`              |
+//! |                                |                                 | `ClosureResRefOneOf2<C1, C2, In, Out, Error>`         |
+//! |                                |                                 | `ClosureResRefOneOf3<C1, C2, C3, In, Out, Error>`     |
+//! |                                |                                 | `ClosureResRefOneOf4<C1, C2, C3, C4, In, Out, Error>` |
+//! | `TryFunVal<In, Out, Error>`    | `In -> Result<Out, Error>`      | `Closure<Capture, In, Result<Out, Error>>`            |
+//! |                                |                                 | `ClosureOneOf2<C1, C2, In, Result<Out, Error>>`       |
+//! |                                |                                 | `ClosureOneOf3<C1, C2, C3, In, Result<Out, Error>>`   |
+//! |                                |                                 | `ClosureOneOf4<.., In, Result<Out, Error>>`           |
 //!
 //! The fun traits are useful due to the following:
 //!
@@ -373,6 +430,44 @@
 //!
 //! Instead, `Closure` and all variants have the `as_fn` method, such as `fn as_fn(&self) -> impl Fn(In) -> Out + '_ `, which gives us the compiler generated closure implementing the `Fn` trait.
 //!
+//! One might also expect to call a stored closure as `(provider.weights)(edge)` through a `Deref<Target = impl Fn(In) -> Out>` implementation. This is not possible either. `Deref` must return a reference to a callable that lives inside the closure; however, the callable calling `fun` with `&capture` would be a reference into the very same struct. Such self-referential structs cannot be built in safe rust, and I did not want to introduce `unsafe` code only to save the `.call`.
+//!
+//! The closest we get is to bind the result of `as_fn` once and use the call syntax afterwards:
+//!
+//! ```rust
+//! use orx_closure::*;
+//!
+//! struct Provider {
+//!     weights: Closure<Vec<Vec<i32>>, (usize, usize), i32>,
+//! }
+//!
+//! let provider = Provider {
+//!     weights: Capture(vec![vec![0, 4], vec![2, 0]]).fun(|w, (i, j)| w[i][j]),
+//! };
+//!
+//! let weights = provider.weights.as_fn();
+//! assert_eq!(4, weights((0, 1)));
+//! assert_eq!(2, weights((1, 0)));
+//! ```
+//!
+//! The benchmark defined in `/benches/call_syntax` shows that calling through the bound `as_fn` performs the same as `call`; hence, this is purely a matter of taste.
+//!
+//! Since every `Fn` is also an `FnMut`, the function returned by `as_fn` can directly be passed to APIs expecting `FnMut` callbacks, such as `Iterator::map` or `Iterator::filter_map`, without writing `|x| c.call(x)` shims. The closure is only borrowed; when the same adapter is needed by several consumers, it can be bound once and passed as `&f` or `&mut f`. One caveat is the reference inputs: the returned function is not generic over the lifetime of its input; therefore, it does not satisfy bounds such as `for<'a> FnMut(&'a T)` required by `slice::sort_by_key` or `Vec::retain`. In these cases, mapping over copied items or a thin shim is required.
+//!
+//! ```rust
+//! use orx_closure::*;
+//!
+//! let lengths = Capture(vec!["john", "doe"]).fun(|names, i: usize| names[i].len());
+//!
+//! let mut f = lengths.as_fn();
+//! assert_eq!(vec![4, 3], (0..2).map(&mut f).collect::<Vec<_>>());
+//! assert_eq!(7, (0..2).map(f).sum::<usize>());
+//! ```
+//!
+//! The function returned by `as_fn` borrows the closure; hence, it cannot outlive it. When a long-lived callback is required, such as one stored in a struct, returned from a function or moved into another thread, the closure unions provide `to_fn(self: Arc<Self>)`. The returned function owns a shared reference to the closure. Since it cannot hand out references to the data it owns, the reference-returning unions convert their outputs to the owned counterpart, such as `String` for `str`, with `ToOwned`.
+//!
+//! When binary size matters more than the cost of a function call, such as when many union instantiations are called from many places, `call_outlined` can be used instead of `call`. It is never inlined; therefore, the closure is compiled once per type rather than once per call site. The benchmark defined in `/benches/call_outlined` measures the cost of this boundary.
+//!
 //! ## E. Benchmarks & Performance
 //!
 //! Assume we have the requirement to hold a function as a field of a struct. In the example case defined in `/benches/fun_as_a_field`, we hold the function that accesses two-index access to a jagged array.
@@ -453,36 +548,119 @@
     clippy::missing_panics_doc,
     clippy::todo
 )]
+#![forbid(unsafe_code)]
 
+mod accumulator;
+mod arity2;
+mod arity3;
+mod call_value;
 mod capture;
+mod capture_hub;
+mod capture_multi;
+mod capture_visitor;
+mod closure_array_of;
+mod closure_bit_set;
+mod closure_call_value;
+mod closure_chain;
+mod closure_factory;
+mod closure_fold;
+mod closure_key;
+mod closure_mut;
+mod closure_once;
 mod closure_opt_ref;
+mod closure_opt_ref_mut;
 mod closure_ref;
+mod closure_ref_mut;
 mod closure_res_ref;
+mod closure_res_ref_mut;
+mod closure_scratch;
 mod closure_val;
+pub mod defaults;
+mod defer;
+#[cfg(feature = "petgraph")]
+pub mod edge_costs;
+mod env_capture;
+pub mod features;
+pub mod formatters;
 mod fun;
+mod lens;
+#[cfg(feature = "mmap")]
+mod mmap_slice;
 mod one_of;
+mod one_of_macro;
 mod one_of_variants;
+mod path_macro;
+mod piecewise;
+mod pipeline_macro;
+mod recording;
+mod scratch_pool;
+mod select_by;
+mod setter;
+#[cfg(feature = "testing")]
+pub mod testing;
 
+pub use accumulator::Accumulator;
+pub use arity2::{
+    closure_opt_ref::ClosureOptRef2, closure_ref::ClosureRef2, closure_res_ref::ClosureResRef2,
+    closure_val::Closure2,
+};
+pub use arity3::closure_val::Closure3;
+pub use call_value::CallValue;
 pub use capture::Capture;
+pub use capture_hub::CaptureHub;
+pub use capture_multi::{Capture2, Capture3};
+pub use capture_visitor::CaptureVisitor;
+pub use closure_array_of::ClosureArrayOf;
+pub use closure_bit_set::{BitMatrix, ClosureBitSet};
+pub use closure_call_value::ClosureCallValue;
+pub use closure_chain::ClosureChain;
+pub use closure_factory::ClosureFactory;
+pub use closure_fold::ClosureFold;
+pub use closure_key::{sort_by_closure, ClosureKey};
+pub use closure_mut::ClosureMut;
+pub use closure_once::ClosureOnce;
 pub use closure_opt_ref::ClosureOptRef;
+pub use closure_opt_ref_mut::ClosureOptRefMut;
 pub use closure_ref::ClosureRef;
+pub use closure_ref_mut::ClosureRefMut;
 pub use closure_res_ref::ClosureResRef;
+pub use closure_res_ref_mut::ClosureResRefMut;
+pub use closure_scratch::ClosureScratch;
 pub use closure_val::Closure;
+pub use defer::{defer, Defer};
+pub use env_capture::EnvCapture;
+pub use lens::Lens;
+#[cfg(feature = "mmap")]
+pub use mmap_slice::MmapSlice;
 pub use one_of::{OneOf2, OneOf3, OneOf4};
+pub use one_of_macro::IntoOneOf;
+pub use piecewise::Piecewise;
+pub use recording::{Recording, Replay};
+pub use scratch_pool::{ScratchGuard, ScratchPool};
+pub use select_by::select_by;
+pub use setter::Setter;
+
+pub use one_of_variants::one_of1::closure_val::ClosureOneOf1;
 
 pub use one_of_variants::one_of2::{
+    closure_mut::ClosureMutOneOf2, closure_once::ClosureOnceOneOf2,
     closure_opt_ref::ClosureOptRefOneOf2, closure_ref::ClosureRefOneOf2,
     closure_res_ref::ClosureResRefOneOf2, closure_val::ClosureOneOf2,
 };
 
 pub use one_of_variants::one_of3::{
-    closure_opt_ref::ClosureOptRefOneOf3, closure_ref::ClosureRefOneOf3,
-    closure_res_ref::ClosureResRefOneOf3, closure_val::ClosureOneOf3,
+    closure_mut::ClosureMutOneOf3, closure_opt_ref::ClosureOptRefOneOf3,
+    closure_ref::ClosureRefOneOf3, closure_res_ref::ClosureResRefOneOf3,
+    closure_val::ClosureOneOf3,
 };
 
 pub use one_of_variants::one_of4::{
-    closure_opt_ref::ClosureOptRefOneOf4, closure_ref::ClosureRefOneOf4,
-    closure_res_ref::ClosureResRefOneOf4, closure_val::ClosureOneOf4,
+    closure_mut::ClosureMutOneOf4, closure_opt_ref::ClosureOptRefOneOf4,
+    closure_ref::ClosureRefOneOf4, closure_res_ref::ClosureResRefOneOf4,
+    closure_val::ClosureOneOf4,
 };
 
-pub use fun::{Fun, FunOptRef, FunRef, FunResRef};
+pub use fun::{
+    Fun, FunLookup, FunMut, FunOnce, FunOptRef, FunOptRefMut, FunRef, FunRefMut, FunResRef,
+    FunResRefMut, TryFun, TryFunVal,
+};
@@ -0,0 +1,105 @@
+use bytemuck::{Pod, PodCastError};
+use memmap2::Mmap;
+use std::{fmt::Debug, marker::PhantomData, ops::Deref};
+
+/// A memory-mapped file interpreted as a slice `&[T]` of plain-old-data elements, which owns the map and keeps it alive.
+///
+/// It is meant to be used as the captured data of closures, such as `Capture(MmapSlice::<f32>::new(mmap)?).fun_ref(...)`,
+/// allowing large static tables to be loaded via memory-mapping and exposed as a closure without copying them into memory.
+/// Since the map is owned by the capture, references returned by the closure cannot outlive it.
+///
+/// The interpretation of the bytes is safe and validated once on construction: `T` must implement `bytemuck::Pod`,
+/// and the length and alignment of the map must be compatible with `T`.
+/// Creating the `Mmap` itself is unsafe since the file must not be modified while mapped; see `memmap2::Mmap::map`.
+/// This is the only unsafe call required by the user.
+///
+/// Requires the `mmap` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use memmap2::Mmap;
+/// use std::{fs::File, io::Write};
+///
+/// // a table of weights stored as native-endian f32 values
+/// let path = std::env::temp_dir().join("orx_closure_mmap_slice_doc.bin");
+/// let weights = [0.5f32, 1.5, 2.5];
+/// let bytes: Vec<u8> = weights.iter().flat_map(|w| w.to_ne_bytes()).collect();
+/// File::create(&path).unwrap().write_all(&bytes).unwrap();
+///
+/// let file = File::open(&path).unwrap();
+/// let mmap = unsafe { Mmap::map(&file) }.unwrap();
+///
+/// let weights = MmapSlice::<f32>::new(mmap).unwrap();
+/// assert_eq!(3, weights.len());
+///
+/// let weight = Capture(weights).fun_ref(|w, i: usize| &w[i]);
+/// assert_eq!(&1.5, weight.call(1));
+///
+/// let try_weight = weight.into_captured_data();
+/// let try_weight = Capture(try_weight).fun_option_ref(|w, i: usize| w.get(i));
+/// assert_eq!(Some(&2.5), try_weight.call(2));
+/// assert_eq!(None, try_weight.call(3));
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+pub struct MmapSlice<T> {
+    mmap: Mmap,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Debug for MmapSlice<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MmapSlice")
+            .field("len", &(self.mmap.len() / std::mem::size_of::<T>().max(1)))
+            .finish()
+    }
+}
+
+impl<T: Pod> MmapSlice<T> {
+    /// Creates the slice by interpreting the bytes of the `mmap` as elements of type `T`.
+    ///
+    /// Returns an error if the length of the map is not a multiple of the size of `T`, or if the map is not aligned for `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use memmap2::Mmap;
+    /// use std::{fs::File, io::Write};
+    ///
+    /// let path = std::env::temp_dir().join("orx_closure_mmap_slice_new_doc.bin");
+    /// File::create(&path).unwrap().write_all(&[0u8; 6]).unwrap();
+    ///
+    /// let mmap = || unsafe { Mmap::map(&File::open(&path).unwrap()) }.unwrap();
+    ///
+    /// assert_eq!(3, MmapSlice::<u16>::new(mmap()).unwrap().len());
+    /// assert!(MmapSlice::<u32>::new(mmap()).is_err()); // 6 bytes are not a multiple of 4
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn new(mmap: Mmap) -> Result<Self, PodCastError> {
+        bytemuck::try_cast_slice::<u8, T>(&mmap)?;
+        Ok(Self {
+            mmap,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the memory-mapped data as a slice of `T`.
+    pub fn as_slice(&self) -> &[T] {
+        bytemuck::cast_slice(&self.mmap)
+    }
+
+    /// Consumes the slice and returns back the underlying memory map.
+    pub fn into_mmap(self) -> Mmap {
+        self.mmap
+    }
+}
+
+impl<T: Pod> Deref for MmapSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
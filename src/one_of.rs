@@ -1,5 +1,8 @@
 /// One of the two variants.
 ///
+/// With the `serde` feature, `OneOf2`, `OneOf3` and `OneOf4` implement `Serialize` and `Deserialize` whenever their variants do;
+/// the variants are externally tagged by their names, such as `{"Variant1": 42}` in json.
+///
 /// # Examples
 ///
 /// ```rust
@@ -9,6 +12,7 @@
 /// let _ = OneOf2::<i32, bool>::Variant2(true);
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OneOf2<C1, C2> {
     /// First variant.
     Variant1(C1),
@@ -28,6 +32,7 @@ pub enum OneOf2<C1, C2> {
 /// let _ = OneOf3::<i32, bool, String>::Variant3("hi".to_string());
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OneOf3<C1, C2, C3> {
     /// First variant.
     Variant1(C1),
@@ -50,6 +55,7 @@ pub enum OneOf3<C1, C2, C3> {
 /// let _ = OneOf4::<i32, bool, String, char>::Variant4('x');
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OneOf4<C1, C2, C3, C4> {
     /// First variant.
     Variant1(C1),
@@ -60,3 +66,306 @@ pub enum OneOf4<C1, C2, C3, C4> {
     /// Fourth variant.
     Variant4(C4),
 }
+
+impl<C1, C2> OneOf2<C1, C2> {
+    /// Converts the union into a `Result` where the first variant is mapped to `Ok` and the second to `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// assert_eq!(Ok(42), OneOf2::<i32, bool>::Variant1(42).into_result());
+    /// assert_eq!(Err(true), OneOf2::<i32, bool>::Variant2(true).into_result());
+    /// ```
+    pub fn into_result(self) -> Result<C1, C2> {
+        match self {
+            OneOf2::Variant1(x) => Ok(x),
+            OneOf2::Variant2(x) => Err(x),
+        }
+    }
+}
+
+impl<C1, C2> From<Result<C1, C2>> for OneOf2<C1, C2> {
+    fn from(value: Result<C1, C2>) -> Self {
+        match value {
+            Ok(x) => OneOf2::Variant1(x),
+            Err(x) => OneOf2::Variant2(x),
+        }
+    }
+}
+
+impl<C1, C2, C3> OneOf3<C1, C2, C3> {
+    /// Converts the union into the equivalent nested union of two variants, `OneOf2<C1, OneOf2<C2, C3>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// type Union = OneOf3<i32, bool, char>;
+    ///
+    /// assert_eq!(OneOf2::Variant1(42), Union::Variant1(42).into_nested());
+    /// assert_eq!(
+    ///     OneOf2::Variant2(OneOf2::Variant2('x')),
+    ///     Union::Variant3('x').into_nested()
+    /// );
+    /// ```
+    pub fn into_nested(self) -> OneOf2<C1, OneOf2<C2, C3>> {
+        match self {
+            OneOf3::Variant1(x) => OneOf2::Variant1(x),
+            OneOf3::Variant2(x) => OneOf2::Variant2(OneOf2::Variant1(x)),
+            OneOf3::Variant3(x) => OneOf2::Variant2(OneOf2::Variant2(x)),
+        }
+    }
+}
+
+impl<C1, C2, C3, C4> OneOf4<C1, C2, C3, C4> {
+    /// Converts the union into the equivalent nested union of two variants, `OneOf2<C1, OneOf2<C2, OneOf2<C3, C4>>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// type Union = OneOf4<i32, bool, char, String>;
+    ///
+    /// assert_eq!(OneOf2::Variant1(42), Union::Variant1(42).into_nested());
+    /// assert_eq!(
+    ///     OneOf2::Variant2(OneOf2::Variant2(OneOf2::Variant1('x'))),
+    ///     Union::Variant3('x').into_nested()
+    /// );
+    /// ```
+    pub fn into_nested(self) -> OneOf2<C1, OneOf2<C2, OneOf2<C3, C4>>> {
+        match self {
+            OneOf4::Variant1(x) => OneOf2::Variant1(x),
+            OneOf4::Variant2(x) => OneOf2::Variant2(OneOf2::Variant1(x)),
+            OneOf4::Variant3(x) => OneOf2::Variant2(OneOf2::Variant2(OneOf2::Variant1(x))),
+            OneOf4::Variant4(x) => OneOf2::Variant2(OneOf2::Variant2(OneOf2::Variant2(x))),
+        }
+    }
+}
+
+// iterator
+
+/// A union of iterators yielding the same item type is an iterator itself.
+///
+/// The variant is matched on each `next` call; however, internal iteration such as `fold`, `sum` or `for_each`
+/// resolves the variant once and then runs the loop of the underlying iterator.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// fn numbers(listed: bool) -> OneOf2<std::ops::Range<i32>, std::vec::IntoIter<i32>> {
+///     match listed {
+///         false => OneOf2::Variant1(0..5),
+///         true => OneOf2::Variant2(vec![1, 3].into_iter()),
+///     }
+/// }
+///
+/// assert_eq!(10, numbers(false).sum::<i32>());
+/// assert_eq!(vec![1, 3], numbers(true).collect::<Vec<_>>());
+/// ```
+impl<I1, I2> Iterator for OneOf2<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            OneOf2::Variant1(x) => x.next(),
+            OneOf2::Variant2(x) => x.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            OneOf2::Variant1(x) => x.size_hint(),
+            OneOf2::Variant2(x) => x.size_hint(),
+        }
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            OneOf2::Variant1(x) => x.fold(init, f),
+            OneOf2::Variant2(x) => x.fold(init, f),
+        }
+    }
+}
+
+impl<I1, I2, I3> Iterator for OneOf3<I1, I2, I3>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+    I3: Iterator<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            OneOf3::Variant1(x) => x.next(),
+            OneOf3::Variant2(x) => x.next(),
+            OneOf3::Variant3(x) => x.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            OneOf3::Variant1(x) => x.size_hint(),
+            OneOf3::Variant2(x) => x.size_hint(),
+            OneOf3::Variant3(x) => x.size_hint(),
+        }
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            OneOf3::Variant1(x) => x.fold(init, f),
+            OneOf3::Variant2(x) => x.fold(init, f),
+            OneOf3::Variant3(x) => x.fold(init, f),
+        }
+    }
+}
+
+impl<I1, I2, I3, I4> Iterator for OneOf4<I1, I2, I3, I4>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+    I3: Iterator<Item = I1::Item>,
+    I4: Iterator<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            OneOf4::Variant1(x) => x.next(),
+            OneOf4::Variant2(x) => x.next(),
+            OneOf4::Variant3(x) => x.next(),
+            OneOf4::Variant4(x) => x.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            OneOf4::Variant1(x) => x.size_hint(),
+            OneOf4::Variant2(x) => x.size_hint(),
+            OneOf4::Variant3(x) => x.size_hint(),
+            OneOf4::Variant4(x) => x.size_hint(),
+        }
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            OneOf4::Variant1(x) => x.fold(init, f),
+            OneOf4::Variant2(x) => x.fold(init, f),
+            OneOf4::Variant3(x) => x.fold(init, f),
+            OneOf4::Variant4(x) => x.fold(init, f),
+        }
+    }
+}
+
+// either
+
+#[cfg(feature = "either")]
+mod either_interop {
+    use super::*;
+    use either::Either;
+
+    impl<L, R> From<OneOf2<L, R>> for Either<L, R> {
+        fn from(value: OneOf2<L, R>) -> Self {
+            match value {
+                OneOf2::Variant1(x) => Either::Left(x),
+                OneOf2::Variant2(x) => Either::Right(x),
+            }
+        }
+    }
+
+    impl<L, R> From<Either<L, R>> for OneOf2<L, R> {
+        fn from(value: Either<L, R>) -> Self {
+            match value {
+                Either::Left(x) => OneOf2::Variant1(x),
+                Either::Right(x) => OneOf2::Variant2(x),
+            }
+        }
+    }
+
+    impl<C1, C2> OneOf2<C1, C2> {
+        /// Converts the union into `Either` where the first variant is mapped to `Left` and the second to `Right`.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use orx_closure::*;
+        /// use either::Either;
+        ///
+        /// assert_eq!(Either::Left(42), OneOf2::<i32, bool>::Variant1(42).into_either());
+        /// assert_eq!(Either::Right(true), OneOf2::<i32, bool>::Variant2(true).into_either());
+        /// ```
+        pub fn into_either(self) -> Either<C1, C2> {
+            self.into()
+        }
+    }
+
+    impl<C1, C2, C3> OneOf3<C1, C2, C3> {
+        /// Converts the union into the equivalent nested `Either<C1, Either<C2, C3>>`.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use orx_closure::*;
+        /// use either::Either;
+        ///
+        /// type Union = OneOf3<i32, bool, char>;
+        ///
+        /// assert_eq!(Either::Left(42), Union::Variant1(42).into_nested_either());
+        /// assert_eq!(
+        ///     Either::Right(Either::Right('x')),
+        ///     Union::Variant3('x').into_nested_either()
+        /// );
+        /// ```
+        pub fn into_nested_either(self) -> Either<C1, Either<C2, C3>> {
+            match self.into_nested() {
+                OneOf2::Variant1(x) => Either::Left(x),
+                OneOf2::Variant2(x) => Either::Right(x.into()),
+            }
+        }
+    }
+
+    impl<C1, C2, C3, C4> OneOf4<C1, C2, C3, C4> {
+        /// Converts the union into the equivalent nested `Either<C1, Either<C2, Either<C3, C4>>>`.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use orx_closure::*;
+        /// use either::Either;
+        ///
+        /// type Union = OneOf4<i32, bool, char, String>;
+        ///
+        /// assert_eq!(Either::Left(42), Union::Variant1(42).into_nested_either());
+        /// assert_eq!(
+        ///     Either::Right(Either::Right(Either::Left('x'))),
+        ///     Union::Variant3('x').into_nested_either()
+        /// );
+        /// ```
+        pub fn into_nested_either(self) -> Either<C1, Either<C2, Either<C3, C4>>> {
+            match self.into_nested() {
+                OneOf2::Variant1(x) => Either::Left(x),
+                OneOf2::Variant2(OneOf2::Variant1(x)) => Either::Right(Either::Left(x)),
+                OneOf2::Variant2(OneOf2::Variant2(x)) => Either::Right(Either::Right(x.into())),
+            }
+        }
+    }
+}
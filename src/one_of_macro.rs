@@ -0,0 +1,103 @@
+/// Transforms a closure into the `VARIANT`-th variant of the closure union `Union`.
+///
+/// This trait is implemented for every closure type and each of the unions it can be a variant of:
+///
+/// * `Closure` -> `ClosureOneOf1`, `ClosureOneOf2`, `ClosureOneOf3`, `ClosureOneOf4`
+/// * `ClosureOneOf1` -> `ClosureOneOf2`, `ClosureOneOf3`, `ClosureOneOf4`, as the first variant
+/// * `ClosureMut` -> `ClosureMutOneOf2`, `ClosureMutOneOf3`, `ClosureMutOneOf4`
+/// * `ClosureOnce` -> `ClosureOnceOneOf2`
+/// * `ClosureRef` -> `ClosureRefOneOf2`, `ClosureRefOneOf3`, `ClosureRefOneOf4`
+/// * `ClosureOptRef` -> `ClosureOptRefOneOf2`, `ClosureOptRefOneOf3`, `ClosureOptRefOneOf4`
+/// * `ClosureResRef` -> `ClosureResRefOneOf2`, `ClosureResRefOneOf3`, `ClosureResRefOneOf4`
+///
+/// It is equivalent to the inherent `into_oneofN_varK` methods; however, since the union type is a generic parameter of the trait, it can be inferred.
+/// Therefore, the other capture types need not be spelled out at the call site whenever the union type is known.
+///
+/// This is mostly used through the `one_of!` macro.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// type Weight = ClosureOneOf3<Vec<i32>, (), [i32; 2], usize, i32>;
+///
+/// // instead of Capture(()).fun(|_, _| 1).into_oneof3_var2::<Vec<i32>, [i32; 2]>()
+/// let weight: Weight = IntoOneOf::<2, _>::into_one_of(Capture(()).fun(|_, _: usize| 1));
+/// assert_eq!(1, weight.call(42));
+/// ```
+pub trait IntoOneOf<const VARIANT: usize, Union> {
+    /// Transforms the closure into the `VARIANT`-th variant of `Union`.
+    fn into_one_of(self) -> Union;
+}
+
+/// Transforms a closure into a variant of a closure union, without spelling out the capture types of the other variants.
+///
+/// * `one_of!(var2: closure => Union)` transforms the `closure` into the second variant of the explicitly provided `Union` type;
+/// * `one_of!(var2: closure)` transforms the `closure` into the second variant of the union type which is inferred from the context,
+///   such as the return type of a function or the type of a field.
+///
+/// Variants `var1` to `var4` are available.
+/// The macro works for all closure types and their unions, such as `ClosureOneOf3`, `ClosureRefOneOf2` or `ClosureResRefOneOf4`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::collections::HashMap;
+///
+/// type Jagged = Vec<Vec<i32>>;
+/// type Map = HashMap<(usize, usize), i32>;
+/// type Weight = ClosureOneOf3<Jagged, Map, (), (usize, usize), i32>;
+///
+/// fn weights(kind: &str) -> Weight {
+///     match kind {
+///         "jagged" => {
+///             let data: Jagged = vec![vec![0, 2], vec![3, 0]];
+///             one_of!(var1: Capture(data).fun(|d, (i, j): (usize, usize)| d[i][j]))
+///         }
+///         "map" => {
+///             let data = Map::from_iter([((0, 1), 7)]);
+///             one_of!(var2: Capture(data).fun(|d, ij: (usize, usize)| d.get(&ij).copied().unwrap_or(0)))
+///         }
+///         _ => one_of!(var3: Capture(()).fun(|_, _: (usize, usize)| 1)),
+///     }
+/// }
+///
+/// assert_eq!(3, weights("jagged").call((1, 0)));
+/// assert_eq!(7, weights("map").call((0, 1)));
+/// assert_eq!(1, weights("unit").call((0, 1)));
+///
+/// // with an explicit union type
+/// let names = vec!["john".to_string()];
+/// let name = one_of!(var2: Capture(names).fun_option_ref(|n, i: usize| n.get(i))
+///     => ClosureOptRefOneOf2<(), Vec<String>, usize, String>);
+/// assert_eq!(Some(&"john".to_string()), name.call(0));
+/// ```
+#[macro_export]
+macro_rules! one_of {
+    (var1: $closure:expr => $union:ty) => {
+        $crate::IntoOneOf::<1, $union>::into_one_of($closure)
+    };
+    (var2: $closure:expr => $union:ty) => {
+        $crate::IntoOneOf::<2, $union>::into_one_of($closure)
+    };
+    (var3: $closure:expr => $union:ty) => {
+        $crate::IntoOneOf::<3, $union>::into_one_of($closure)
+    };
+    (var4: $closure:expr => $union:ty) => {
+        $crate::IntoOneOf::<4, $union>::into_one_of($closure)
+    };
+    (var1: $closure:expr) => {
+        $crate::IntoOneOf::<1, _>::into_one_of($closure)
+    };
+    (var2: $closure:expr) => {
+        $crate::IntoOneOf::<2, _>::into_one_of($closure)
+    };
+    (var3: $closure:expr) => {
+        $crate::IntoOneOf::<3, _>::into_one_of($closure)
+    };
+    (var4: $closure:expr) => {
+        $crate::IntoOneOf::<4, _>::into_one_of($closure)
+    };
+}
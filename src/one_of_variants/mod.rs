@@ -1,3 +1,4 @@
+pub(crate) mod one_of1;
 pub(crate) mod one_of2;
 pub(crate) mod one_of3;
 pub(crate) mod one_of4;
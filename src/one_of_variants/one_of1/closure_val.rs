@@ -0,0 +1,285 @@
+use crate::{
+    fun::Fun, CaptureVisitor, Closure, ClosureOneOf2, ClosureOneOf3, ClosureOneOf4, IntoOneOf,
+};
+use std::{any::Any, sync::Arc};
+
+/// `ClosureOneOf1<C, In, Out>` is the trivial union of a single closure `Closure<C, In, Out>`.
+///
+/// It does not add any functionality over the closure it wraps; however, it provides the same API as the larger unions
+/// `ClosureOneOf2`, `ClosureOneOf3` and `ClosureOneOf4`.
+/// This allows generic code, such as macros generating unions of `N` variants, to avoid special-casing `N = 1`.
+///
+/// It can be grown into the first variant of a larger union by `grow` or by `IntoOneOf`.
+///
+/// It represents the transformation `In -> Out`.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureOneOf1` auto-implements `Clone` given that the captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let weight: ClosureOneOf1<Vec<i32>, usize, i32> = one_of!(var1: Capture(vec![1, 2]).fun(|v, i: usize| v[i]));
+/// assert_eq!(2, weight.call(1));
+///
+/// // later, another capture type is required
+/// let weight: ClosureOneOf2<Vec<i32>, i32, usize, i32> = weight.grow();
+/// assert_eq!(1, weight.call(0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClosureOneOf1<C, In, Out> {
+    closure: Closure<C, In, Out>,
+}
+impl<C, In, Out> ClosureOneOf1<C, In, Out> {
+    /// Calls the closure with the given `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]).into_oneof1();
+    /// assert_eq!(3, fun.call(2));
+    /// ```
+    #[inline(always)]
+    pub fn call(&self, input: In) -> Out {
+        self.closure.call(input)
+    }
+
+    /// Calls the closure with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]).into_oneof1();
+    /// assert_eq!(2, fun.call_outlined(1));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Out {
+        self.call(input)
+    }
+
+    /// Returns a reference to the captured data.
+    pub fn captured_data(&self) -> &C {
+        self.closure.captured_data()
+    }
+
+    /// Applies the `visitor` on the captured data, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C, Output = R>,
+    {
+        visitor.visit(self.captured_data())
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]).into_oneof1();
+    /// assert_eq!(vec![1, 2, 3], fun.into_captured_data());
+    /// ```
+    #[inline(always)]
+    pub fn into_captured_data(self) -> C {
+        self.closure.into_captured_data()
+    }
+
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let weight = Capture(vec![vec![1, 2], vec![3, 4]]).fun(|m, (i, j): (usize, usize)| m[i][j]).into_oneof1();
+    ///
+    /// let weight = weight.premap(|k: usize| (k / 2, k % 2));
+    /// assert_eq!(3, weight.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> Closure<(Self, fn(NewIn) -> In), NewIn, Out> {
+        Closure::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
+    /// Returns the closure as an `impl Fn(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call` method,
+    /// * or pass the closure to functions accepting a function generic over the `Fn`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun = Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]).into_oneof1();
+    /// assert_eq!(6, (0..3).map(fun.as_fn()).sum::<i32>());
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(In) -> Out + '_ {
+        move |x| self.call(x)
+    }
+
+    /// Consumes the closure and returns it as an `impl Fn(In) -> Out` struct which owns the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// fn weight(values: Vec<i32>) -> impl Fn(usize) -> i32 {
+    ///     Capture(values).fun(|v, i: usize| v[i]).into_oneof1().into_fn()
+    /// }
+    ///
+    /// let weight = weight(vec![4, 2]);
+    /// assert_eq!(6, (0..2).map(weight).sum::<i32>());
+    /// ```
+    pub fn into_fn(self) -> impl Fn(In) -> Out {
+        move |x| self.call(x)
+    }
+
+    /// Returns an `impl Fn(In) -> Out` struct which owns a shared reference to the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::sync::Arc;
+    ///
+    /// let fun = Arc::new(Capture(vec![4, 2]).fun(|v, i: usize| v[i]).into_oneof1());
+    ///
+    /// let fun1 = Arc::clone(&fun).to_fn();
+    /// let fun2 = fun.to_fn();
+    /// assert_eq!(6, fun1(0) + fun2(1));
+    /// ```
+    pub fn to_fn(self: Arc<Self>) -> impl Fn(In) -> Out {
+        move |x| self.call(x)
+    }
+
+    /// Consumes the closure and returns back the captured data as a type-erased `Box<dyn Any>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure = Capture(vec![1, 2]).fun(|v, i: usize| v[i]).into_oneof1();
+    ///
+    /// let data = closure.into_captured_data_any();
+    /// assert_eq!(Some(&vec![1, 2]), data.downcast_ref::<Vec<i32>>());
+    /// ```
+    pub fn into_captured_data_any(self) -> Box<dyn Any>
+    where
+        C: 'static,
+    {
+        Box::new(self.into_captured_data())
+    }
+
+    /// Consumes the union and returns back the closure it wraps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure = Capture(vec![1, 2]).fun(|v, i: usize| v[i]).into_oneof1();
+    ///
+    /// // fun: Closure<Vec<i32>, usize, i32>
+    /// let fun = closure.shrink();
+    /// assert_eq!(2, fun.call(1));
+    /// ```
+    pub fn shrink(self) -> Closure<C, In, Out> {
+        self.closure
+    }
+
+    /// Grows the union into the more general `ClosureOneOf2<C, C2, In, Out>` for any `C2`, where this closure is the first variant.
+    ///
+    /// Unions with more variants can be obtained by `IntoOneOf`, such as by `one_of!(var1: closure)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure = Capture(vec![1, 2]).fun(|v, i: usize| v[i]).into_oneof1();
+    ///
+    /// let grown: ClosureOneOf2<Vec<i32>, (), usize, i32> = closure.clone().grow();
+    /// assert_eq!(2, grown.call(1));
+    ///
+    /// let grown: ClosureOneOf4<Vec<i32>, (), char, bool, usize, i32> = one_of!(var1: closure);
+    /// assert_eq!(1, grown.call(0));
+    /// ```
+    pub fn grow<C2>(self) -> ClosureOneOf2<C, C2, In, Out> {
+        self.closure.into_oneof2_var1()
+    }
+}
+
+impl<Capture, In, Out> Closure<Capture, In, Out> {
+    /// Transforms `Closure<C, In, Out>` into the trivial union `ClosureOneOf1<C, In, Out>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun: ClosureOneOf1<Vec<i32>, usize, i32> = Capture(vec![1, 2]).fun(|v, i: usize| v[i]).into_oneof1();
+    /// assert_eq!(2, fun.call(1));
+    /// ```
+    pub fn into_oneof1(self) -> ClosureOneOf1<Capture, In, Out> {
+        ClosureOneOf1 { closure: self }
+    }
+}
+
+impl<C, In, Out> Fun<In, Out> for ClosureOneOf1<C, In, Out> {
+    fn call(&self, input: In) -> Out {
+        ClosureOneOf1::call(self, input)
+    }
+}
+
+impl<Capture, In, Out> IntoOneOf<1, ClosureOneOf1<Capture, In, Out>> for Closure<Capture, In, Out> {
+    fn into_one_of(self) -> ClosureOneOf1<Capture, In, Out> {
+        self.into_oneof1()
+    }
+}
+
+impl<Var2, Capture, In, Out> IntoOneOf<1, ClosureOneOf2<Capture, Var2, In, Out>>
+    for ClosureOneOf1<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf2<Capture, Var2, In, Out> {
+        self.closure.into_oneof2_var1()
+    }
+}
+
+impl<Var2, Var3, Capture, In, Out> IntoOneOf<1, ClosureOneOf3<Capture, Var2, Var3, In, Out>>
+    for ClosureOneOf1<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf3<Capture, Var2, Var3, In, Out> {
+        self.closure.into_oneof3_var1()
+    }
+}
+
+impl<Var2, Var3, Var4, Capture, In, Out>
+    IntoOneOf<1, ClosureOneOf4<Capture, Var2, Var3, Var4, In, Out>>
+    for ClosureOneOf1<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf4<Capture, Var2, Var3, Var4, In, Out> {
+        self.closure.into_oneof4_var1()
+    }
+}
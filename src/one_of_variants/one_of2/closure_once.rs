@@ -0,0 +1,209 @@
+use crate::{fun::FunOnce, CaptureVisitor, ClosureOnce, IntoOneOf, OneOf2};
+
+/// `ClosureOnceOneOf2<C1, C2, In, Out>` is a union of two closures:
+///
+/// * `ClosureOnce<C1, In, Out>`
+/// * `ClosureOnce<C2, In, Out>`
+///
+/// This is useful when it is possible that the closure might capture and consume either of the two types of data `C1` or `C2`.
+///
+/// It represents the transformation `In -> Out` which can be called only once, since the call consumes the captured data.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureOnceOneOf2` auto-implements `Clone` given that captured data variants are cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// // on shutdown, either flushes the buffered lines or reports the number of dropped events
+/// type OnShutdown = ClosureOnceOneOf2<Vec<String>, usize, &'static str, String>;
+///
+/// struct Service {
+///     on_shutdown: OnShutdown,
+/// }
+///
+/// let flush = Service {
+///     on_shutdown: Capture(vec![String::from("a"), String::from("b")])
+///         .fun_once(|lines, name: &str| format!("{} flushed {}", name, lines.join(",")))
+///         .into_oneof2_var1(),
+/// };
+///
+/// let report = Service {
+///     on_shutdown: Capture(3)
+///         .fun_once(|dropped, name: &str| format!("{} dropped {}", name, dropped))
+///         .into_oneof2_var2(),
+/// };
+///
+/// assert_eq!("svc flushed a,b", flush.on_shutdown.call_once("svc"));
+/// assert_eq!("svc dropped 3", report.on_shutdown.call_once("svc"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClosureOnceOneOf2<C1, C2, In, Out> {
+    closure: OneOf2<ClosureOnce<C1, In, Out>, ClosureOnce<C2, In, Out>>,
+}
+impl<C1, C2, In, Out> ClosureOnceOneOf2<C1, C2, In, Out> {
+    /// Consumes the closure and calls it with the given `input`, moving the captured data of the active variant into its function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// type Release = ClosureOnceOneOf2<Vec<i32>, String, (), usize>;
+    ///
+    /// let release: Release = Capture(vec![1, 2, 3]).fun_once(|v, _: ()| v.len()).into_oneof2_var1();
+    /// assert_eq!(3, release.call_once(()));
+    ///
+    /// let release: Release = Capture(String::from("ab")).fun_once(|s, _: ()| s.len()).into_oneof2_var2();
+    /// assert_eq!(2, release.call_once(()));
+    /// ```
+    #[inline(always)]
+    pub fn call_once(self, input: In) -> Out {
+        match self.closure {
+            OneOf2::Variant1(fun) => fun.call_once(input),
+            OneOf2::Variant2(fun) => fun.call_once(input),
+        }
+    }
+
+    /// Returns a reference to the captured data.
+    pub fn captured_data(&self) -> OneOf2<&C1, &C2> {
+        match &self.closure {
+            OneOf2::Variant1(x) => OneOf2::Variant1(x.captured_data()),
+            OneOf2::Variant2(x) => OneOf2::Variant2(x.captured_data()),
+        }
+    }
+
+    /// Applies the `visitor` on the captured data of the active variant, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C1, Output = R>,
+        V: CaptureVisitor<C2, Output = R>,
+    {
+        match self.captured_data() {
+            OneOf2::Variant1(x) => CaptureVisitor::<C1>::visit(visitor, x),
+            OneOf2::Variant2(x) => CaptureVisitor::<C2>::visit(visitor, x),
+        }
+    }
+
+    /// Consumes the closure without calling it and returns back the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let release: ClosureOnceOneOf2<Vec<i32>, String, (), usize> = Capture(String::from("ab"))
+    ///     .fun_once(|s, _: ()| s.len())
+    ///     .into_oneof2_var2();
+    ///
+    /// let data = release.into_captured_data();
+    /// assert!(matches!(data, OneOf2::Variant2(s) if s == "ab"));
+    /// ```
+    pub fn into_captured_data(self) -> OneOf2<C1, C2> {
+        match self.closure {
+            OneOf2::Variant1(fun) => OneOf2::Variant1(fun.into_captured_data()),
+            OneOf2::Variant2(fun) => OneOf2::Variant2(fun.into_captured_data()),
+        }
+    }
+
+    /// Returns the closure as an `impl FnOnce(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call_once` method,
+    /// * or pass the closure to functions accepting a function generic over the `FnOnce`, such as `Option::map`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let release: ClosureOnceOneOf2<Vec<i32>, String, i32, i32> = Capture(vec![1, 2])
+    ///     .fun_once(|v, x: i32| v.into_iter().sum::<i32>() + x)
+    ///     .into_oneof2_var1();
+    ///
+    /// assert_eq!(Some(13), Some(10).map(release.into_fn_once()));
+    /// ```
+    pub fn into_fn_once(self) -> impl FnOnce(In) -> Out {
+        move |x| self.call_once(x)
+    }
+
+    /// Consumes the union and returns back the concrete closure of the active variant, as a variant of `OneOf2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOnceOneOf2<Vec<i32>, String, usize, i32> =
+    ///     Capture(vec![1, 2]).fun_once(|v, i: usize| v[i]).into_oneof2_var1();
+    ///
+    /// if let OneOf2::Variant1(fun) = closure.shrink() {
+    ///     // fun: ClosureOnce<Vec<i32>, usize, i32>
+    ///     assert_eq!(2, fun.call_once(1));
+    /// }
+    /// ```
+    pub fn shrink(self) -> OneOf2<ClosureOnce<C1, In, Out>, ClosureOnce<C2, In, Out>> {
+        self.closure
+    }
+}
+
+impl<Capture, In, Out> ClosureOnce<Capture, In, Out> {
+    /// Transforms `ClosureOnce<C1, In, Out>` into the more general `ClosureOnceOneOf2<C1, C2, In, Out>` for any `C2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOnceOneOf2<Vec<i32>, String, (), usize> =
+    ///     Capture(vec![1]).fun_once(|v, _: ()| v.len()).into_oneof2_var1();
+    ///
+    /// assert!(matches!(closure.captured_data(), OneOf2::Variant1(v) if v.len() == 1));
+    /// ```
+    pub fn into_oneof2_var1<Var2>(self) -> ClosureOnceOneOf2<Capture, Var2, In, Out> {
+        let closure = OneOf2::Variant1(self);
+        ClosureOnceOneOf2 { closure }
+    }
+
+    /// Transforms `ClosureOnce<C2, In, Out>` into the more general `ClosureOnceOneOf2<C1, C2, In, Out>` for any `C1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOnceOneOf2<Vec<i32>, String, (), usize> =
+    ///     Capture(String::from("abc")).fun_once(|s, _: ()| s.len()).into_oneof2_var2();
+    ///
+    /// assert!(matches!(closure.captured_data(), OneOf2::Variant2(s) if s == "abc"));
+    /// ```
+    pub fn into_oneof2_var2<Var1>(self) -> ClosureOnceOneOf2<Var1, Capture, In, Out> {
+        let closure = OneOf2::Variant2(self);
+        ClosureOnceOneOf2 { closure }
+    }
+}
+
+impl<C1, C2, In, Out> FunOnce<In, Out> for ClosureOnceOneOf2<C1, C2, In, Out> {
+    fn call_once(self, input: In) -> Out {
+        ClosureOnceOneOf2::call_once(self, input)
+    }
+}
+
+impl<Var2, Capture, In, Out> IntoOneOf<1, ClosureOnceOneOf2<Capture, Var2, In, Out>>
+    for ClosureOnce<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOnceOneOf2<Capture, Var2, In, Out> {
+        self.into_oneof2_var1()
+    }
+}
+
+impl<Var1, Capture, In, Out> IntoOneOf<2, ClosureOnceOneOf2<Var1, Capture, In, Out>>
+    for ClosureOnce<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOnceOneOf2<Var1, Capture, In, Out> {
+        self.into_oneof2_var2()
+    }
+}
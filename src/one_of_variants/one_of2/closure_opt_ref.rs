@@ -1,4 +1,8 @@
-use crate::{fun::FunOptRef, ClosureOptRef, OneOf2};
+use crate::{
+    fun::{FunLookup, FunOptRef},
+    CaptureVisitor, ClosureOptRef, IntoOneOf, OneOf2,
+};
+use std::{any::Any, sync::Arc};
 
 /// `ClosureOptRefOneOf2<C1, C2, In, Out>` is a union of two closures:
 ///
@@ -138,6 +142,26 @@ impl<C1, C2, In, Out: ?Sized> ClosureOptRefOneOf2<C1, C2, In, Out> {
         }
     }
 
+    /// Calls the closure of the active variant with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun: ClosureOptRefOneOf2<Vec<i32>, String, usize, i32> = Capture(vec![1, 2, 3]).fun_option_ref(|v, i: usize| v.get(i)).into_oneof2_var1();
+    /// assert_eq!(Some(&2), fun.call_outlined(1));
+    /// assert_eq!(None, fun.call_outlined(3));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Option<&Out> {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     pub fn captured_data(&self) -> OneOf2<&C1, &C2> {
         match &self.closure {
@@ -146,6 +170,21 @@ impl<C1, C2, In, Out: ?Sized> ClosureOptRefOneOf2<C1, C2, In, Out> {
         }
     }
 
+    /// Applies the `visitor` on the captured data of the active variant, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C1, Output = R>,
+        V: CaptureVisitor<C2, Output = R>,
+    {
+        match self.captured_data() {
+            OneOf2::Variant1(x) => CaptureVisitor::<C1>::visit(visitor, x),
+            OneOf2::Variant2(x) => CaptureVisitor::<C2>::visit(visitor, x),
+        }
+    }
+
     /// Consumes the closure and returns back the captured data.
     ///
     /// # Examples
@@ -208,6 +247,38 @@ impl<C1, C2, In, Out: ?Sized> ClosureOptRefOneOf2<C1, C2, In, Out> {
         }
     }
 
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight: ClosureOptRefOneOf2<Vec<Vec<i32>>, String, (usize, usize), i32> =
+    ///     Capture(vec![vec![1, 2], vec![3]]).fun_option_ref(|m, (i, j): (usize, usize)| m.get(i).and_then(|r| r.get(j))).into_oneof2_var1();
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!(Some(&3), weight.call(Edge { from: 1, to: 0 }));
+    /// assert_eq!(None, weight.call(Edge { from: 1, to: 1 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> ClosureOptRef<(Self, fn(NewIn) -> In), NewIn, Out> {
+        ClosureOptRef::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> Option<&Out>` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -262,6 +333,92 @@ impl<C1, C2, In, Out: ?Sized> ClosureOptRefOneOf2<C1, C2, In, Out> {
     pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> Option<&'a Out> {
         move |x| self.call(x)
     }
+
+    /// Returns an `impl Fn(In) -> Option<Out::Owned>` struct which owns a shared reference to the closure.
+    ///
+    /// Unlike `as_fn`, the returned function does not borrow the closure; therefore, it can be stored, returned from functions
+    /// or used in iterator chains outliving the scope of the closure.
+    /// Since the returned function cannot hand out references to the data it owns, the output is converted to its owned counterpart with `ToOwned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::sync::Arc;
+    ///
+    /// type Name = ClosureOptRefOneOf2<Vec<String>, String, usize, str>;
+    ///
+    /// fn names(ids: Vec<usize>, name: Arc<Name>) -> impl Iterator<Item = Option<String>> {
+    ///     ids.into_iter().map(name.to_fn())
+    /// }
+    ///
+    /// let name: Name = Capture(vec![String::from("john"), String::from("doe")])
+    ///     .fun_option_ref(|v, i: usize| v.get(i).map(|x| x.as_str()))
+    ///     .into_oneof2_var1();
+    /// let name = Arc::new(name);
+    ///
+    /// let names: Vec<_> = names(vec![1, 2], Arc::clone(&name)).collect();
+    /// assert_eq!(vec![Some(String::from("doe")), None], names);
+    /// ```
+    pub fn to_fn(self: Arc<Self>) -> impl Fn(In) -> Option<Out::Owned>
+    where
+        Out: ToOwned,
+    {
+        move |x| self.call(x).map(Out::to_owned)
+    }
+
+    /// Consumes the closure and returns back the captured data as a type-erased `Box<dyn Any>`, without having to know or match the active variant.
+    ///
+    /// The result can be downcast to the capture type of the active variant.
+    /// This is useful for generic teardown code reclaiming the captured data, for instance, to be pooled and reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOptRefOneOf2<Vec<i32>, String, usize, i32> =
+    ///     Capture(vec![1, 2]).fun_option_ref(|v, i: usize| v.get(i)).into_oneof2_var1();
+    ///
+    /// let data = closure.into_captured_data_any();
+    /// assert_eq!(Some(&vec![1, 2]), data.downcast_ref::<Vec<i32>>());
+    /// assert!(!data.is::<String>());
+    /// ```
+    pub fn into_captured_data_any(self) -> Box<dyn Any>
+    where
+        C1: 'static,
+        C2: 'static,
+    {
+        match self.closure {
+            OneOf2::Variant1(fun) => Box::new(fun.into_captured_data()),
+            OneOf2::Variant2(fun) => Box::new(fun.into_captured_data()),
+        }
+    }
+
+    /// Consumes the union and returns back the concrete closure of the active variant, as a variant of `OneOf2`.
+    ///
+    /// After the configuration phase, matching on the result once allows to continue with the monomorphic closure,
+    /// avoiding the variant dispatch on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOptRefOneOf2<Vec<i32>, String, usize, i32> =
+    ///     Capture(vec![1, 2]).fun_option_ref(|v, i: usize| v.get(i)).into_oneof2_var1();
+    ///
+    /// let shrunk = closure.shrink();
+    /// assert!(matches!(shrunk, OneOf2::Variant1(_)));
+    ///
+    /// if let OneOf2::Variant1(fun) = shrunk {
+    ///     // fun: ClosureOptRef<Vec<i32>, usize, i32>
+    ///     assert_eq!(Some(&2), fun.call(1));
+    /// }
+    /// ```
+    pub fn shrink(self) -> OneOf2<ClosureOptRef<C1, In, Out>, ClosureOptRef<C2, In, Out>> {
+        self.closure
+    }
 }
 
 impl<Capture, In, Out: ?Sized> ClosureOptRef<Capture, In, Out> {
@@ -376,3 +533,25 @@ impl<C1, C2, In, Out: ?Sized> FunOptRef<In, Out> for ClosureOptRefOneOf2<C1, C2,
         ClosureOptRefOneOf2::call(self, input)
     }
 }
+
+impl<C1, C2, K, V: ?Sized> FunLookup<K, V> for ClosureOptRefOneOf2<C1, C2, K, V> {
+    fn get(&self, key: K) -> Option<&V> {
+        ClosureOptRefOneOf2::call(self, key)
+    }
+}
+
+impl<Var2, Capture, In, Out: ?Sized> IntoOneOf<1, ClosureOptRefOneOf2<Capture, Var2, In, Out>>
+    for ClosureOptRef<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOptRefOneOf2<Capture, Var2, In, Out> {
+        self.into_oneof2_var1()
+    }
+}
+
+impl<Var1, Capture, In, Out: ?Sized> IntoOneOf<2, ClosureOptRefOneOf2<Var1, Capture, In, Out>>
+    for ClosureOptRef<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOptRefOneOf2<Var1, Capture, In, Out> {
+        self.into_oneof2_var2()
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{fun::FunRef, ClosureRef, OneOf2};
+use crate::{fun::FunRef, CaptureVisitor, ClosureRef, IntoOneOf, OneOf2};
+use std::{any::Any, sync::Arc};
 
 /// `ClosureRefOneOf2<C1, C2, In, Out>` is a union of two closures:
 ///
@@ -135,6 +136,25 @@ impl<C1, C2, In, Out: ?Sized> ClosureRefOneOf2<C1, C2, In, Out> {
         }
     }
 
+    /// Calls the closure of the active variant with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun: ClosureRefOneOf2<Vec<String>, String, usize, str> = Capture(vec![String::from("a"), String::from("b")]).fun_ref(|v, i: usize| v[i].as_str()).into_oneof2_var1();
+    /// assert_eq!("b", fun.call_outlined(1));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> &Out {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> OneOf2<&C1, &C2> {
@@ -144,6 +164,21 @@ impl<C1, C2, In, Out: ?Sized> ClosureRefOneOf2<C1, C2, In, Out> {
         }
     }
 
+    /// Applies the `visitor` on the captured data of the active variant, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C1, Output = R>,
+        V: CaptureVisitor<C2, Output = R>,
+    {
+        match self.captured_data() {
+            OneOf2::Variant1(x) => CaptureVisitor::<C1>::visit(visitor, x),
+            OneOf2::Variant2(x) => CaptureVisitor::<C2>::visit(visitor, x),
+        }
+    }
+
     /// Consumes the closure and returns back the captured data.
     ///
     /// # Examples
@@ -201,6 +236,37 @@ impl<C1, C2, In, Out: ?Sized> ClosureRefOneOf2<C1, C2, In, Out> {
         }
     }
 
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight: ClosureRefOneOf2<Vec<Vec<String>>, String, (usize, usize), str> =
+    ///     Capture(vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]]).fun_ref(|m, (i, j): (usize, usize)| m[i][j].as_str()).into_oneof2_var1();
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!("c", weight.call(Edge { from: 1, to: 0 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> ClosureRef<(Self, fn(NewIn) -> In), NewIn, Out> {
+        ClosureRef::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> &Out` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -254,6 +320,92 @@ impl<C1, C2, In, Out: ?Sized> ClosureRefOneOf2<C1, C2, In, Out> {
     pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> &'a Out {
         move |x| self.call(x)
     }
+
+    /// Returns an `impl Fn(In) -> Out::Owned` struct which owns a shared reference to the closure.
+    ///
+    /// Unlike `as_fn`, the returned function does not borrow the closure; therefore, it can be stored, returned from functions
+    /// or used in iterator chains outliving the scope of the closure.
+    /// Since the returned function cannot hand out references to the data it owns, the output is converted to its owned counterpart with `ToOwned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::sync::Arc;
+    ///
+    /// type Name = ClosureRefOneOf2<Vec<String>, String, usize, str>;
+    ///
+    /// fn names(ids: Vec<usize>, name: Arc<Name>) -> impl Iterator<Item = String> {
+    ///     ids.into_iter().map(name.to_fn())
+    /// }
+    ///
+    /// let name: Name = Capture(vec![String::from("john"), String::from("doe")])
+    ///     .fun_ref(|v, i: usize| v[i].as_str())
+    ///     .into_oneof2_var1();
+    /// let name = Arc::new(name);
+    ///
+    /// let names: Vec<_> = names(vec![1, 0], Arc::clone(&name)).collect();
+    /// assert_eq!(vec!["doe", "john"], names);
+    /// ```
+    pub fn to_fn(self: Arc<Self>) -> impl Fn(In) -> Out::Owned
+    where
+        Out: ToOwned,
+    {
+        move |x| self.call(x).to_owned()
+    }
+
+    /// Consumes the closure and returns back the captured data as a type-erased `Box<dyn Any>`, without having to know or match the active variant.
+    ///
+    /// The result can be downcast to the capture type of the active variant.
+    /// This is useful for generic teardown code reclaiming the captured data, for instance, to be pooled and reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureRefOneOf2<Vec<i32>, String, usize, i32> =
+    ///     Capture(vec![1, 2]).fun_ref(|v, i: usize| &v[i]).into_oneof2_var1();
+    ///
+    /// let data = closure.into_captured_data_any();
+    /// assert_eq!(Some(&vec![1, 2]), data.downcast_ref::<Vec<i32>>());
+    /// assert!(!data.is::<String>());
+    /// ```
+    pub fn into_captured_data_any(self) -> Box<dyn Any>
+    where
+        C1: 'static,
+        C2: 'static,
+    {
+        match self.closure {
+            OneOf2::Variant1(fun) => Box::new(fun.into_captured_data()),
+            OneOf2::Variant2(fun) => Box::new(fun.into_captured_data()),
+        }
+    }
+
+    /// Consumes the union and returns back the concrete closure of the active variant, as a variant of `OneOf2`.
+    ///
+    /// After the configuration phase, matching on the result once allows to continue with the monomorphic closure,
+    /// avoiding the variant dispatch on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureRefOneOf2<Vec<i32>, String, usize, i32> =
+    ///     Capture(vec![1, 2]).fun_ref(|v, i: usize| &v[i]).into_oneof2_var1();
+    ///
+    /// let shrunk = closure.shrink();
+    /// assert!(matches!(shrunk, OneOf2::Variant1(_)));
+    ///
+    /// if let OneOf2::Variant1(fun) = shrunk {
+    ///     // fun: ClosureRef<Vec<i32>, usize, i32>
+    ///     assert_eq!(&2, fun.call(1));
+    /// }
+    /// ```
+    pub fn shrink(self) -> OneOf2<ClosureRef<C1, In, Out>, ClosureRef<C2, In, Out>> {
+        self.closure
+    }
 }
 
 impl<Capture, In, Out: ?Sized> ClosureRef<Capture, In, Out> {
@@ -367,3 +519,19 @@ impl<C1, C2, In, Out: ?Sized> FunRef<In, Out> for ClosureRefOneOf2<C1, C2, In, O
         ClosureRefOneOf2::call(self, input)
     }
 }
+
+impl<Var2, Capture, In, Out: ?Sized> IntoOneOf<1, ClosureRefOneOf2<Capture, Var2, In, Out>>
+    for ClosureRef<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureRefOneOf2<Capture, Var2, In, Out> {
+        self.into_oneof2_var1()
+    }
+}
+
+impl<Var1, Capture, In, Out: ?Sized> IntoOneOf<2, ClosureRefOneOf2<Var1, Capture, In, Out>>
+    for ClosureRef<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureRefOneOf2<Var1, Capture, In, Out> {
+        self.into_oneof2_var2()
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{fun::FunResRef, ClosureResRef, OneOf2};
+use crate::{
+    fun::{FunResRef, TryFun},
+    CaptureVisitor, ClosureResRef, IntoOneOf, OneOf2,
+};
+use std::{any::Any, error::Error as StdError, fmt::Display, sync::Arc};
 
 type UnionClosure<C1, C2, In, Out, Error> =
     OneOf2<ClosureResRef<C1, In, Out, Error>, ClosureResRef<C2, In, Out, Error>>;
@@ -152,6 +156,26 @@ impl<C1, C2, In, Out: ?Sized, Error> ClosureResRefOneOf2<C1, C2, In, Out, Error>
         }
     }
 
+    /// Calls the closure of the active variant with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun: ClosureResRefOneOf2<Vec<i32>, String, usize, i32, &'static str> = Capture(vec![1, 2, 3]).fun_result_ref(|v, i: usize| v.get(i).ok_or("out of bounds")).into_oneof2_var1();
+    /// assert_eq!(Ok(&2), fun.call_outlined(1));
+    /// assert_eq!(Err("out of bounds"), fun.call_outlined(3));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Result<&Out, Error> {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> OneOf2<&C1, &C2> {
@@ -161,6 +185,21 @@ impl<C1, C2, In, Out: ?Sized, Error> ClosureResRefOneOf2<C1, C2, In, Out, Error>
         }
     }
 
+    /// Applies the `visitor` on the captured data of the active variant, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C1, Output = R>,
+        V: CaptureVisitor<C2, Output = R>,
+    {
+        match self.captured_data() {
+            OneOf2::Variant1(x) => CaptureVisitor::<C1>::visit(visitor, x),
+            OneOf2::Variant2(x) => CaptureVisitor::<C2>::visit(visitor, x),
+        }
+    }
+
     /// Consumes the closure and returns back the captured data.
     ///
     /// # Examples
@@ -228,6 +267,70 @@ impl<C1, C2, In, Out: ?Sized, Error> ClosureResRefOneOf2<C1, C2, In, Out, Error>
         }
     }
 
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight: ClosureResRefOneOf2<Vec<Vec<i32>>, String, (usize, usize), i32, &'static str> =
+    ///     Capture(vec![vec![1, 2], vec![3]]).fun_result_ref(|m, (i, j): (usize, usize)| m.get(i).and_then(|r| r.get(j)).ok_or("no edge")).into_oneof2_var1();
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!(Ok(&3), weight.call(Edge { from: 1, to: 0 }));
+    /// assert_eq!(Err("no edge"), weight.call(Edge { from: 1, to: 1 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> ClosureResRef<(Self, fn(NewIn) -> In), NewIn, Out, Error> {
+        ClosureResRef::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
+    /// Transforms the closure into one returning errors of type `E2`, by applying `map_err` on the error returned by this closure, if any;
+    /// i.e., calling the transformed closure with `input` returns `self.call(input).map_err(map_err)`.
+    ///
+    /// This allows to adapt closures with simple errors, such as `&'static str`, to APIs requiring a domain error type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum LookupError {
+    ///     Missing(&'static str),
+    /// }
+    ///
+    /// let price: ClosureResRefOneOf2<Vec<i32>, String, usize, i32, &'static str> =
+    ///     Capture(vec![1, 3]).fun_result_ref(|p, i: usize| p.get(i).ok_or("no price")).into_oneof2_var1();
+    ///
+    /// let price = price.map_err(LookupError::Missing);
+    /// assert_eq!(Ok(&3), price.call(1));
+    /// assert_eq!(Err(LookupError::Missing("no price")), price.call(2));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_err<E2>(
+        self,
+        map_err: fn(Error) -> E2,
+    ) -> ClosureResRef<(Self, fn(Error) -> E2), In, Out, E2> {
+        ClosureResRef::new((self, map_err), |(fun, map_err), input| {
+            fun.call(input).map_err(*map_err)
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> Result<&Out>` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -286,6 +389,151 @@ impl<C1, C2, In, Out: ?Sized, Error> ClosureResRefOneOf2<C1, C2, In, Out, Error>
     pub fn as_fn<'a>(&'a self) -> impl Fn(In) -> Result<&'a Out, Error> {
         move |x| self.call(x)
     }
+
+    /// Returns an `impl Fn(In) -> Result<Out::Owned, Error>` struct which owns a shared reference to the closure.
+    ///
+    /// Unlike `as_fn`, the returned function does not borrow the closure; therefore, it can be stored, returned from functions
+    /// or used in iterator chains outliving the scope of the closure.
+    /// Since the returned function cannot hand out references to the data it owns, the output is converted to its owned counterpart with `ToOwned`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::sync::Arc;
+    ///
+    /// type Name = ClosureResRefOneOf2<Vec<String>, String, usize, str, &'static str>;
+    ///
+    /// fn names(ids: Vec<usize>, name: Arc<Name>) -> impl Iterator<Item = Result<String, &'static str>> {
+    ///     ids.into_iter().map(name.to_fn())
+    /// }
+    ///
+    /// let name: Name = Capture(vec![String::from("john"), String::from("doe")])
+    ///     .fun_result_ref(|v, i: usize| v.get(i).map(|x| x.as_str()).ok_or("unknown id"))
+    ///     .into_oneof2_var1();
+    /// let name = Arc::new(name);
+    ///
+    /// let names: Vec<_> = names(vec![1, 2], Arc::clone(&name)).collect();
+    /// assert_eq!(vec![Ok(String::from("doe")), Err("unknown id")], names);
+    /// ```
+    pub fn to_fn(self: Arc<Self>) -> impl Fn(In) -> Result<Out::Owned, Error>
+    where
+        Out: ToOwned,
+    {
+        move |x| self.call(x).map(Out::to_owned)
+    }
+
+    /// Consumes the closure and returns back the captured data as a type-erased `Box<dyn Any>`, without having to know or match the active variant.
+    ///
+    /// The result can be downcast to the capture type of the active variant.
+    /// This is useful for generic teardown code reclaiming the captured data, for instance, to be pooled and reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureResRefOneOf2<Vec<i32>, String, usize, i32, &'static str> =
+    ///     Capture(vec![1, 2]).fun_result_ref(|v, i: usize| v.get(i).ok_or("out of bounds")).into_oneof2_var1();
+    ///
+    /// let data = closure.into_captured_data_any();
+    /// assert_eq!(Some(&vec![1, 2]), data.downcast_ref::<Vec<i32>>());
+    /// assert!(!data.is::<String>());
+    /// ```
+    pub fn into_captured_data_any(self) -> Box<dyn Any>
+    where
+        C1: 'static,
+        C2: 'static,
+    {
+        match self.closure {
+            OneOf2::Variant1(fun) => Box::new(fun.into_captured_data()),
+            OneOf2::Variant2(fun) => Box::new(fun.into_captured_data()),
+        }
+    }
+
+    /// Consumes the union and returns back the concrete closure of the active variant, as a variant of `OneOf2`.
+    ///
+    /// After the configuration phase, matching on the result once allows to continue with the monomorphic closure,
+    /// avoiding the variant dispatch on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureResRefOneOf2<Vec<i32>, String, usize, i32, &'static str> =
+    ///     Capture(vec![1, 2]).fun_result_ref(|v, i: usize| v.get(i).ok_or("out of bounds")).into_oneof2_var1();
+    ///
+    /// let shrunk = closure.shrink();
+    /// assert!(matches!(shrunk, OneOf2::Variant1(_)));
+    ///
+    /// if let OneOf2::Variant1(fun) = shrunk {
+    ///     // fun: ClosureResRef<Vec<i32>, usize, i32, &'static str>
+    ///     assert_eq!(Ok(&2), fun.call(1));
+    /// }
+    /// ```
+    pub fn shrink(self) -> UnionClosure<C1, C2, In, Out, Error> {
+        self.closure
+    }
+
+    /// Consumes the closure and converts its errors into `String`s, using their `Display` implementation.
+    ///
+    /// This allows to store closures with different error types behind a single uniform signature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// let fun: ClosureResRefOneOf2<Vec<i32>, String, usize, i32, ParseIntError> =
+    ///     Capture(vec![1, 2]).fun_result_ref(|v, i: usize| v.get(i).ok_or_else(|| "x".parse::<i32>().unwrap_err())).into_oneof2_var1();
+    ///
+    /// // ClosureResRefOneOf2<Vec<i32>, String, usize, i32, ParseIntError> -> error type String
+    /// let fun = fun.errors_to_string();
+    ///
+    /// assert_eq!(Ok(&2), fun.call(1));
+    /// assert_eq!(Err(String::from("invalid digit found in string")), fun.call(7));
+    /// ```
+    pub fn errors_to_string(self) -> ClosureResRef<Self, In, Out, String>
+    where
+        Error: Display,
+    {
+        ClosureResRef::new(self, |fun, input| {
+            fun.call(input).map_err(|e| e.to_string())
+        })
+    }
+
+    /// Consumes the closure and boxes its errors into `Box<dyn Error + Send + Sync>`.
+    ///
+    /// The boxed errors are compatible with the `?` operator of functions returning `Box<dyn Error>`,
+    /// as well as the error types of crates such as `anyhow` or `eyre`.
+    ///
+    /// Any error convertible into the box can be boxed, including the types implementing `Error + Send + Sync` as well as `&str` and `String` messages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::num::ParseIntError;
+    /// use std::error::Error;
+    ///
+    /// let fun: ClosureResRefOneOf2<Vec<i32>, String, usize, i32, ParseIntError> =
+    ///     Capture(vec![1, 2]).fun_result_ref(|v, i: usize| v.get(i).ok_or_else(|| "x".parse::<i32>().unwrap_err())).into_oneof2_var1();
+    ///
+    /// let fun = fun.errors_boxed();
+    ///
+    /// fn parse_all(fun: &impl FunResRef<usize, i32, Box<dyn Error + Send + Sync>>) -> Result<i32, Box<dyn Error + Send + Sync>> {
+    ///     Ok(fun.call(0)? + fun.call(7)?)
+    /// }
+    /// assert_eq!("invalid digit found in string", parse_all(&fun).unwrap_err().to_string());
+    /// ```
+    pub fn errors_boxed(self) -> ClosureResRef<Self, In, Out, Box<dyn StdError + Send + Sync>>
+    where
+        Error: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        ClosureResRef::new(self, |fun, input| fun.call(input).map_err(|e| e.into()))
+    }
 }
 
 impl<Capture, In, Out: ?Sized, Error> ClosureResRef<Capture, In, Out, Error> {
@@ -409,3 +657,29 @@ impl<C1, C2, In, Out: ?Sized, Error> FunResRef<In, Out, Error>
         ClosureResRefOneOf2::call(self, input)
     }
 }
+
+impl<C1, C2, In, Out: ?Sized, Error> TryFun<In, Out, Error>
+    for ClosureResRefOneOf2<C1, C2, In, Out, Error>
+{
+    fn try_call(&self, input: In) -> Result<&Out, Error> {
+        ClosureResRefOneOf2::call(self, input)
+    }
+}
+
+impl<Var2, Capture, In, Out: ?Sized, Error>
+    IntoOneOf<1, ClosureResRefOneOf2<Capture, Var2, In, Out, Error>>
+    for ClosureResRef<Capture, In, Out, Error>
+{
+    fn into_one_of(self) -> ClosureResRefOneOf2<Capture, Var2, In, Out, Error> {
+        self.into_oneof2_var1()
+    }
+}
+
+impl<Var1, Capture, In, Out: ?Sized, Error>
+    IntoOneOf<2, ClosureResRefOneOf2<Var1, Capture, In, Out, Error>>
+    for ClosureResRef<Capture, In, Out, Error>
+{
+    fn into_one_of(self) -> ClosureResRefOneOf2<Var1, Capture, In, Out, Error> {
+        self.into_oneof2_var2()
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{fun::FunMut, CaptureVisitor, ClosureMut, IntoOneOf, OneOf4};
+
+type UnionClosures<C1, C2, C3, C4, In, Out> = OneOf4<
+    ClosureMut<C1, In, Out>,
+    ClosureMut<C2, In, Out>,
+    ClosureMut<C3, In, Out>,
+    ClosureMut<C4, In, Out>,
+>;
+
+/// `ClosureMutOneOf4<C1, C2, C3, C4, In, Out>` is a union of four closures:
+///
+/// * `ClosureMut<C1, In, Out>`
+/// * `ClosureMut<C2, In, Out>`
+/// * `ClosureMut<C3, In, Out>`
+/// * `ClosureMut<C4, In, Out>`
+///
+/// This is useful when it is possible that the closure might capture and mutate either of the four types of data `C1`, `C2`, `C3` or `C4`.
+///
+/// It represents the transformation `In -> Out` which is allowed to mutate its captured state on each call.
+///
+/// Note that, unlike trait objects of fn-traits, `ClosureMutOneOf4` auto-implements `Clone` given that captured data variants are cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// // scores either by counting the calls, or by keeping the history of inputs
+/// type Scorer = ClosureMutOneOf4<usize, Vec<i32>, String, char, i32, usize>;
+///
+/// let mut count: Scorer = Capture(0).fun_mut(|c, _: i32| {
+///     *c += 1;
+///     *c
+/// }).into_oneof4_var1();
+///
+/// let mut distinct: Scorer = Capture(vec![]).fun_mut(|h, x: i32| {
+///     if !h.contains(&x) {
+///         h.push(x);
+///     }
+///     h.len()
+/// }).into_oneof4_var2();
+///
+/// for x in [7, 7, 3] {
+///     count.call_mut(x);
+///     distinct.call_mut(x);
+/// }
+///
+/// assert_eq!(4, count.call_mut(3));
+/// assert_eq!(2, distinct.call_mut(3));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClosureMutOneOf4<C1, C2, C3, C4, In, Out> {
+    closure: UnionClosures<C1, C2, C3, C4, In, Out>,
+}
+impl<C1, C2, C3, C4, In, Out> ClosureMutOneOf4<C1, C2, C3, C4, In, Out> {
+    /// Calls the closure with the given `input`, possibly mutating the captured data of the active variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// // scores either by counting the calls, or by keeping the history of inputs
+    /// type Scorer = ClosureMutOneOf4<usize, Vec<i32>, String, char, i32, usize>;
+    ///
+    /// let mut count: Scorer = Capture(0).fun_mut(|c, _: i32| {
+    ///     *c += 1;
+    ///     *c
+    /// }).into_oneof4_var1();
+    ///
+    /// let mut distinct: Scorer = Capture(vec![]).fun_mut(|h, x: i32| {
+    ///     if !h.contains(&x) {
+    ///         h.push(x);
+    ///     }
+    ///     h.len()
+    /// }).into_oneof4_var2();
+    ///
+    /// for x in [7, 7, 3] {
+    ///     count.call_mut(x);
+    ///     distinct.call_mut(x);
+    /// }
+    ///
+    /// assert_eq!(4, count.call_mut(3));
+    /// assert_eq!(2, distinct.call_mut(3));
+    /// ```
+    #[inline(always)]
+    pub fn call_mut(&mut self, input: In) -> Out {
+        match &mut self.closure {
+            OneOf4::Variant1(fun) => fun.call_mut(input),
+            OneOf4::Variant2(fun) => fun.call_mut(input),
+            OneOf4::Variant3(fun) => fun.call_mut(input),
+            OneOf4::Variant4(fun) => fun.call_mut(input),
+        }
+    }
+
+    /// Returns a reference to the captured data.
+    pub fn captured_data(&self) -> OneOf4<&C1, &C2, &C3, &C4> {
+        match &self.closure {
+            OneOf4::Variant1(x) => OneOf4::Variant1(x.captured_data()),
+            OneOf4::Variant2(x) => OneOf4::Variant2(x.captured_data()),
+            OneOf4::Variant3(x) => OneOf4::Variant3(x.captured_data()),
+            OneOf4::Variant4(x) => OneOf4::Variant4(x.captured_data()),
+        }
+    }
+
+    /// Applies the `visitor` on the captured data of the active variant, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C1, Output = R>,
+        V: CaptureVisitor<C2, Output = R>,
+        V: CaptureVisitor<C3, Output = R>,
+        V: CaptureVisitor<C4, Output = R>,
+    {
+        match self.captured_data() {
+            OneOf4::Variant1(x) => CaptureVisitor::<C1>::visit(visitor, x),
+            OneOf4::Variant2(x) => CaptureVisitor::<C2>::visit(visitor, x),
+            OneOf4::Variant3(x) => CaptureVisitor::<C3>::visit(visitor, x),
+            OneOf4::Variant4(x) => CaptureVisitor::<C4>::visit(visitor, x),
+        }
+    }
+
+    /// Consumes the closure and returns back the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let mut history: ClosureMutOneOf4<usize, Vec<i32>, String, char, i32, usize> = Capture(vec![])
+    ///     .fun_mut(|h, x: i32| {
+    ///         h.push(x);
+    ///         h.len()
+    ///     })
+    ///     .into_oneof4_var2();
+    ///
+    /// history.call_mut(4);
+    /// history.call_mut(2);
+    ///
+    /// let data = history.into_captured_data();
+    /// assert!(matches!(data, OneOf4::Variant2(h) if h == vec![4, 2]));
+    /// ```
+    #[inline(always)]
+    pub fn into_captured_data(self) -> OneOf4<C1, C2, C3, C4> {
+        match self.closure {
+            OneOf4::Variant1(fun) => OneOf4::Variant1(fun.into_captured_data()),
+            OneOf4::Variant2(fun) => OneOf4::Variant2(fun.into_captured_data()),
+            OneOf4::Variant3(fun) => OneOf4::Variant3(fun.into_captured_data()),
+            OneOf4::Variant4(fun) => OneOf4::Variant4(fun.into_captured_data()),
+        }
+    }
+
+    /// Returns the closure as an `impl FnMut(In) -> Out` struct, allowing the convenience
+    ///
+    /// * to avoid the `call_mut` method,
+    /// * or pass the closure to functions accepting a function generic over the `FnMut`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let mut count: ClosureMutOneOf4<usize, Vec<i32>, String, char, i32, usize> = Capture(0)
+    ///     .fun_mut(|c, _: i32| {
+    ///         *c += 1;
+    ///         *c
+    ///     })
+    ///     .into_oneof4_var1();
+    ///
+    /// let counts: Vec<_> = [5, 6, 7].into_iter().map(count.as_fn_mut()).collect();
+    /// assert_eq!(vec![1, 2, 3], counts);
+    /// ```
+    pub fn as_fn_mut(&mut self) -> impl FnMut(In) -> Out + '_ {
+        move |x| self.call_mut(x)
+    }
+
+    /// Consumes the union and returns back the concrete closure of the active variant, as a variant of `OneOf4`.
+    ///
+    /// After the configuration phase, matching on the result once allows to continue with the monomorphic closure,
+    /// avoiding the variant dispatch on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureMutOneOf4<Vec<i32>, String, char, bool, usize, i32> =
+    ///     Capture(vec![1, 2]).fun_mut(|v, i: usize| v[i]).into_oneof4_var1();
+    ///
+    /// let shrunk = closure.shrink();
+    /// assert!(matches!(shrunk, OneOf4::Variant1(_)));
+    ///
+    /// if let OneOf4::Variant1(mut fun) = shrunk {
+    ///     // fun: ClosureMut<Vec<i32>, usize, i32>
+    ///     assert_eq!(2, fun.call_mut(1));
+    /// }
+    /// ```
+    pub fn shrink(self) -> UnionClosures<C1, C2, C3, C4, In, Out> {
+        self.closure
+    }
+}
+
+impl<Capture, In, Out> ClosureMut<Capture, In, Out> {
+    /// Transforms `ClosureMut<C1, In, Out>` into the more general `ClosureMutOneOf4<C1, C2, C3, C4, In, Out>` for any `C2`, `C3`, `C4`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureMutOneOf4<u64, Vec<i32>, String, char, (), u64> = Capture(0)
+    ///     .fun_mut(|x, _: ()| {
+    ///         *x += 1;
+    ///         *x
+    ///     })
+    ///     .into_oneof4_var1();
+    ///
+    /// assert!(matches!(closure.captured_data(), OneOf4::Variant1(&0)));
+    /// ```
+    pub fn into_oneof4_var1<Var2, Var3, Var4>(
+        self,
+    ) -> ClosureMutOneOf4<Capture, Var2, Var3, Var4, In, Out> {
+        let closure = OneOf4::Variant1(self);
+        ClosureMutOneOf4 { closure }
+    }
+
+    /// Transforms `ClosureMut<C2, In, Out>` into the more general `ClosureMutOneOf4<C1, C2, C3, C4, In, Out>` for any `C1`, `C3`, `C4`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureMutOneOf4<usize, u64, String, char, (), u64> = Capture(0)
+    ///     .fun_mut(|x, _: ()| {
+    ///         *x += 1;
+    ///         *x
+    ///     })
+    ///     .into_oneof4_var2();
+    ///
+    /// assert!(matches!(closure.captured_data(), OneOf4::Variant2(&0)));
+    /// ```
+    pub fn into_oneof4_var2<Var1, Var3, Var4>(
+        self,
+    ) -> ClosureMutOneOf4<Var1, Capture, Var3, Var4, In, Out> {
+        let closure = OneOf4::Variant2(self);
+        ClosureMutOneOf4 { closure }
+    }
+
+    /// Transforms `ClosureMut<C3, In, Out>` into the more general `ClosureMutOneOf4<C1, C2, C3, C4, In, Out>` for any `C1`, `C2`, `C4`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureMutOneOf4<usize, Vec<i32>, u64, char, (), u64> = Capture(0)
+    ///     .fun_mut(|x, _: ()| {
+    ///         *x += 1;
+    ///         *x
+    ///     })
+    ///     .into_oneof4_var3();
+    ///
+    /// assert!(matches!(closure.captured_data(), OneOf4::Variant3(&0)));
+    /// ```
+    pub fn into_oneof4_var3<Var1, Var2, Var4>(
+        self,
+    ) -> ClosureMutOneOf4<Var1, Var2, Capture, Var4, In, Out> {
+        let closure = OneOf4::Variant3(self);
+        ClosureMutOneOf4 { closure }
+    }
+
+    /// Transforms `ClosureMut<C4, In, Out>` into the more general `ClosureMutOneOf4<C1, C2, C3, C4, In, Out>` for any `C1`, `C2`, `C3`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureMutOneOf4<usize, Vec<i32>, String, u64, (), u64> = Capture(0)
+    ///     .fun_mut(|x, _: ()| {
+    ///         *x += 1;
+    ///         *x
+    ///     })
+    ///     .into_oneof4_var4();
+    ///
+    /// assert!(matches!(closure.captured_data(), OneOf4::Variant4(&0)));
+    /// ```
+    pub fn into_oneof4_var4<Var1, Var2, Var3>(
+        self,
+    ) -> ClosureMutOneOf4<Var1, Var2, Var3, Capture, In, Out> {
+        let closure = OneOf4::Variant4(self);
+        ClosureMutOneOf4 { closure }
+    }
+}
+
+impl<C1, C2, C3, C4, In, Out> FunMut<In, Out> for ClosureMutOneOf4<C1, C2, C3, C4, In, Out> {
+    fn call_mut(&mut self, input: In) -> Out {
+        ClosureMutOneOf4::call_mut(self, input)
+    }
+}
+
+impl<Var2, Var3, Var4, Capture, In, Out>
+    IntoOneOf<1, ClosureMutOneOf4<Capture, Var2, Var3, Var4, In, Out>>
+    for ClosureMut<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureMutOneOf4<Capture, Var2, Var3, Var4, In, Out> {
+        self.into_oneof4_var1()
+    }
+}
+
+impl<Var1, Var3, Var4, Capture, In, Out>
+    IntoOneOf<2, ClosureMutOneOf4<Var1, Capture, Var3, Var4, In, Out>>
+    for ClosureMut<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureMutOneOf4<Var1, Capture, Var3, Var4, In, Out> {
+        self.into_oneof4_var2()
+    }
+}
+
+impl<Var1, Var2, Var4, Capture, In, Out>
+    IntoOneOf<3, ClosureMutOneOf4<Var1, Var2, Capture, Var4, In, Out>>
+    for ClosureMut<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureMutOneOf4<Var1, Var2, Capture, Var4, In, Out> {
+        self.into_oneof4_var3()
+    }
+}
+
+impl<Var1, Var2, Var3, Capture, In, Out>
+    IntoOneOf<4, ClosureMutOneOf4<Var1, Var2, Var3, Capture, In, Out>>
+    for ClosureMut<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureMutOneOf4<Var1, Var2, Var3, Capture, In, Out> {
+        self.into_oneof4_var4()
+    }
+}
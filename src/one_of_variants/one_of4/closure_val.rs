@@ -1,4 +1,8 @@
-use crate::{fun::Fun, Closure, OneOf4};
+use crate::{
+    fun::{Fun, TryFunVal},
+    CaptureVisitor, Closure, IntoOneOf, OneOf4,
+};
+use std::{any::Any, sync::Arc};
 
 type UnionClosures<C1, C2, C3, C4, In, Out> =
     OneOf4<Closure<C1, In, Out>, Closure<C2, In, Out>, Closure<C3, In, Out>, Closure<C4, In, Out>>;
@@ -131,6 +135,25 @@ impl<C1, C2, C3, C4, In, Out> ClosureOneOf4<C1, C2, C3, C4, In, Out> {
         }
     }
 
+    /// Calls the closure of the active variant with the given `input` through an out-of-line entry point.
+    ///
+    /// Unlike `call`, which is always inlined, this method is never inlined into its callers.
+    /// The closure is compiled once per instantiation behind this boundary rather than at every call site,
+    /// trading a function call for smaller binaries when many closure types are instantiated and called from many places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let fun: ClosureOneOf4<Vec<i32>, String, bool, char, usize, i32> = Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]).into_oneof4_var1();
+    /// assert_eq!(2, fun.call_outlined(1));
+    /// ```
+    #[inline(never)]
+    pub fn call_outlined(&self, input: In) -> Out {
+        self.call(input)
+    }
+
     /// Returns a reference to the captured data.
     #[inline(always)]
     pub fn captured_data(&self) -> OneOf4<&C1, &C2, &C3, &C4> {
@@ -142,6 +165,25 @@ impl<C1, C2, C3, C4, In, Out> ClosureOneOf4<C1, C2, C3, C4, In, Out> {
         }
     }
 
+    /// Applies the `visitor` on the captured data of the active variant, and returns the output of the visit.
+    ///
+    /// This allows generic passes, such as persistence or validation, over the captures of heterogeneous closures without matching over the variants.
+    /// See [`CaptureVisitor`] for details and an example.
+    pub fn for_each_capture<V, R>(&self, visitor: &mut V) -> R
+    where
+        V: CaptureVisitor<C1, Output = R>,
+        V: CaptureVisitor<C2, Output = R>,
+        V: CaptureVisitor<C3, Output = R>,
+        V: CaptureVisitor<C4, Output = R>,
+    {
+        match self.captured_data() {
+            OneOf4::Variant1(x) => CaptureVisitor::<C1>::visit(visitor, x),
+            OneOf4::Variant2(x) => CaptureVisitor::<C2>::visit(visitor, x),
+            OneOf4::Variant3(x) => CaptureVisitor::<C3>::visit(visitor, x),
+            OneOf4::Variant4(x) => CaptureVisitor::<C4>::visit(visitor, x),
+        }
+    }
+
     /// Consumes the closure and returns back the captured data.
     ///
     /// *The example below illustrates the usage of the closure over two possible types of captures; however, ClosureOneOf4 is only a generalization of the below for three different capture types.*
@@ -197,6 +239,37 @@ impl<C1, C2, C3, C4, In, Out> ClosureOneOf4<C1, C2, C3, C4, In, Out> {
         }
     }
 
+    /// Transforms the closure into one accepting `NewIn` as the input, by applying `map_in` on the input before calling this closure;
+    /// i.e., calling the transformed closure with `input` calls this closure with `map_in(input)`.
+    ///
+    /// This allows to adapt the input type of a closure, such as to accept a struct rather than a tuple, without rebuilding the capture logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// struct Edge {
+    ///     from: usize,
+    ///     to: usize,
+    /// }
+    ///
+    /// let weight: ClosureOneOf4<Vec<Vec<i32>>, String, bool, char, (usize, usize), i32> =
+    ///     Capture(vec![vec![1, 2], vec![3, 4]]).fun(|m, (i, j): (usize, usize)| m[i][j]).into_oneof4_var1();
+    ///
+    /// let weight = weight.premap(|e: Edge| (e.from, e.to));
+    /// assert_eq!(3, weight.call(Edge { from: 1, to: 0 }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn premap<NewIn>(
+        self,
+        map_in: fn(NewIn) -> In,
+    ) -> Closure<(Self, fn(NewIn) -> In), NewIn, Out> {
+        Closure::new((self, map_in), |(fun, map_in), input| {
+            fun.call(map_in(input))
+        })
+    }
+
     /// Returns the closure as an `impl Fn(In) -> Out` struct, allowing the convenience
     ///
     /// * to avoid the `call` method,
@@ -257,6 +330,117 @@ impl<C1, C2, C3, C4, In, Out> ClosureOneOf4<C1, C2, C3, C4, In, Out> {
     pub fn as_fn(&self) -> impl Fn(In) -> Out + '_ {
         move |x| self.call(x)
     }
+
+    /// Consumes the closure and returns it as an `impl Fn(In) -> Out` struct which owns the captured data.
+    ///
+    /// Unlike `as_fn`, the returned function does not borrow the closure; therefore, it can be returned from functions or stored,
+    /// and it is `'static` whenever the captured data is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// type Weight = ClosureOneOf4<Vec<i32>, String, char, bool, usize, i32>;
+    ///
+    /// fn weight(values: Vec<i32>) -> impl Fn(usize) -> i32 {
+    ///     let closure: Weight = Capture(values).fun(|v, i: usize| v[i]).into_oneof4_var1();
+    ///     closure.into_fn()
+    /// }
+    ///
+    /// let weight = weight(vec![4, 2]);
+    /// assert_eq!(6, (0..2).map(weight).sum::<i32>());
+    /// ```
+    pub fn into_fn(self) -> impl Fn(In) -> Out {
+        move |x| self.call(x)
+    }
+
+    /// Returns an `impl Fn(In) -> Out` struct which owns a shared reference to the closure.
+    ///
+    /// Unlike `as_fn`, the returned function does not borrow the closure; therefore, it can be stored, returned from functions
+    /// or used in iterator chains outliving the scope of the closure.
+    /// Unlike `into_fn`, the closure and its captured data are shared among all functions created from clones of the `Arc`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use std::sync::Arc;
+    ///
+    /// type Name = ClosureOneOf4<Vec<String>, String, char, bool, usize, usize>;
+    ///
+    /// fn names(ids: Vec<usize>, name: Arc<Name>) -> impl Iterator<Item = usize> {
+    ///     ids.into_iter().map(name.to_fn())
+    /// }
+    ///
+    /// let name: Name = Capture(vec![String::from("john"), String::from("doe")])
+    ///     .fun(|v, i: usize| v[i].len())
+    ///     .into_oneof4_var1();
+    /// let name = Arc::new(name);
+    ///
+    /// let names: Vec<_> = names(vec![1, 0], Arc::clone(&name)).collect();
+    /// assert_eq!(vec![3, 4], names);
+    /// ```
+    pub fn to_fn(self: Arc<Self>) -> impl Fn(In) -> Out {
+        move |x| self.call(x)
+    }
+
+    /// Consumes the closure and returns back the captured data as a type-erased `Box<dyn Any>`, without having to know or match the active variant.
+    ///
+    /// The result can be downcast to the capture type of the active variant.
+    /// This is useful for generic teardown code reclaiming the captured data, for instance, to be pooled and reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOneOf4<Vec<i32>, String, char, bool, usize, i32> =
+    ///     Capture(vec![1, 2]).fun(|v, i: usize| v[i]).into_oneof4_var1();
+    ///
+    /// let data = closure.into_captured_data_any();
+    /// assert_eq!(Some(&vec![1, 2]), data.downcast_ref::<Vec<i32>>());
+    /// assert!(!data.is::<String>());
+    /// ```
+    pub fn into_captured_data_any(self) -> Box<dyn Any>
+    where
+        C1: 'static,
+        C2: 'static,
+        C3: 'static,
+        C4: 'static,
+    {
+        match self.closure {
+            OneOf4::Variant1(fun) => Box::new(fun.into_captured_data()),
+            OneOf4::Variant2(fun) => Box::new(fun.into_captured_data()),
+            OneOf4::Variant3(fun) => Box::new(fun.into_captured_data()),
+            OneOf4::Variant4(fun) => Box::new(fun.into_captured_data()),
+        }
+    }
+
+    /// Consumes the union and returns back the concrete closure of the active variant, as a variant of `OneOf4`.
+    ///
+    /// After the configuration phase, matching on the result once allows to continue with the monomorphic closure,
+    /// avoiding the variant dispatch on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let closure: ClosureOneOf4<Vec<i32>, String, char, bool, usize, i32> =
+    ///     Capture(vec![1, 2]).fun(|v, i: usize| v[i]).into_oneof4_var1();
+    ///
+    /// let shrunk = closure.shrink();
+    /// assert!(matches!(shrunk, OneOf4::Variant1(_)));
+    ///
+    /// if let OneOf4::Variant1(fun) = shrunk {
+    ///     // fun: Closure<Vec<i32>, usize, i32>
+    ///     assert_eq!(2, fun.call(1));
+    /// }
+    /// ```
+    pub fn shrink(self) -> UnionClosures<C1, C2, C3, C4, In, Out> {
+        self.closure
+    }
 }
 
 impl<Capture, In, Out> Closure<Capture, In, Out> {
@@ -398,3 +582,115 @@ impl<C1, C2, C3, C4, In, Out> Fun<In, Out> for ClosureOneOf4<C1, C2, C3, C4, In,
         ClosureOneOf4::call(self, input)
     }
 }
+
+impl<C1, C2, C3, C4, In, Out, Error> TryFunVal<In, Out, Error>
+    for ClosureOneOf4<C1, C2, C3, C4, In, Result<Out, Error>>
+{
+    fn try_call_val(&self, input: In) -> Result<Out, Error> {
+        ClosureOneOf4::call(self, input)
+    }
+}
+
+impl<Var2, Var3, Var4, Capture, In, Out>
+    IntoOneOf<1, ClosureOneOf4<Capture, Var2, Var3, Var4, In, Out>> for Closure<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf4<Capture, Var2, Var3, Var4, In, Out> {
+        self.into_oneof4_var1()
+    }
+}
+
+impl<Var1, Var3, Var4, Capture, In, Out>
+    IntoOneOf<2, ClosureOneOf4<Var1, Capture, Var3, Var4, In, Out>> for Closure<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf4<Var1, Capture, Var3, Var4, In, Out> {
+        self.into_oneof4_var2()
+    }
+}
+
+impl<Var1, Var2, Var4, Capture, In, Out>
+    IntoOneOf<3, ClosureOneOf4<Var1, Var2, Capture, Var4, In, Out>> for Closure<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf4<Var1, Var2, Capture, Var4, In, Out> {
+        self.into_oneof4_var3()
+    }
+}
+
+impl<Var1, Var2, Var3, Capture, In, Out>
+    IntoOneOf<4, ClosureOneOf4<Var1, Var2, Var3, Capture, In, Out>> for Closure<Capture, In, Out>
+{
+    fn into_one_of(self) -> ClosureOneOf4<Var1, Var2, Var3, Capture, In, Out> {
+        self.into_oneof4_var4()
+    }
+}
+
+impl<C1, C2, C3, C4, Out> ClosureOneOf4<C1, C2, C3, C4, (usize, usize), Out> {
+    /// Evaluates the closure on every `(i, j)` pair of the `rows` x `cols` grid, and collects the results in row-major order.
+    ///
+    /// The variant of the closure is resolved once, rather than for each of the `rows * cols` calls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let jagged = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    /// let weight: ClosureOneOf4<Vec<Vec<i32>>, (), (), (), (usize, usize), i32> =
+    ///     Capture(jagged).fun(|x, (i, j): (usize, usize)| x[i][j]).into_oneof4_var1();
+    ///
+    /// assert_eq!(vec![0, 1, 3, 4], weight.eval_grid(2, 2));
+    /// ```
+    pub fn eval_grid(&self, rows: usize, cols: usize) -> Vec<Out> {
+        match &self.closure {
+            OneOf4::Variant1(fun) => fun.eval_grid(rows, cols),
+            OneOf4::Variant2(fun) => fun.eval_grid(rows, cols),
+            OneOf4::Variant3(fun) => fun.eval_grid(rows, cols),
+            OneOf4::Variant4(fun) => fun.eval_grid(rows, cols),
+        }
+    }
+
+    /// Returns an iterator yielding the results of evaluating the closure on every `(i, j)` pair of the `rows` x `cols` grid in row-major order.
+    ///
+    /// The variant of the closure is resolved once when the iterator is created; the iterator is the union of the grid iterators of the variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let jagged = vec![vec![0, 1, 2], vec![3, 4, 5]];
+    /// let weight: ClosureOneOf4<Vec<Vec<i32>>, (), (), (), (usize, usize), i32> =
+    ///     Capture(jagged).fun(|x, (i, j): (usize, usize)| x[i][j]).into_oneof4_var1();
+    ///
+    /// assert_eq!(15, weight.eval_grid_iter(2, 3).sum::<i32>());
+    /// ```
+    pub fn eval_grid_iter(&self, rows: usize, cols: usize) -> impl Iterator<Item = Out> + '_ {
+        match &self.closure {
+            OneOf4::Variant1(fun) => OneOf4::Variant1(fun.eval_grid_iter(rows, cols)),
+            OneOf4::Variant2(fun) => OneOf4::Variant2(fun.eval_grid_iter(rows, cols)),
+            OneOf4::Variant3(fun) => OneOf4::Variant3(fun.eval_grid_iter(rows, cols)),
+            OneOf4::Variant4(fun) => OneOf4::Variant4(fun.eval_grid_iter(rows, cols)),
+        }
+    }
+
+    /// Evaluates the closure on every `(i, j)` pair of the `rows` x `cols` grid in parallel, and collects the results in row-major order.
+    ///
+    /// The variant of the closure is resolved once, rather than for each of the `rows * cols` calls.
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn par_eval_grid(&self, rows: usize, cols: usize) -> Vec<Out>
+    where
+        C1: Sync,
+        C2: Sync,
+        C3: Sync,
+        C4: Sync,
+        Out: Send,
+    {
+        match &self.closure {
+            OneOf4::Variant1(fun) => fun.par_eval_grid(rows, cols),
+            OneOf4::Variant2(fun) => fun.par_eval_grid(rows, cols),
+            OneOf4::Variant3(fun) => fun.par_eval_grid(rows, cols),
+            OneOf4::Variant4(fun) => fun.par_eval_grid(rows, cols),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod closure_mut;
 pub(crate) mod closure_opt_ref;
 pub(crate) mod closure_ref;
 pub(crate) mod closure_res_ref;
@@ -0,0 +1,64 @@
+/// Creates a non-capturing accessor function navigating a path of fields, indices and method calls inside the captured data,
+/// to be used with `fun_ref` or `fun_option_ref`.
+///
+/// * `path!(Capture => path)` creates `fn(&Capture, ()) -> &Out` returning a reference to `data.path`;
+/// * `path!(Capture, id: In => path)` creates `fn(&Capture, In) -> &Out` where the input `id` can be used within the path, such as an index;
+/// * `path!(Capture => ? path)` and `path!(Capture, id: In => ? path)` create `fn(&Capture, In) -> Option<&Out>` where
+///   the `?` operator can be used within the path to navigate through optional values.
+///
+/// The path starts with a field or a method of the captured data, and it must denote a place such as a field or an indexed element,
+/// since the result is borrowed as `&data.path`.
+///
+/// The accessor borrows the result from the captured data; hence, it avoids the lifetime errors of returning references
+/// from closures when the data is captured by value.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::collections::HashMap;
+///
+/// struct Limits {
+///     per_user: Vec<Limit>,
+///     per_group: HashMap<String, Limit>,
+/// }
+/// struct Limit {
+///     max: u32,
+/// }
+/// struct Config {
+///     limits: Limits,
+/// }
+///
+/// let config = || Config {
+///     limits: Limits {
+///         per_user: vec![Limit { max: 3 }, Limit { max: 5 }],
+///         per_group: HashMap::from_iter([(String::from("admin"), Limit { max: 10 })]),
+///     },
+/// };
+///
+/// let first_max = Capture(config()).fun_ref(path!(Config => limits.per_user[0].max));
+/// assert_eq!(&3, first_max.call(()));
+///
+/// let user_max = Capture(config()).fun_ref(path!(Config, id: usize => limits.per_user[id].max));
+/// assert_eq!(&5, user_max.call(1));
+///
+/// let group_max = Capture(config())
+///     .fun_option_ref(path!(Config, group: &str => ? limits.per_group.get(group)?.max));
+/// assert_eq!(Some(&10), group_max.call("admin"));
+/// assert_eq!(None, group_max.call("guest"));
+/// ```
+#[macro_export]
+macro_rules! path {
+    ($capture:ty => ? $($path:tt)+) => {
+        |data: &$capture, _: ()| ::core::option::Option::Some(&data.$($path)+)
+    };
+    ($capture:ty, $input:ident : $in:ty => ? $($path:tt)+) => {
+        |data: &$capture, $input: $in| ::core::option::Option::Some(&data.$($path)+)
+    };
+    ($capture:ty => $($path:tt)+) => {
+        |data: &$capture, _: ()| &data.$($path)+
+    };
+    ($capture:ty, $input:ident : $in:ty => $($path:tt)+) => {
+        |data: &$capture, $input: $in| &data.$($path)+
+    };
+}
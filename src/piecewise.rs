@@ -0,0 +1,171 @@
+use crate::{closure_val::Closure, fun::Fun};
+use std::{fmt::Debug, ops::Range};
+
+/// Builder of a piecewise-defined function dispatching each input to the function of the range it belongs to.
+///
+/// * pieces are added with `on(range, fun)`, where the ranges are half-open and must not overlap,
+/// * the builder is completed with `otherwise(default)` which is called for the inputs not covered by any of the ranges.
+///
+/// The resulting [`Closure`] captures the pieces together with the default function.
+/// Pieces are kept sorted by the start of their ranges; hence, the piece of an input is found by a binary search.
+///
+/// All pieces share the same function type `F`, which can be any [`Fun<In, Out>`] such as a `Closure` over a common capture,
+/// a closure union such as `ClosureOneOf2` or simply a `fn(In) -> Out` pointer.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// // tiered shipping cost per weight in kg
+/// let rates = vec![1.0, 0.8, 0.5];
+///
+/// let cost = Piecewise::new()
+///     .on(0.0..10.0, Capture(rates.clone()).fun(|r, kg: f64| r[0] * kg))
+///     .on(10.0..100.0, Capture(rates.clone()).fun(|r, kg: f64| r[1] * kg))
+///     .otherwise(Capture(rates).fun(|r, kg: f64| r[2] * kg));
+///
+/// assert_eq!(5.0, cost.call(5.0));
+/// assert_eq!(40.0, cost.call(50.0));
+/// assert_eq!(100.0, cost.call(200.0));
+/// ```
+#[derive(Clone)]
+pub struct Piecewise<In, F> {
+    pieces: Vec<(Range<In>, F)>,
+}
+
+impl<In: Debug, F> Debug for Piecewise<In, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Piecewise")
+            .field(
+                "ranges",
+                &self.pieces.iter().map(|(r, _)| r).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<In, F> Default for Piecewise<In, F> {
+    fn default() -> Self {
+        Self { pieces: vec![] }
+    }
+}
+
+impl<In, F> Piecewise<In, F> {
+    /// Creates a new piecewise function builder without any pieces.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of pieces, excluding the default function.
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// Returns whether or not no piece is added yet.
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+}
+
+impl<In: PartialOrd, F> Piecewise<In, F> {
+    /// Adds the function `fun` to be used for the inputs within the half-open `range`; and returns back the builder.
+    ///
+    /// Pieces can be added in any order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `range` is empty or if it overlaps with a range added before.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let sign = Piecewise::<i32, fn(i32) -> i32>::new()
+    ///     .on(1..i32::MAX, |_: i32| 1)
+    ///     .on(i32::MIN..0, |_: i32| -1)
+    ///     .otherwise(|_: i32| 0);
+    ///
+    /// assert_eq!(vec![-1, 0, 1], [-42, 0, 7].map(sign.as_fn()));
+    /// ```
+    pub fn on(mut self, range: Range<In>, fun: F) -> Self {
+        assert!(range.start < range.end, "piecewise ranges cannot be empty");
+
+        let position = self.pieces.partition_point(|(r, _)| r.start < range.start);
+        let overlaps_prev = position > 0 && range.start < self.pieces[position - 1].0.end;
+        let overlaps_next =
+            position < self.pieces.len() && self.pieces[position].0.start < range.end;
+        assert!(
+            !overlaps_prev && !overlaps_next,
+            "piecewise ranges cannot overlap"
+        );
+
+        self.pieces.insert(position, (range, fun));
+        self
+    }
+
+    /// Returns a reference to the function of the piece whose range contains the `input`; None if the input is not covered by any of the ranges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let pieces = Piecewise::new()
+    ///     .on(0..10, Capture(1).fun(|a, x: i32| a + x))
+    ///     .on(10..20, Capture(2).fun(|a, x: i32| a * x));
+    ///
+    /// assert_eq!(Some(4), pieces.piece(&3).map(|f| f.call(3)));
+    /// assert_eq!(Some(30), pieces.piece(&15).map(|f| f.call(15)));
+    /// assert!(pieces.piece(&20).is_none());
+    /// ```
+    pub fn piece(&self, input: &In) -> Option<&F> {
+        let position = self.pieces.partition_point(|(r, _)| r.start <= *input);
+        match position {
+            0 => None,
+            _ => {
+                let (range, fun) = &self.pieces[position - 1];
+                match *input < range.end {
+                    true => Some(fun),
+                    false => None,
+                }
+            }
+        }
+    }
+
+    /// Completes the builder with the `default` function which is called for the inputs which do not belong to any of the ranges,
+    /// and returns the piecewise function as a closure.
+    ///
+    /// The returned closure captures the builder and the default function; these can be obtained back by `into_captured_data`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let tax = Piecewise::new()
+    ///     .on(0..1000, Capture(0).fun(|r, x: u32| x * r / 100))
+    ///     .on(1000..5000, Capture(10).fun(|r, x: u32| x * r / 100))
+    ///     .otherwise(Capture(20).fun(|r, x: u32| x * r / 100));
+    ///
+    /// assert_eq!(0, tax.call(500));
+    /// assert_eq!(200, tax.call(2000));
+    /// assert_eq!(2000, tax.call(10000));
+    ///
+    /// let (pieces, default) = tax.into_captured_data();
+    /// assert_eq!(2, pieces.len());
+    /// assert_eq!(&20, default.captured_data());
+    /// ```
+    pub fn otherwise<Out>(self, default: F) -> Closure<(Self, F), In, Out>
+    where
+        F: Fun<In, Out>,
+    {
+        Closure::new((self, default), |(pieces, default), input| {
+            match pieces.piece(&input) {
+                Some(fun) => fun.call(input),
+                None => default.call(input),
+            }
+        })
+    }
+}
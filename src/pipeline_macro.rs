@@ -0,0 +1,65 @@
+/// Creates a pipeline `Closure` owning each of the given stages, and hence, each of their captures,
+/// which calls the stages one after the other by feeding the output of each stage to the next.
+///
+/// `pipeline!(s1, s2, ..., sn)` creates a `Closure<(S1, (S2, (..., Sn))), In, Out>` such that calling it with `input`
+/// returns `sn.call(...s2.call(s1.call(input)))`.
+///
+/// Each stage can be any type with a `call(&self, In) -> Out` method, such as `Closure`, `ClosureOneOf2` or another pipeline.
+/// Since the resulting pipeline is a regular `Closure`:
+///
+/// * it exposes a single `call(In) -> Out` while keeping all captures explicit,
+/// * it is `Clone` whenever all stages are,
+/// * the stages can be obtained back by `into_captured_data`, nested as `(s1, (s2, (..., sn)))`.
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_closure::*;
+/// use std::collections::HashMap;
+///
+/// // extract
+/// let records = vec!["john,42", "jane,35", "doe,x"];
+/// let extract = Capture(records).fun(|r, i: usize| r[i].to_string());
+///
+/// // transform
+/// let parse = Capture(',').fun(|sep, line: String| {
+///     let (name, age) = line.split_once(*sep).unwrap();
+///     (name.to_string(), age.parse::<u32>().ok())
+/// });
+///
+/// // load
+/// let defaults = HashMap::from([("doe".to_string(), 50)]);
+/// let load = Capture(defaults).fun(|d, (name, age): (String, Option<u32>)| {
+///     let age = age.or_else(|| d.get(&name).copied()).unwrap_or(0);
+///     format!("{}:{}", name, age)
+/// });
+///
+/// let etl = pipeline!(extract, parse, load);
+///
+/// assert_eq!("john:42", etl.call(0));
+/// assert_eq!("doe:50", etl.call(2));
+///
+/// let (extract, (parse, load)) = etl.into_captured_data();
+/// assert_eq!(3, extract.captured_data().len());
+/// assert_eq!(&',', parse.captured_data());
+/// assert_eq!(1, load.captured_data().len());
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    (@nest $last:expr) => {
+        $last
+    };
+    (@nest $head:expr, $($tail:expr),+) => {
+        ($head, $crate::pipeline!(@nest $($tail),+))
+    };
+    (@call $stages:expr, $input:expr; $last:expr) => {
+        $stages.call($input)
+    };
+    (@call $stages:expr, $input:expr; $head:expr, $($tail:expr),+) => {
+        $crate::pipeline!(@call $stages.1, $stages.0.call($input); $($tail),+)
+    };
+    ($($stage:expr),+ $(,)?) => {
+        $crate::Capture($crate::pipeline!(@nest $($stage),+))
+            .fun(|stages, input| $crate::pipeline!(@call stages, input; $($stage),+))
+    };
+}
@@ -0,0 +1,206 @@
+use crate::{
+    fun::{Fun, FunOptRef, FunRef, FunResRef},
+    ClosureOptRef,
+};
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug};
+
+/// A closure serving recorded answers: given a log of `(In, Out)` pairs, it returns the output recorded for the first matching input, or `None` if the input has not been recorded.
+///
+/// It can be created from a `Recording` by `into_replay`, or directly from a log by `Capture(log).fun_option_ref(...)`.
+pub type Replay<In, Out> = ClosureOptRef<Vec<(In, Out)>, In, Out>;
+
+/// A decorator recording the `(input, output)` pair of every call of the wrapped function `F` into a bounded log.
+///
+/// * The log is a ring with the given `capacity`: once it is full, the oldest entry is dropped to make room for the new one.
+/// * Entries can be retrieved and cleared with `take_log`.
+/// * The recorded log can be turned into a `Replay` closure serving the recorded answers with `into_replay`.
+///
+/// `Recording` implements the same function trait as the wrapped function:
+///
+/// * `Fun<In, Out>` if `F: Fun<In, Out>`, logging `(In, Out)`;
+/// * `FunRef<In, Out>` if `F: FunRef<In, Out>`, logging `(In, Out::Owned)`;
+/// * `FunOptRef<In, Out>` if `F: FunOptRef<In, Out>`, logging `(In, Option<Out::Owned>)`;
+/// * `FunResRef<In, Out, Error>` if `F: FunResRef<In, Out, Error>`, logging `(In, Result<Out::Owned, Error>)`.
+///
+/// Since outputs of reference-returning functions are references into the captured data, they are cloned into the log as owned values.
+///
+/// Note that the log lives in a `RefCell`; therefore, `Recording` is not `Sync`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let weights = vec![vec![0, 3], vec![4, 0]];
+/// let weight = Capture(weights).fun(|w, (i, j): (usize, usize)| w[i][j]);
+///
+/// let recording = Recording::new(weight, 2);
+/// assert_eq!(3, recording.call((0, 1)));
+/// assert_eq!(4, recording.call((1, 0)));
+/// assert_eq!(0, recording.call((1, 1))); // drops ((0, 1), 3)
+///
+/// let log = recording.take_log();
+/// assert_eq!(vec![((1, 0), 4), ((1, 1), 0)], log);
+/// assert!(recording.take_log().is_empty());
+/// ```
+pub struct Recording<F, In, Logged> {
+    fun: F,
+    log: RefCell<VecDeque<(In, Logged)>>,
+    capacity: usize,
+}
+
+impl<F: Debug, In: Debug, Logged: Debug> Debug for Recording<F, In, Logged> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recording")
+            .field("fun", &self.fun)
+            .field("log", &self.log.borrow())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<F, In, Logged> Recording<F, In, Logged> {
+    /// Creates a recording decorator wrapping the function `fun` and keeping the last `capacity` calls in its log.
+    pub fn new(fun: F, capacity: usize) -> Self {
+        Self {
+            fun,
+            log: RefCell::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+
+    /// Takes out and returns the recorded `(input, output)` pairs in the order of the calls, leaving the log empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let names = vec!["john".to_string(), "doe".to_string()];
+    /// let name = Capture(names).fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()));
+    ///
+    /// let recording = Recording::new(name, 8);
+    /// assert_eq!(Some("doe"), recording.call(1));
+    /// assert_eq!(None, recording.call(2));
+    ///
+    /// // outputs are cloned into owned values
+    /// assert_eq!(
+    ///     vec![(1, Some("doe".to_string())), (2, None)],
+    ///     recording.take_log()
+    /// );
+    /// ```
+    pub fn take_log(&self) -> Vec<(In, Logged)> {
+        self.log.borrow_mut().drain(..).collect()
+    }
+
+    /// Returns the maximum number of calls kept in the log.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a reference to the wrapped function.
+    pub fn inner(&self) -> &F {
+        &self.fun
+    }
+
+    /// Consumes the recording and returns back the wrapped function together with the recorded log.
+    pub fn into_inner(self) -> (F, Vec<(In, Logged)>) {
+        (self.fun, self.log.into_inner().into_iter().collect())
+    }
+
+    /// Consumes the recording and returns a `Replay` closure serving the recorded answers.
+    ///
+    /// The replay closure returns the output recorded for the first matching input, or `None` if the input has not been recorded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    ///
+    /// let offset = 100;
+    /// let fun = Capture(offset).fun(|o, x: i32| x + o);
+    ///
+    /// let recording = Recording::new(fun, 16);
+    /// for x in [1, 2, 3] {
+    ///     recording.call(x);
+    /// }
+    ///
+    /// // reproduce the recorded calls without the original function
+    /// let replay = recording.into_replay();
+    /// assert_eq!(Some(&102), replay.call(2));
+    /// assert_eq!(None, replay.call(4));
+    /// ```
+    pub fn into_replay(self) -> Replay<In, Logged>
+    where
+        In: PartialEq,
+    {
+        let (_, log) = self.into_inner();
+        ClosureOptRef::new(log, |log, input| {
+            log.iter()
+                .find(|(logged, _)| *logged == input)
+                .map(|(_, output)| output)
+        })
+    }
+
+    fn record(&self, input: In, output: Logged) {
+        if self.capacity > 0 {
+            let mut log = self.log.borrow_mut();
+            if log.len() == self.capacity {
+                log.pop_front();
+            }
+            log.push_back((input, output));
+        }
+    }
+}
+
+impl<F: Fun<In, Out>, In: Clone, Out: Clone> Fun<In, Out> for Recording<F, In, Out> {
+    fn call(&self, input: In) -> Out {
+        let output = self.fun.call(input.clone());
+        self.record(input, output.clone());
+        output
+    }
+}
+
+impl<F, In, Out> FunRef<In, Out> for Recording<F, In, Out::Owned>
+where
+    F: FunRef<In, Out>,
+    In: Clone,
+    Out: ToOwned + ?Sized,
+{
+    fn call(&self, input: In) -> &Out {
+        let output = self.fun.call(input.clone());
+        self.record(input, output.to_owned());
+        output
+    }
+}
+
+impl<F, In, Out> FunOptRef<In, Out> for Recording<F, In, Option<Out::Owned>>
+where
+    F: FunOptRef<In, Out>,
+    In: Clone,
+    Out: ToOwned + ?Sized,
+{
+    fn call(&self, input: In) -> Option<&Out> {
+        let output = self.fun.call(input.clone());
+        self.record(input, output.map(|x| x.to_owned()));
+        output
+    }
+}
+
+impl<F, In, Out, Error> FunResRef<In, Out, Error> for Recording<F, In, Result<Out::Owned, Error>>
+where
+    F: FunResRef<In, Out, Error>,
+    In: Clone,
+    Out: ToOwned + ?Sized,
+    Error: Clone,
+{
+    fn call(&self, input: In) -> Result<&Out, Error> {
+        let output = self.fun.call(input.clone());
+        let logged = match &output {
+            Ok(x) => Ok((*x).to_owned()),
+            Err(e) => Err(e.clone()),
+        };
+        self.record(input, logged);
+        output
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::{Mutex, PoisonError},
+};
+
+/// A thread-safe pool of scratch spaces to be used by `ClosureScratch` closures in multi-threaded code.
+///
+/// * `get` pops an idle scratch space from the pool, or creates a new one with the `create` function if none is idle;
+/// * the returned `ScratchGuard` gives mutable access to the scratch space and returns it back to the pool when dropped.
+///
+/// Therefore, the number of scratch spaces ever created is bounded by the maximum number of concurrent calls.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let pool = ScratchPool::new(|| Vec::<u32>::with_capacity(64));
+///
+/// {
+///     let mut scratch = pool.get();
+///     scratch.push(42);
+/// } // returned back to the pool
+///
+/// assert_eq!(1, pool.num_idle());
+///
+/// let scratch = pool.get(); // the same buffer is reused
+/// assert_eq!(&[42], scratch.as_slice());
+/// assert_eq!(0, pool.num_idle());
+/// ```
+pub struct ScratchPool<Scratch> {
+    idle: Mutex<Vec<Scratch>>,
+    create: fn() -> Scratch,
+}
+
+impl<Scratch> Debug for ScratchPool<Scratch> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScratchPool")
+            .field("num_idle", &self.num_idle())
+            .finish()
+    }
+}
+
+impl<Scratch> ScratchPool<Scratch> {
+    /// Creates an empty pool which creates new scratch spaces with the `create` function whenever there is no idle scratch space.
+    pub fn new(create: fn() -> Scratch) -> Self {
+        Self {
+            idle: Mutex::new(Vec::new()),
+            create,
+        }
+    }
+
+    /// Borrows a scratch space from the pool, which is returned back to the pool when the guard is dropped.
+    ///
+    /// Note that the scratch space is not reset; the function using the scratch is responsible for clearing it when required.
+    pub fn get(&self) -> ScratchGuard<'_, Scratch> {
+        let scratch = self.lock().pop().unwrap_or_else(self.create);
+        ScratchGuard {
+            pool: self,
+            scratch: Some(scratch),
+        }
+    }
+
+    /// Returns the number of idle scratch spaces in the pool.
+    pub fn num_idle(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Scratch>> {
+        // scratch spaces hold no invariants, poisoning can safely be ignored
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A scratch space borrowed from a `ScratchPool`, which is returned back to the pool when dropped.
+pub struct ScratchGuard<'a, Scratch> {
+    pool: &'a ScratchPool<Scratch>,
+    scratch: Option<Scratch>,
+}
+
+impl<'a, Scratch: Debug> Debug for ScratchGuard<'a, Scratch> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScratchGuard")
+            .field("scratch", self.deref())
+            .finish()
+    }
+}
+
+impl<'a, Scratch> Deref for ScratchGuard<'a, Scratch> {
+    type Target = Scratch;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.scratch {
+            Some(scratch) => scratch,
+            None => unreachable!("scratch is only taken on drop"),
+        }
+    }
+}
+
+impl<'a, Scratch> DerefMut for ScratchGuard<'a, Scratch> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.scratch {
+            Some(scratch) => scratch,
+            None => unreachable!("scratch is only taken on drop"),
+        }
+    }
+}
+
+impl<'a, Scratch> Drop for ScratchGuard<'a, Scratch> {
+    fn drop(&mut self) {
+        if let Some(scratch) = self.scratch.take() {
+            self.pool.lock().push(scratch);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{closure_val::Closure, fun::Fun};
+
+/// Creates a closure routing each input either to `if_true` or to `if_false` depending on the result of the predicate `pred` on the input.
+///
+/// The predicate receives a reference to the input; hence, the input is moved into the selected branch without being cloned.
+/// Any function generic over the lifetime of the reference can be used as the predicate, such as a [`ClosureKey`](crate::ClosureKey) or a `fn(&In) -> bool`.
+///
+/// The resulting closure captures the predicate together with both branches, which can be obtained back by `into_captured_data`.
+/// The branches may be of different types as long as they share the same input and output types.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// let is_weekend = Capture([5, 6]).fun_key(|w, day: &usize| w.contains(day));
+/// let weekend_rate = Capture(1.5).fun(|r, _: usize| *r);
+/// let weekday_rates = Capture(vec![1.0, 1.0, 1.1, 1.0, 1.2]).fun(|r, day: usize| r[day]);
+///
+/// let rate = select_by(is_weekend, weekend_rate, weekday_rates);
+///
+/// assert_eq!(1.1, rate.call(2));
+/// assert_eq!(1.5, rate.call(6));
+///
+/// let (is_weekend, _, _) = rate.into_captured_data();
+/// assert_eq!(&[5, 6], is_weekend.captured_data());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn select_by<In, Out, P, T, F>(pred: P, if_true: T, if_false: F) -> Closure<(P, T, F), In, Out>
+where
+    P: for<'a> Fun<&'a In, bool>,
+    T: Fun<In, Out>,
+    F: Fun<In, Out>,
+{
+    Closure::new(
+        (pred, if_true, if_false),
+        |(pred, if_true, if_false), input| match pred.call(&input) {
+            true => if_true.call(input),
+            false => if_false.call(input),
+        },
+    )
+}
@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+
+/// Setter strictly separating the captured data from the update function, and hence, having two components:
+///
+/// * `Capture` is any captured data,
+/// * `fn(&mut Capture, In, V)` is the update writing the value `V` at the position `In` of the captured data.
+///
+/// It is the write counterpart of the value and reference closures which read from the captured data;
+/// see [`Lens`](crate::Lens) for a pair of read and write accessors.
+///
+/// Note that, unlike trait objects of fn-traits, `Setter` auto-implements `Clone` given that captured data is cloneable.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+///
+/// // set_weight: Setter<Vec<Vec<u32>>, (usize, usize), u32>
+/// let mut set_weight = Capture(vec![vec![0; 3]; 2]).setter(|w, (i, j): (usize, usize), value: u32| {
+///     w[i][j] = value;
+/// });
+///
+/// set_weight.set((0, 2), 7);
+/// set_weight.set((1, 0), 3);
+///
+/// assert_eq!(vec![vec![0, 0, 7], vec![3, 0, 0]], set_weight.into_captured_data());
+/// ```
+#[derive(Clone)]
+pub struct Setter<Capture, In, V> {
+    capture: Capture,
+    fun: fn(&mut Capture, In, V),
+}
+
+impl<Capture: Debug, In, V> Debug for Setter<Capture, In, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Setter")
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl<Capture, In, V> Setter<Capture, In, V> {
+    pub(super) fn new(capture: Capture, fun: fn(&mut Capture, In, V)) -> Self {
+        Self { capture, fun }
+    }
+
+    /// Writes the `value` at the position `input` of the captured data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::Capture;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut set_age = Capture(HashMap::new()).setter(|ages, name: &str, age: u32| {
+    ///     ages.insert(name.to_string(), age);
+    /// });
+    ///
+    /// set_age.set("john", 42);
+    /// set_age.set("john", 43);
+    ///
+    /// assert_eq!(Some(&43), set_age.captured_data().get("john"));
+    /// ```
+    #[inline(always)]
+    pub fn set(&mut self, input: In, value: V) {
+        (self.fun)(&mut self.capture, input, value)
+    }
+
+    /// Returns a reference to the captured data.
+    #[inline(always)]
+    pub fn captured_data(&self) -> &Capture {
+        &self.capture
+    }
+
+    /// Consumes the setter and returns back the captured data.
+    pub fn into_captured_data(self) -> Capture {
+        self.capture
+    }
+}
@@ -0,0 +1,630 @@
+//! Test doubles for code which is generic over the function traits of this crate.
+//!
+//! The module is available with the `testing` feature, which is meant to be enabled only in `[dev-dependencies]`.
+//!
+//! Crates accepting an `F: Fun<In, Out>`, `F: FunRef<In, Out>`, etc. can test their logic with a [`MockFun`]
+//! rather than constructing realistic captured data.
+//!
+//! Further, any function can be wrapped in an [`AllocTracked`] to verify that its calls do not allocate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orx_closure::*;
+//! use orx_closure::testing::MockFun;
+//!
+//! // code under test
+//! fn total_cost<F: Fun<(usize, usize), u32>>(cost: &F, path: &[usize]) -> u32 {
+//!     path.windows(2).map(|w| cost.call((w[0], w[1]))).sum()
+//! }
+//!
+//! let cost = MockFun::new()
+//!     .returns((0, 1), 10u32)
+//!     .returns((1, 2), 5);
+//!
+//! assert_eq!(15, total_cost(&cost, &[0, 1, 2]));
+//!
+//! cost.assert_num_calls_with(&(0, 1), 1);
+//! cost.assert_num_calls_with(&(1, 2), 1);
+//! ```
+
+use crate::fun::{
+    Fun, FunLookup, FunMut, FunOptRef, FunOptRefMut, FunRef, FunRefMut, FunResRef, FunResRefMut,
+    TryFun,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    cell::Cell,
+    convert::Infallible,
+    fmt::Debug,
+};
+
+#[derive(Debug, Clone)]
+enum Response<Out, Error> {
+    Value(Out),
+    Missing,
+    Error(Error),
+}
+
+#[derive(Debug, Clone)]
+struct Expectation<In, Out, Error> {
+    input: In,
+    response: Response<Out, Error>,
+    num_calls: Cell<usize>,
+}
+
+/// A mock function with programmable responses which implements all function traits of this crate.
+///
+/// * Responses are defined per input by [`returns`](Self::returns), [`returns_none`](Self::returns_none) and [`returns_err`](Self::returns_err).
+/// * Number of calls are tracked per input and can be asserted by [`assert_num_calls_with`](Self::assert_num_calls_with).
+///
+/// Since the mock owns the responses, it can implement the reference returning traits such as `FunRef<In, Out>` without leaking:
+/// the returned references borrow from the mock itself.
+/// Further, any `Owned: Borrow<Out>` response works as a response of type `Out`, for instance, `String` responses can be returned as `&str`.
+///
+/// # Panics
+///
+/// Calling the mock panics:
+///
+/// * when the input does not match any of the programmed inputs,
+/// * when the programmed response cannot be represented by the called trait, such as a `returns_none` response through the `Fun` trait.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use orx_closure::testing::MockFun;
+///
+/// fn greet<F: FunOptRef<u32, str>>(name_of: &F, id: u32) -> String {
+///     match name_of.call(id) {
+///         Some(name) => format!("hello {}", name),
+///         None => "who are you?".to_string(),
+///     }
+/// }
+///
+/// let name_of = MockFun::new()
+///     .returns(1, "john".to_string())
+///     .returns_none(2);
+///
+/// assert_eq!("hello john", greet(&name_of, 1));
+/// assert_eq!("who are you?", greet(&name_of, 2));
+/// assert_eq!("who are you?", greet(&name_of, 2));
+///
+/// assert_eq!(3, name_of.num_calls());
+/// name_of.assert_num_calls_with(&2, 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockFun<In, Out, Error = Infallible> {
+    expectations: Vec<Expectation<In, Out, Error>>,
+}
+
+impl<In, Out, Error> Default for MockFun<In, Out, Error> {
+    fn default() -> Self {
+        Self {
+            expectations: Vec::new(),
+        }
+    }
+}
+
+impl<In: PartialEq + Debug, Out> MockFun<In, Out> {
+    /// Creates a new mock function without any programmed responses.
+    ///
+    /// The created mock cannot respond with errors; see [`new_fallible`](MockFun::new_fallible) to program error responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<In: PartialEq + Debug, Out, Error> MockFun<In, Out, Error> {
+    /// Creates a new mock function without any programmed responses, which can be programmed to respond with errors of type `Error`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use orx_closure::testing::MockFun;
+    ///
+    /// let mock = MockFun::new_fallible().returns(0, 42).returns_err(1, "too far");
+    ///
+    /// assert_eq!(Ok(&42), TryFun::try_call(&mock, 0));
+    /// assert_eq!(Err("too far"), TryFun::<_, i32, _>::try_call(&mock, 1));
+    /// ```
+    pub fn new_fallible() -> Self {
+        Self::default()
+    }
+
+    /// Programs the mock to return `output` when called with `input`.
+    ///
+    /// A later response programmed for an equal input overrides the earlier one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use orx_closure::testing::MockFun;
+    ///
+    /// let mock = MockFun::new().returns('a', 1).returns('b', 2).returns('a', 3);
+    ///
+    /// assert_eq!(3, Fun::call(&mock, 'a'));
+    /// assert_eq!(2, Fun::call(&mock, 'b'));
+    /// ```
+    pub fn returns(self, input: In, output: Out) -> Self {
+        self.with_response(input, Response::Value(output))
+    }
+
+    /// Programs the mock to return `None` when called with `input`.
+    ///
+    /// This response is valid only for the optional traits such as `FunOptRef` or `FunLookup`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use orx_closure::testing::MockFun;
+    ///
+    /// let mock = MockFun::new().returns(0, 42).returns_none(1);
+    ///
+    /// assert_eq!(Some(&42), FunOptRef::call(&mock, 0));
+    /// assert_eq!(None, FunOptRef::<_, i32>::call(&mock, 1));
+    /// ```
+    pub fn returns_none(self, input: In) -> Self {
+        self.with_response(input, Response::Missing)
+    }
+
+    /// Programs the mock to return the `error` when called with `input`.
+    ///
+    /// This response is valid only for the fallible traits such as `FunResRef` or `TryFun`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use orx_closure::testing::MockFun;
+    ///
+    /// let mock = MockFun::new_fallible().returns(0, 42).returns_err(1, "too far");
+    ///
+    /// assert_eq!(Ok(&42), FunResRef::call(&mock, 0));
+    /// assert_eq!(Err("too far"), FunResRef::<_, i32, _>::call(&mock, 1));
+    /// ```
+    pub fn returns_err(self, input: In, error: Error) -> Self {
+        self.with_response(input, Response::Error(error))
+    }
+
+    /// Returns the total number of calls made to the mock.
+    pub fn num_calls(&self) -> usize {
+        self.expectations.iter().map(|x| x.num_calls.get()).sum()
+    }
+
+    /// Returns the number of calls made to the mock with the given `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_closure::*;
+    /// use orx_closure::testing::MockFun;
+    ///
+    /// let mock = MockFun::new().returns(0, 'x').returns(1, 'y');
+    ///
+    /// let chars: String = [0, 1, 1].into_iter().map(|i| Fun::call(&mock, i)).collect();
+    /// assert_eq!("xyy", chars);
+    ///
+    /// assert_eq!(1, mock.num_calls_with(&0));
+    /// assert_eq!(2, mock.num_calls_with(&1));
+    /// assert_eq!(0, mock.num_calls_with(&42));
+    /// ```
+    pub fn num_calls_with(&self, input: &In) -> usize {
+        self.find(input).map(|x| x.num_calls.get()).unwrap_or(0)
+    }
+
+    /// Asserts that the mock is called exactly `expected` times with the given `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of calls with the `input` differs from `expected`.
+    pub fn assert_num_calls_with(&self, input: &In, expected: usize) {
+        let num_calls = self.num_calls_with(input);
+        assert_eq!(
+            expected, num_calls,
+            "MockFun expected {} call(s) with input {:?}, but received {}",
+            expected, input, num_calls
+        );
+    }
+
+    /// Asserts that the mock is called at least once with each of the programmed inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the programmed inputs is never called.
+    pub fn assert_all_called(&self) {
+        let never_called: Vec<_> = self
+            .expectations
+            .iter()
+            .filter(|x| x.num_calls.get() == 0)
+            .map(|x| &x.input)
+            .collect();
+        assert!(
+            never_called.is_empty(),
+            "MockFun is never called with inputs {:?}",
+            never_called
+        );
+    }
+
+    fn with_response(mut self, input: In, response: Response<Out, Error>) -> Self {
+        self.expectations.retain(|x| x.input != input);
+        self.expectations.push(Expectation {
+            input,
+            response,
+            num_calls: Cell::new(0),
+        });
+        self
+    }
+
+    fn find(&self, input: &In) -> Option<&Expectation<In, Out, Error>> {
+        self.expectations.iter().find(|x| &x.input == input)
+    }
+
+    fn respond(&self, input: In) -> &Response<Out, Error> {
+        match self.find(&input) {
+            Some(expectation) => {
+                expectation.num_calls.set(expectation.num_calls.get() + 1);
+                &expectation.response
+            }
+            None => unexpected(&input),
+        }
+    }
+
+    fn respond_mut(&mut self, input: In) -> &mut Response<Out, Error> {
+        match self.expectations.iter_mut().find(|x| x.input == input) {
+            Some(expectation) => {
+                *expectation.num_calls.get_mut() += 1;
+                &mut expectation.response
+            }
+            None => unexpected(&input),
+        }
+    }
+}
+
+#[allow(clippy::panic)]
+fn unexpected<In: Debug>(input: &In) -> ! {
+    panic!("MockFun is called with the unexpected input {:?}", input)
+}
+
+#[allow(clippy::panic)]
+fn unrepresentable(trait_name: &str, response: &str) -> ! {
+    panic!(
+        "MockFun is programmed to respond with {} which cannot be returned by {}",
+        response, trait_name
+    )
+}
+
+impl<Out, Error> Response<Out, Error> {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Value(_) => "a value",
+            Self::Missing => "none",
+            Self::Error(_) => "an error",
+        }
+    }
+
+    fn value(&self, trait_name: &str) -> &Out {
+        match self {
+            Self::Value(x) => x,
+            _ => unrepresentable(trait_name, self.name()),
+        }
+    }
+
+    fn value_mut(&mut self, trait_name: &str) -> &mut Out {
+        match self {
+            Self::Value(x) => x,
+            _ => unrepresentable(trait_name, self.name()),
+        }
+    }
+
+    fn option(&self, trait_name: &str) -> Option<&Out> {
+        match self {
+            Self::Value(x) => Some(x),
+            Self::Missing => None,
+            Self::Error(_) => unrepresentable(trait_name, self.name()),
+        }
+    }
+
+    fn option_mut(&mut self, trait_name: &str) -> Option<&mut Out> {
+        match self {
+            Self::Value(x) => Some(x),
+            Self::Missing => None,
+            Self::Error(_) => unrepresentable(trait_name, self.name()),
+        }
+    }
+}
+
+impl<Out, Error: Clone> Response<Out, Error> {
+    fn result(&self, trait_name: &str) -> Result<&Out, Error> {
+        match self {
+            Self::Value(x) => Ok(x),
+            Self::Error(e) => Err(e.clone()),
+            Self::Missing => unrepresentable(trait_name, self.name()),
+        }
+    }
+
+    fn result_mut(&mut self, trait_name: &str) -> Result<&mut Out, Error> {
+        match self {
+            Self::Value(x) => Ok(x),
+            Self::Error(e) => Err(e.clone()),
+            Self::Missing => unrepresentable(trait_name, self.name()),
+        }
+    }
+}
+
+impl<In: PartialEq + Debug, Out: Clone, Error> Fun<In, Out> for MockFun<In, Out, Error> {
+    fn call(&self, input: In) -> Out {
+        self.respond(input).value("Fun").clone()
+    }
+}
+
+impl<In: PartialEq + Debug, Out: Clone, Error> FunMut<In, Out> for MockFun<In, Out, Error> {
+    fn call_mut(&mut self, input: In) -> Out {
+        self.respond_mut(input).value("FunMut").clone()
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: Borrow<Out>, Out: ?Sized, Error> FunRef<In, Out>
+    for MockFun<In, Owned, Error>
+{
+    fn call(&self, input: In) -> &Out {
+        self.respond(input).value("FunRef").borrow()
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: Borrow<Out>, Out: ?Sized, Error> FunOptRef<In, Out>
+    for MockFun<In, Owned, Error>
+{
+    fn call(&self, input: In) -> Option<&Out> {
+        self.respond(input).option("FunOptRef").map(|x| x.borrow())
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: Borrow<Out>, Out: ?Sized, Error> FunLookup<In, Out>
+    for MockFun<In, Owned, Error>
+{
+    fn get(&self, key: In) -> Option<&Out> {
+        self.respond(key).option("FunLookup").map(|x| x.borrow())
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: Borrow<Out>, Out: ?Sized, Error: Clone> FunResRef<In, Out, Error>
+    for MockFun<In, Owned, Error>
+{
+    fn call(&self, input: In) -> Result<&Out, Error> {
+        self.respond(input).result("FunResRef").map(|x| x.borrow())
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: Borrow<Out>, Out: ?Sized, Error: Clone> TryFun<In, Out, Error>
+    for MockFun<In, Owned, Error>
+{
+    fn try_call(&self, input: In) -> Result<&Out, Error> {
+        self.respond(input).result("TryFun").map(|x| x.borrow())
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: BorrowMut<Out>, Out: ?Sized, Error> FunRefMut<In, Out>
+    for MockFun<In, Owned, Error>
+{
+    fn call_mut(&mut self, input: In) -> &mut Out {
+        self.respond_mut(input).value_mut("FunRefMut").borrow_mut()
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: BorrowMut<Out>, Out: ?Sized, Error> FunOptRefMut<In, Out>
+    for MockFun<In, Owned, Error>
+{
+    fn call_mut(&mut self, input: In) -> Option<&mut Out> {
+        self.respond_mut(input)
+            .option_mut("FunOptRefMut")
+            .map(|x| x.borrow_mut())
+    }
+}
+
+impl<In: PartialEq + Debug, Owned: BorrowMut<Out>, Out: ?Sized, Error: Clone>
+    FunResRefMut<In, Out, Error> for MockFun<In, Owned, Error>
+{
+    fn call_mut(&mut self, input: In) -> Result<&mut Out, Error> {
+        self.respond_mut(input)
+            .result_mut("FunResRefMut")
+            .map(|x| x.borrow_mut())
+    }
+}
+
+/// Allocation statistics collected by an [`AllocTracked`] function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of calls made to the tracked function.
+    pub num_calls: usize,
+    /// Total number of allocations observed during the calls.
+    pub num_allocations: usize,
+}
+
+impl AllocStats {
+    /// Returns the average number of allocations per call; zero if the function is never called.
+    pub fn allocations_per_call(&self) -> f64 {
+        match self.num_calls {
+            0 => 0.0,
+            n => self.num_allocations as f64 / n as f64,
+        }
+    }
+}
+
+/// A wrapper around a function implementing the function traits of this crate, which counts the allocations made during each call.
+///
+/// Allocations are observed through `counter` which must return the total number of allocations made so far by the process;
+/// the allocations of a call is the difference between the counter values after and before the call.
+/// The counter is typically provided by a counting global allocator defined in the test crate.
+/// Such an allocator requires `unsafe` code which this crate forbids; however, it is a small shim as demonstrated in the example below.
+///
+/// The wrapper implements `Fun`, `FunMut`, `FunRef`, `FunOptRef` and `FunResRef` whenever the wrapped function does;
+/// hence, it can be used to verify that a closure or any of the closure unions does not allocate per call.
+/// Statistics are collected per wrapper, and hence, per closure, and can be obtained by [`alloc_stats`](Self::alloc_stats).
+///
+/// Note that the counter is global; therefore, allocations made concurrently by other threads are attributed to the call as well.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_closure::*;
+/// use orx_closure::testing::AllocTracked;
+/// use std::alloc::{GlobalAlloc, Layout, System};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// // counting allocator shim of the test crate
+/// static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct Counting;
+///
+/// unsafe impl GlobalAlloc for Counting {
+///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+///         NUM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+///         System.alloc(layout)
+///     }
+///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+///         System.dealloc(ptr, layout)
+///     }
+/// }
+///
+/// #[global_allocator]
+/// static GLOBAL: Counting = Counting;
+///
+/// fn num_allocations() -> usize {
+///     NUM_ALLOCATIONS.load(Ordering::Relaxed)
+/// }
+///
+/// // zero-allocation provider
+/// let weight: ClosureOneOf2<Vec<u32>, u32, usize, u32> =
+///     Capture(vec![3, 5]).fun(|w, i: usize| w[i]).into_oneof2_var1();
+/// let weight = AllocTracked::new(weight, num_allocations);
+///
+/// let total: u32 = (0..2).map(|i| Fun::call(&weight, i)).sum();
+/// assert_eq!(8, total);
+/// assert_eq!(2, weight.alloc_stats().num_calls);
+/// assert_eq!(0, weight.alloc_stats().num_allocations);
+///
+/// // allocating provider
+/// let label = Capture("node").fun(|prefix, i: usize| format!("{}-{}", prefix, i));
+/// let label = AllocTracked::new(label, num_allocations);
+///
+/// assert_eq!("node-1", Fun::call(&label, 1));
+/// assert!(label.alloc_stats().num_allocations > 0);
+/// ```
+pub struct AllocTracked<F> {
+    fun: F,
+    counter: fn() -> usize,
+    stats: Cell<AllocStats>,
+}
+
+impl<F: Debug> Debug for AllocTracked<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AllocTracked")
+            .field("fun", &self.fun)
+            .field("stats", &self.stats.get())
+            .finish()
+    }
+}
+
+impl<F> AllocTracked<F> {
+    /// Wraps the function `fun` to count the allocations of its calls as observed by the `counter`.
+    pub fn new(fun: F, counter: fn() -> usize) -> Self {
+        Self {
+            fun,
+            counter,
+            stats: Cell::new(AllocStats::default()),
+        }
+    }
+
+    /// Returns the allocation statistics collected so far.
+    pub fn alloc_stats(&self) -> AllocStats {
+        self.stats.get()
+    }
+
+    /// Resets the collected allocation statistics.
+    pub fn reset(&self) {
+        self.stats.set(AllocStats::default());
+    }
+
+    /// Asserts that none of the calls made so far has allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any allocation is observed during the calls.
+    pub fn assert_no_allocations(&self) {
+        let stats = self.stats.get();
+        assert_eq!(
+            0, stats.num_allocations,
+            "AllocTracked expected no allocations, but observed {} allocation(s) in {} call(s)",
+            stats.num_allocations, stats.num_calls
+        );
+    }
+
+    /// Returns a reference to the wrapped function.
+    pub fn inner(&self) -> &F {
+        &self.fun
+    }
+
+    /// Consumes the wrapper and returns back the wrapped function.
+    pub fn into_inner(self) -> F {
+        self.fun
+    }
+
+    fn record(&self, before: usize) {
+        let after = (self.counter)();
+        let mut stats = self.stats.get();
+        stats.num_calls += 1;
+        stats.num_allocations += after.saturating_sub(before);
+        self.stats.set(stats);
+    }
+}
+
+impl<In, Out, F: Fun<In, Out>> Fun<In, Out> for AllocTracked<F> {
+    fn call(&self, input: In) -> Out {
+        let before = (self.counter)();
+        let output = self.fun.call(input);
+        self.record(before);
+        output
+    }
+}
+
+impl<In, Out, F: FunMut<In, Out>> FunMut<In, Out> for AllocTracked<F> {
+    fn call_mut(&mut self, input: In) -> Out {
+        let before = (self.counter)();
+        let output = self.fun.call_mut(input);
+        self.record(before);
+        output
+    }
+}
+
+impl<In, Out: ?Sized, F: FunRef<In, Out>> FunRef<In, Out> for AllocTracked<F> {
+    fn call(&self, input: In) -> &Out {
+        let before = (self.counter)();
+        let output = self.fun.call(input);
+        self.record(before);
+        output
+    }
+}
+
+impl<In, Out: ?Sized, F: FunOptRef<In, Out>> FunOptRef<In, Out> for AllocTracked<F> {
+    fn call(&self, input: In) -> Option<&Out> {
+        let before = (self.counter)();
+        let output = self.fun.call(input);
+        self.record(before);
+        output
+    }
+}
+
+impl<In, Out: ?Sized, Error, F: FunResRef<In, Out, Error>> FunResRef<In, Out, Error>
+    for AllocTracked<F>
+{
+    fn call(&self, input: In) -> Result<&Out, Error> {
+        let before = (self.counter)();
+        let output = self.fun.call(input);
+        self.record(before);
+        output
+    }
+}
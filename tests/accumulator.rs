@@ -0,0 +1,76 @@
+use orx_closure::*;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Running {
+    count: usize,
+    mean: f64,
+}
+
+fn running_mean() -> Accumulator<Running, f64> {
+    Capture(Running::default()).accumulator(|r, x: f64| {
+        r.count += 1;
+        r.mean += (x - r.mean) / r.count as f64;
+    })
+}
+
+#[test]
+fn accumulator_push_and_extend() {
+    let mut mean = running_mean();
+    mean.push(1.0);
+    mean.push(3.0);
+    assert_eq!(2.0, mean.captured_data().mean);
+
+    mean.extend(vec![5.0, 7.0]);
+    assert_eq!(
+        Running {
+            count: 4,
+            mean: 4.0
+        },
+        mean.into_captured_data()
+    );
+}
+
+#[test]
+fn accumulator_fold() {
+    let state = running_mean().fold((1..=9).map(|x| x as f64));
+    assert_eq!(9, state.count);
+    assert_eq!(5.0, state.mean);
+
+    let empty = running_mean().fold([]);
+    assert_eq!(Running::default(), empty);
+}
+
+#[test]
+fn accumulator_with_captured_context() {
+    let mut events = Capture((vec!["a", "b"], 0usize)).accumulator(|(known, unknown), e: &str| {
+        if !known.contains(&e) {
+            *unknown += 1;
+        }
+    });
+    events.extend(["a", "c", "d", "b"]);
+    assert_eq!(2, events.captured_data().1);
+}
+
+#[test]
+fn accumulator_clone_forks_state() {
+    let mut a = running_mean();
+    a.push(10.0);
+    let mut b = a.clone();
+    b.push(20.0);
+
+    assert_eq!(10.0, a.captured_data().mean);
+    assert_eq!(15.0, b.captured_data().mean);
+}
+
+#[test]
+fn accumulator_as_fun_mut() {
+    fn feed<F: FunMut<u8, ()>>(fun: &mut F, bytes: &[u8]) {
+        for b in bytes {
+            fun.call_mut(*b);
+        }
+    }
+
+    let mut checksum = Capture(0u8).accumulator(|c, b: u8| *c = c.wrapping_add(b));
+    feed(&mut checksum, &[200, 100, 1]);
+    assert_eq!(&45, checksum.captured_data());
+}
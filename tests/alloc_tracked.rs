@@ -0,0 +1,144 @@
+#![cfg(feature = "testing")]
+
+use orx_closure::testing::{AllocStats, AllocTracked};
+use orx_closure::*;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+// counts per thread so that tests running in parallel do not observe each other's allocations
+thread_local! {
+    static NUM_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = NUM_ALLOCATIONS.try_with(|x| x.set(x.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn num_allocations() -> usize {
+    NUM_ALLOCATIONS.with(|x| x.get())
+}
+
+type Names = Vec<String>;
+
+fn names() -> Names {
+    vec![String::from("john"), String::from("doe")]
+}
+
+#[test]
+fn alloc_tracked_val_unions() {
+    let one: ClosureOneOf2<Names, (), usize, usize> = Capture(names())
+        .fun(|n, i: usize| n[i].len())
+        .into_oneof2_var1();
+    let three: ClosureOneOf3<(), Names, char, usize, usize> = Capture(names())
+        .fun(|n, i: usize| n[i].len())
+        .into_oneof3_var2();
+    let four: ClosureOneOf4<(), char, bool, Names, usize, usize> = Capture(names())
+        .fun(|n, i: usize| n[i].len())
+        .into_oneof4_var4();
+
+    let one = AllocTracked::new(one, num_allocations);
+    let three = AllocTracked::new(three, num_allocations);
+    let four = AllocTracked::new(four, num_allocations);
+
+    for i in 0..2 {
+        assert_eq!(Fun::call(&one, i), Fun::call(&three, i));
+        assert_eq!(Fun::call(&one, i), Fun::call(&four, i));
+    }
+
+    assert_eq!(4, one.alloc_stats().num_calls);
+    assert_eq!(2, three.alloc_stats().num_calls);
+    for tracked in [one.alloc_stats(), three.alloc_stats(), four.alloc_stats()] {
+        assert_eq!(0, tracked.num_allocations);
+    }
+    four.assert_no_allocations();
+}
+
+#[test]
+fn alloc_tracked_ref_unions() {
+    let name: ClosureRefOneOf2<Names, (), usize, str> = Capture(names())
+        .fun_ref(|n, i: usize| n[i].as_str())
+        .into_oneof2_var1();
+    let get: ClosureOptRefOneOf3<(), Names, char, usize, str> = Capture(names())
+        .fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()))
+        .into_oneof3_var2();
+    let try_get: ClosureResRefOneOf2<(), Names, usize, str, &'static str> = Capture(names())
+        .fun_result_ref(|n, i: usize| n.get(i).map(|x| x.as_str()).ok_or("no name"))
+        .into_oneof2_var2();
+
+    let name = AllocTracked::new(name, num_allocations);
+    let get = AllocTracked::new(get, num_allocations);
+    let try_get = AllocTracked::new(try_get, num_allocations);
+
+    assert_eq!("doe", FunRef::call(&name, 1));
+    assert_eq!(None, FunOptRef::call(&get, 2));
+    assert_eq!(Err("no name"), FunResRef::call(&try_get, 2));
+
+    name.assert_no_allocations();
+    get.assert_no_allocations();
+    try_get.assert_no_allocations();
+}
+
+#[test]
+fn alloc_tracked_mut_union() {
+    let counts: ClosureMutOneOf2<Vec<usize>, (), usize, usize> = Capture(vec![0; 4])
+        .fun_mut(|c, i: usize| {
+            c[i] += 1;
+            c[i]
+        })
+        .into_oneof2_var1();
+    let mut counts = AllocTracked::new(counts, num_allocations);
+
+    FunMut::call_mut(&mut counts, 1);
+    assert_eq!(2, FunMut::call_mut(&mut counts, 1));
+    counts.assert_no_allocations();
+
+    let history = Capture(Vec::new()).fun_mut(|h, x: i32| {
+        h.push(x);
+        h.len()
+    });
+    let mut history = AllocTracked::new(history, num_allocations);
+    FunMut::call_mut(&mut history, 1);
+    assert!(history.alloc_stats().num_allocations > 0);
+}
+
+#[test]
+fn alloc_tracked_detects_allocating_closure() {
+    let label = Capture(names()).fun(|n, i: usize| format!("#{}", n[i]));
+    let label = AllocTracked::new(label, num_allocations);
+
+    assert_eq!("#john", Fun::call(&label, 0));
+    assert_eq!("#doe", Fun::call(&label, 1));
+
+    let stats = label.alloc_stats();
+    assert_eq!(2, stats.num_calls);
+    assert!(stats.num_allocations >= 2);
+    assert!(stats.allocations_per_call() >= 1.0);
+
+    label.reset();
+    assert_eq!(AllocStats::default(), label.alloc_stats());
+    assert_eq!(0.0, label.alloc_stats().allocations_per_call());
+    assert_eq!(2, label.into_inner().captured_data().len());
+}
+
+#[test]
+#[should_panic]
+fn alloc_tracked_assert_no_allocations_panics() {
+    let label = Capture(()).fun(|_, i: usize| i.to_string());
+    let label = AllocTracked::new(label, num_allocations);
+
+    Fun::call(&label, 42);
+    label.assert_no_allocations();
+}
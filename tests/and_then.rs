@@ -0,0 +1,53 @@
+use orx_closure::*;
+use std::{cell::Cell, collections::HashMap};
+
+type Parent = HashMap<&'static str, &'static str>;
+
+fn parent() -> Closure<Parent, &'static str, Option<&'static str>> {
+    let parents = HashMap::from([("c", "b"), ("b", "a")]);
+    Capture(parents).fun(|p, child: &'static str| p.get(child).copied())
+}
+
+#[test]
+fn and_then_short_circuits() {
+    let grandparent = parent().and_then(parent());
+    assert_eq!(Some("a"), grandparent.call("c"));
+    assert_eq!(None, grandparent.call("b"));
+    assert_eq!(None, grandparent.call("a"));
+
+    let counted = Capture(Cell::new(0)).fun(|calls, x: &'static str| {
+        calls.set(calls.get() + 1);
+        Some(x.len())
+    });
+    let len_of_parent = parent().and_then(counted);
+    assert_eq!(Some(1), len_of_parent.call("c"));
+    assert_eq!(None, len_of_parent.call("a"));
+    assert_eq!(None, len_of_parent.call("x"));
+
+    let (_, counted) = len_of_parent.into_captured_data();
+    assert_eq!(1, counted.captured_data().get());
+}
+
+#[test]
+fn and_then_changes_types() {
+    let index =
+        Capture(vec!["x", "y"]).fun(|names, name: &str| names.iter().position(|n| *n == name));
+    let score = Capture(vec![1.5]).fun(|scores, i: usize| scores.get(i).copied());
+
+    let score_of = index.and_then(score);
+    assert_eq!(Some(1.5), score_of.call("x"));
+    assert_eq!(None, score_of.call("y"));
+    assert_eq!(None, score_of.call("z"));
+}
+
+#[test]
+fn and_then_chains_and_keeps_captures() {
+    let great_grandparent = parent().and_then(parent()).and_then(parent());
+    assert_eq!(None, great_grandparent.call("c"));
+
+    let cloned = great_grandparent.clone();
+    let (first_two, third) = cloned.into_captured_data();
+    let (first, second) = first_two.into_captured_data();
+    assert_eq!(2, first.into_captured_data().len());
+    assert_eq!(second.captured_data(), third.captured_data());
+}
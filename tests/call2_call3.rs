@@ -0,0 +1,32 @@
+use orx_closure::*;
+
+struct Provider {
+    weights: Closure<Vec<Vec<i32>>, (usize, usize), i32>,
+}
+
+#[test]
+fn call2_packs_the_pair() {
+    let provider = Provider {
+        weights: Capture(vec![vec![0, 4], vec![2, 0]]).fun(|w, (i, j)| w[i][j]),
+    };
+
+    for i in 0..2 {
+        for j in 0..2 {
+            assert_eq!(provider.weights.call((i, j)), provider.weights.call2(i, j));
+        }
+    }
+
+    let names = vec!["john", "doe"];
+    let describe =
+        Capture(&names).fun(|n, (i, suffix): (usize, &str)| format!("{}{}", n[i], suffix));
+    assert_eq!("doe!", describe.call2(1, "!"));
+}
+
+#[test]
+fn call3_packs_the_triple() {
+    let cost = Capture(2u32)
+        .fun(|factor, (from, to, time): (u32, u32, u32)| factor * from.abs_diff(to) + time);
+
+    assert_eq!(8, cost.call3(1, 4, 2));
+    assert_eq!(cost.call((4, 1, 2)), cost.call3(4, 1, 2));
+}
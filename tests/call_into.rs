@@ -0,0 +1,72 @@
+use orx_closure::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct NodeId(usize);
+
+impl From<NodeId> for usize {
+    fn from(id: NodeId) -> usize {
+        id.0
+    }
+}
+
+fn names() -> Vec<String> {
+    vec![String::from("a"), String::from("b"), String::from("c")]
+}
+
+#[test]
+fn call_into_value_and_reference_closures() {
+    let len = Capture(names()).fun(|n, i: usize| n[i].len());
+    assert_eq!(1, len.call_into(NodeId(1)));
+
+    let name = Capture(names()).fun_ref(|n, i: usize| n[i].as_str());
+    assert_eq!("c", name.call_into(NodeId(2)));
+
+    let name = Capture(names()).fun_option_ref(|n, i: usize| n.get(i));
+    assert_eq!(None, name.call_into(NodeId(3)));
+
+    let name = Capture(names()).fun_result_ref(|n, i: usize| n.get(i).ok_or(i));
+    assert_eq!(Err(7), name.call_into(NodeId(7)));
+    assert_eq!(Ok(&names()[0]), name.try_call_into(NodeId(0)));
+}
+
+#[test]
+fn call_into_unions() {
+    let name: ClosureRefOneOf2<Vec<String>, String, usize, str> = Capture(String::from("xyz"))
+        .fun_ref(|s, i: usize| &s[i..])
+        .into_oneof2_var2();
+    assert_eq!("yz", name.call_into(NodeId(1)));
+
+    let len: ClosureOneOf3<Vec<String>, (), u8, usize, usize> = Capture(names())
+        .fun(|n, i: usize| n[i].len())
+        .into_oneof3_var1();
+    let ids = [NodeId(0), NodeId(2)];
+    assert_eq!(2, ids.iter().map(|id| len.call_into(*id)).sum::<usize>());
+}
+
+#[test]
+fn call_into_mutable_and_consuming_closures() {
+    let mut visits = Capture(vec![0; 3]).fun_mut(|v, i: usize| {
+        v[i] += 1;
+        v[i]
+    });
+    visits.call_mut_into(NodeId(1));
+    assert_eq!(2, visits.call_mut_into(NodeId(1)));
+
+    let mut slot = Capture(names()).fun_ref_mut(|n, i: usize| &mut n[i]);
+    slot.call_mut_into(NodeId(0)).push('!');
+    assert_eq!("a!", slot.into_captured_data()[0]);
+
+    let take = Capture(names()).fun_once(|mut n, i: usize| n.swap_remove(i));
+    assert_eq!("b", take.call_once_into(NodeId(1)));
+}
+
+#[test]
+fn call_into_generic_code() {
+    fn total<F: Fun<usize, u32>>(fun: &F, ids: &[NodeId]) -> u32 {
+        ids.iter().map(|id| fun.call_into(*id)).sum()
+    }
+
+    let weights = Capture(vec![4, 5, 6]).fun(|w, i: usize| w[i]);
+    assert_eq!(10, total(&weights, &[NodeId(0), NodeId(2)]));
+    assert_eq!(5, total(&|i: usize| i as u32, &[NodeId(2), NodeId(3)]));
+}
@@ -0,0 +1,55 @@
+use orx_closure::*;
+
+type Flat = (usize, Vec<i32>);
+
+#[test]
+fn call_outlined_matches_call() {
+    let fun = Capture(vec![3, 1, 4]).fun(|v, i: usize| v[i] * 2);
+    for i in 0..3 {
+        assert_eq!(fun.call(i), fun.call_outlined(i));
+    }
+
+    let fun =
+        Capture(vec![String::from("a"), String::from("b")]).fun_ref(|v, i: usize| v[i].as_str());
+    assert_eq!(fun.call(0), fun.call_outlined(0));
+
+    let fun = Capture(vec![3, 1, 4]).fun_option_ref(|v, i: usize| v.get(i));
+    assert_eq!(fun.call(5), fun.call_outlined(5));
+
+    let fun = Capture(vec![3, 1, 4]).fun_result_ref(|v, i: usize| v.get(i).ok_or(i));
+    assert_eq!(fun.call(2), fun.call_outlined(2));
+    assert_eq!(Err(7), fun.call_outlined(7));
+}
+
+#[test]
+fn call_outlined_on_unions() {
+    let weights: ClosureOneOf2<Vec<Vec<i32>>, Flat, (usize, usize), i32> =
+        Capture((2, vec![1, 2, 3, 4]))
+            .fun(|(n, w), (i, j): (usize, usize)| w[i * n + j])
+            .into_oneof2_var2();
+    assert_eq!(3, weights.call_outlined((1, 0)));
+
+    let weights: ClosureOneOf3<Vec<Vec<i32>>, Flat, (), (usize, usize), i32> =
+        Capture(vec![vec![1, 2], vec![3, 4]])
+            .fun(|w, (i, j): (usize, usize)| w[i][j])
+            .into_oneof3_var1();
+    assert_eq!(4, weights.call_outlined((1, 1)));
+
+    let name: ClosureRefOneOf4<Vec<String>, (), bool, char, usize, str> =
+        Capture(vec![String::from("john")])
+            .fun_ref(|v, i: usize| v[i].as_str())
+            .into_oneof4_var1();
+    assert_eq!("john", name.call_outlined(0));
+
+    let at: ClosureOptRefOneOf3<(), Vec<i32>, bool, usize, i32> = Capture(vec![1, 2])
+        .fun_option_ref(|v, i: usize| v.get(i))
+        .into_oneof3_var2();
+    assert_eq!(Some(&2), at.call_outlined(1));
+    assert_eq!(None, at.call_outlined(2));
+
+    let at: ClosureResRefOneOf2<(), Vec<i32>, usize, i32, usize> = Capture(vec![1, 2])
+        .fun_result_ref(|v, i: usize| v.get(i).ok_or(i))
+        .into_oneof2_var2();
+    assert_eq!(Ok(&1), at.call_outlined(0));
+    assert_eq!(Err(5), at.call_outlined(5));
+}
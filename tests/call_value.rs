@@ -0,0 +1,139 @@
+use orx_closure::*;
+
+type Dense = Vec<Vec<u64>>;
+type Sparse = Vec<(usize, usize, u64)>;
+type Weights = OneOf2<Dense, Sparse>;
+
+fn weight_of(weights: Weights) -> ClosureCallValue<Weights, (usize, usize), u64> {
+    Capture(weights).fun_call_value(|w, (i, j): (usize, usize)| match w {
+        OneOf2::Variant1(dense) => CallValue::Borrowed(&dense[i][j]),
+        OneOf2::Variant2(sparse) => CallValue::Owned(
+            sparse
+                .iter()
+                .find(|x| x.0 == i && x.1 == j)
+                .map(|x| x.2)
+                .unwrap_or(0),
+        ),
+    })
+}
+
+#[test]
+fn call_value_borrowed_and_owned() {
+    let stored = String::from("abc");
+    let borrowed = CallValue::Borrowed(&stored);
+    let owned = CallValue::Owned(String::from("abc"));
+
+    assert!(borrowed.is_borrowed());
+    assert!(!borrowed.is_owned());
+    assert!(owned.is_owned());
+    assert_eq!(borrowed, owned);
+    assert_eq!(3, borrowed.len());
+    assert_eq!("abc", owned.as_ref());
+
+    assert_eq!(stored, borrowed.into_owned());
+    assert_eq!(stored, owned.into_owned());
+}
+
+#[test]
+fn closure_call_value_union_of_captures() {
+    let dense = weight_of(OneOf2::Variant1(vec![vec![0, 4], vec![2, 0]]));
+    let sparse = weight_of(OneOf2::Variant2(vec![(0, 1, 4), (1, 0, 2)]));
+
+    for (i, j) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        assert_eq!(*dense.call((i, j)), *sparse.call((i, j)));
+    }
+
+    assert!(dense.call((0, 1)).is_borrowed());
+    assert!(sparse.call((0, 1)).is_owned());
+
+    let total: u64 = [(0, 1), (1, 0)]
+        .into_iter()
+        .map(dense.as_fn())
+        .map(|x| *x)
+        .sum();
+    assert_eq!(6, total);
+}
+
+#[test]
+fn closure_call_value_captured_data() {
+    let weights = weight_of(OneOf2::Variant2(vec![(0, 1, 4)]));
+    assert!(matches!(weights.captured_data(), OneOf2::Variant2(x) if x.len() == 1));
+
+    let clone = weights.clone();
+    assert_eq!(4, *clone.call((0, 1)));
+
+    match weights.into_captured_data() {
+        OneOf2::Variant2(sparse) => assert_eq!(vec![(0, 1, 4)], sparse),
+        OneOf2::Variant1(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn into_call_value() {
+    let names = vec![String::from("john"), String::from("doe")];
+
+    let name_of = Capture(names.clone())
+        .fun_ref(|n, i: usize| &n[i])
+        .into_call_value();
+    assert!(name_of.call(1).is_borrowed());
+    assert_eq!("doe", name_of.call(1).as_str());
+
+    let upper_of = Capture(names)
+        .fun(|n, i: usize| n[i].to_uppercase())
+        .into_call_value();
+    assert!(upper_of.call(0).is_owned());
+    assert_eq!("JOHN", upper_of.call(0).as_str());
+}
+
+type Labels = Vec<Vec<String>>;
+type Label = ClosureCallValue<
+    OneOf2<ClosureRef<Labels, (usize, usize), String>, Closure<u64, (usize, usize), String>>,
+    (usize, usize),
+    String,
+>;
+
+fn dense_or_computed(dense: Option<Labels>) -> Label {
+    match dense {
+        Some(dense) => {
+            OneOf2::Variant1(Capture(dense).fun_ref(|d, (i, j): (usize, usize)| &d[i][j])).into()
+        }
+        None => OneOf2::Variant2(
+            Capture(10).fun(|n, (i, j): (usize, usize)| (i as u64 * n + j as u64).to_string()),
+        )
+        .into(),
+    }
+}
+
+#[test]
+fn from_ref_or_val_closures() {
+    let labels: Vec<Label> = vec![
+        dense_or_computed(Some(vec![vec!["a".to_string(), "b".to_string()]])),
+        dense_or_computed(None),
+    ];
+
+    assert!(labels[0].call((0, 1)).is_borrowed());
+    assert_eq!("b", labels[0].call((0, 1)).as_str());
+
+    assert!(labels[1].call((2, 3)).is_owned());
+    assert_eq!("23", labels[1].call((2, 3)).as_str());
+
+    let lengths: Vec<usize> = labels.iter().map(|l| l.call((0, 1)).len()).collect();
+    assert_eq!(vec![1, 1], lengths);
+}
+
+#[test]
+fn union_into_call_value() {
+    let dense: ClosureRefOneOf3<Labels, String, (), usize, String> = Capture(String::from("x"))
+        .fun_ref(|s, _: usize| s)
+        .into_oneof3_var2();
+    let dense = dense.into_call_value();
+    assert!(dense.call(7).is_borrowed());
+    assert_eq!("x", dense.call(7).as_str());
+
+    let computed: ClosureOneOf4<(), u8, char, bool, usize, String> = Capture('z')
+        .fun(|c, i: usize| format!("{}{}", c, i))
+        .into_oneof4_var3();
+    let computed = computed.into_call_value();
+    assert!(computed.call(7).is_owned());
+    assert_eq!("z7", computed.call(7).as_str());
+}
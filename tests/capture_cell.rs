@@ -0,0 +1,96 @@
+use orx_closure::*;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+#[test]
+fn cell_capture() {
+    let next_id = Capture::cell(10u32).fun(|id, _: ()| {
+        let current = id.get();
+        id.set(current + 1);
+        current
+    });
+
+    assert_eq!(10, next_id.call(()));
+    assert_eq!(11, next_id.call(()));
+    assert_eq!(&Cell::new(12), next_id.captured_data());
+}
+
+#[test]
+fn refcell_capture_with_existing_builders() {
+    let cache = Capture::refcell(HashMap::<u64, u64>::new()).fun(|cache, n: u64| {
+        if let Some(x) = cache.borrow().get(&n) {
+            return *x;
+        }
+        let value = n * n;
+        cache.borrow_mut().insert(n, value);
+        value
+    });
+
+    assert_eq!(9, cache.call(3));
+    assert_eq!(9, cache.call(3));
+    assert_eq!(1, cache.captured_data().borrow().len());
+}
+
+#[test]
+fn fun_try_mut_owned_refcell() {
+    let push = Capture::refcell(vec![]).fun_try_mut(|v: &mut Vec<char>, c: char| {
+        v.push(c);
+        v.len()
+    });
+
+    assert_eq!(1, push.call('a').unwrap());
+    assert_eq!(2, push.call('b').unwrap());
+
+    let (data, _) = push.into_captured_data();
+    assert_eq!(vec!['a', 'b'], data.into_inner());
+}
+
+#[test]
+fn fun_try_mut_shared_returns_error_instead_of_panic() {
+    let shared = Rc::new(RefCell::new(0));
+
+    let increment = Capture(Rc::clone(&shared)).fun_try_mut(|x: &mut i32, by: i32| {
+        *x += by;
+        *x
+    });
+    let read = Capture(Rc::clone(&shared)).fun_try_ref(|x: &i32, _: ()| *x);
+
+    assert_eq!(3, increment.call(3).unwrap());
+    assert_eq!(3, read.call(()).unwrap());
+
+    {
+        let _reader = shared.borrow();
+        assert!(increment.call(1).is_err());
+        assert_eq!(3, read.call(()).unwrap());
+    }
+
+    {
+        let _writer = shared.borrow_mut();
+        assert!(increment.call(1).is_err());
+        assert!(read.call(()).is_err());
+    }
+
+    assert_eq!(4, increment.call(1).unwrap());
+    assert_eq!(4, *shared.borrow());
+}
+
+#[test]
+fn fun_try_ref_error_message() {
+    let shared = Rc::new(RefCell::new(String::from("x")));
+    let len = Capture(Rc::clone(&shared)).fun_try_ref(|s: &String, _: ()| s.len());
+
+    let _writer = shared.borrow_mut();
+    let error = len.call(()).unwrap_err();
+    assert!(!error.to_string().is_empty());
+}
+
+#[test]
+fn fun_try_ref_through_borrowed_refcell() {
+    let shared = RefCell::new(vec![1, 2, 3]);
+    let sum =
+        Capture(shared).fun_try_ref(|v: &Vec<i32>, k: i32| v.iter().map(|x| x * k).sum::<i32>());
+    assert_eq!(12, sum.call(2).unwrap());
+}
@@ -0,0 +1,58 @@
+use orx_closure::*;
+use std::sync::Arc;
+
+type Matrix = Vec<Vec<i32>>;
+
+fn matrix() -> Matrix {
+    vec![vec![1, 2], vec![3, 4]]
+}
+
+#[test]
+fn hub_closures_share_the_data() {
+    let hub = CaptureHub::new(matrix());
+
+    let at = hub.fun(|m, (i, j): (usize, usize)| m[i][j]);
+    let row = hub.fun_ref(|m, i: usize| m[i].as_slice());
+    let get = hub.fun_option_ref(|m, (i, j): (usize, usize)| m.get(i).and_then(|r| r.get(j)));
+    let try_get = hub.fun_result_ref(|m, i: usize| m.get(i).ok_or(i));
+
+    assert_eq!(3, at.call((1, 0)));
+    assert_eq!(&[3, 4], row.call(1));
+    assert_eq!(Some(&2), get.call((0, 1)));
+    assert_eq!(None, get.call((2, 0)));
+    assert_eq!(Ok(&vec![1, 2]), try_get.call(0));
+    assert_eq!(Err(5), try_get.call(5));
+
+    assert!(Arc::ptr_eq(at.captured_data(), row.captured_data()));
+    assert!(Arc::ptr_eq(get.captured_data(), try_get.captured_data()));
+    assert_eq!(&matrix(), hub.data());
+}
+
+#[test]
+fn hub_tracks_outstanding_closures() {
+    let hub = CaptureHub::new(matrix());
+    assert_eq!(0, hub.num_closures());
+
+    let at = hub.fun(|m, (i, j): (usize, usize)| m[i][j]);
+    let union: ClosureOneOf2<Arc<Matrix>, (), (usize, usize), i32> = at.clone().into_oneof2_var1();
+    assert_eq!(2, hub.num_closures());
+
+    drop(at);
+    assert_eq!(1, hub.num_closures());
+    assert_eq!(4, union.call((1, 1)));
+
+    let hub = hub.try_unwrap().expect_err("union is alive");
+    drop(union);
+    assert_eq!(0, hub.num_closures());
+    assert_eq!(Ok(matrix()), hub.try_unwrap().map_err(|_| ()));
+}
+
+#[test]
+fn hub_closures_outlive_the_hub() {
+    let at = {
+        let hub = CaptureHub::new(matrix());
+        hub.fun(|m, (i, j): (usize, usize)| m[i][j])
+    };
+    assert_eq!(2, at.call((0, 1)));
+    assert_eq!(1, Arc::strong_count(at.captured_data()));
+}
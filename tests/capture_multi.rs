@@ -0,0 +1,83 @@
+use orx_closure::*;
+
+struct Graph {
+    weights: Vec<Vec<u32>>,
+}
+
+#[test]
+fn capture2_pieces_as_separate_parameters() {
+    let graph = Graph {
+        weights: vec![vec![0, 3], vec![5, 0]],
+    };
+    let penalties = vec![10, 20];
+
+    let cost =
+        Capture2(&graph, penalties).fun(|g, p, (i, j): (usize, usize)| g.weights[i][j] + p[j]);
+    assert_eq!(23, cost.call((0, 1)));
+    assert_eq!(15, cost.call((1, 0)));
+
+    let (_, penalties, _) = cost.into_captured_data();
+    assert_eq!(vec![10, 20], penalties);
+}
+
+#[test]
+fn capture_and_chains_pieces() {
+    let cost = Capture(vec![1, 2])
+        .and(vec![10, 20])
+        .and(100)
+        .fun(|a, b, c, i: usize| a[i] + b[i] + c);
+    assert_eq!(122, cost.call(1));
+
+    let Capture3(a, b, c) = Capture(1).and(2).and(3);
+    assert_eq!((1, 2, 3), (a, b, c));
+    assert_eq!((1, 2), Capture(1).and(2).into_captured_data());
+}
+
+#[test]
+fn capture2_reference_returning_closures() {
+    let names = vec![String::from("john"), String::from("doe")];
+    let nicknames = vec![None, Some(String::from("jd"))];
+
+    let display = Capture2(names.clone(), nicknames.clone())
+        .fun_ref(|names, nicknames, i: usize| nicknames[i].as_deref().unwrap_or(&names[i]));
+    assert_eq!("john", display.call(0));
+    assert_eq!("jd", display.call(1));
+
+    let nickname = Capture2(names.clone(), nicknames.clone())
+        .fun_option_ref(|_, nicknames, i: usize| nicknames.get(i)?.as_deref());
+    assert_eq!(None, nickname.call(0));
+    assert_eq!(Some("jd"), nickname.call(1));
+
+    let name = Capture2(names, nicknames).fun_result_ref(|names, nicknames, i: usize| {
+        match nicknames.get(i) {
+            Some(Some(nick)) => Ok(nick.as_str()),
+            Some(None) => Ok(names[i].as_str()),
+            None => Err(i),
+        }
+    });
+    assert_eq!(Ok("john"), name.call(0));
+    assert_eq!(Err(2), name.call(2));
+}
+
+#[test]
+fn capture3_reference_returning_closures() {
+    let fallback = String::from("?");
+    let name = Capture3(vec!["a", "b"], vec![1, 0], fallback).fun_ref(
+        |names, order, fallback, i: usize| {
+            order.get(i).map(|&j| names[j]).unwrap_or(fallback.as_str())
+        },
+    );
+    assert_eq!("b", name.call(0));
+    assert_eq!("?", name.call(5));
+
+    let at = Capture3(vec![1, 2], vec![3, 4], 0usize)
+        .fun_option_ref(|a, b, shift, i: usize| a.get(i + shift).or(b.get(i)));
+    assert_eq!(Some(&2), at.call(1));
+    assert_eq!(None, at.call(2));
+
+    let at = Capture3(vec![1, 2], vec![3, 4], "out of bounds")
+        .fun_result_ref(|a, b, err, i: usize| a.get(i).or_else(|| b.get(i - a.len())).ok_or(*err));
+    assert_eq!(Ok(&2), at.call(1));
+    assert_eq!(Ok(&4), at.call(3));
+    assert_eq!(Err("out of bounds"), at.call(4));
+}
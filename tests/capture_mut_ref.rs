@@ -0,0 +1,86 @@
+use orx_closure::*;
+
+struct Inventory {
+    stock: Vec<u32>,
+}
+
+#[test]
+fn capture_mut_ref_fun_mut() {
+    let mut inventory = Inventory {
+        stock: vec![3, 0, 1],
+    };
+    {
+        let mut take = Capture(&mut inventory).fun_mut(|inv, i: usize| match inv.stock[i] {
+            0 => false,
+            _ => {
+                inv.stock[i] -= 1;
+                true
+            }
+        });
+        assert!(take.call_mut(0));
+        assert!(!take.call_mut(1));
+        assert!(take.call_mut(2));
+        assert!(!take.call_mut(2));
+        assert_eq!(vec![2, 0, 0], take.captured_data().stock);
+    }
+    assert_eq!(vec![2, 0, 0], inventory.stock);
+}
+
+#[test]
+fn capture_mut_ref_returns_borrow() {
+    let mut inventory = Inventory {
+        stock: vec![3, 0, 1],
+    };
+    let mut restock =
+        Capture(&mut inventory).fun_mut(|inv, (i, n): (usize, u32)| inv.stock[i] += n);
+    restock.call_mut((1, 10));
+
+    let inventory: &mut Inventory = restock.into_captured_data();
+    inventory.stock.push(7);
+    assert_eq!(vec![3, 10, 1, 7], inventory.stock);
+}
+
+#[test]
+fn capture_mut_ref_ref_mut_variants() {
+    let mut stock = vec![3, 0, 1];
+
+    let mut by_ref = Capture(&mut stock).fun_ref_mut(|s, i: usize| &mut s[i]);
+    *by_ref.call_mut(0) = 9;
+
+    let mut by_opt =
+        Capture(by_ref.into_captured_data()).fun_option_ref_mut(|s, i: usize| s.get_mut(i));
+    if let Some(x) = by_opt.call_mut(1) {
+        *x = 8;
+    }
+    assert!(by_opt.call_mut(5).is_none());
+
+    let mut by_res = Capture(by_opt.into_captured_data())
+        .fun_result_ref_mut(|s, i: usize| s.get_mut(i).ok_or(i));
+    by_res.call_mut(2).map(|x| *x = 7).unwrap_or_default();
+    assert_eq!(Err(5), by_res.call_mut(5).map(|_| ()));
+
+    assert_eq!(vec![9, 8, 7], stock);
+}
+
+#[test]
+fn capture_mut_ref_through_fun_traits() {
+    fn drain<F: FunMut<(), Option<u32>>>(fun: &mut F) -> Vec<u32> {
+        std::iter::from_fn(|| fun.call_mut(())).collect()
+    }
+
+    let mut queue = vec![1, 2, 3];
+    let mut pop = Capture(&mut queue).fun_mut(|q, _: ()| q.pop());
+    assert_eq!(vec![3, 2, 1], drain(&mut pop));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn capture_mut_ref_read_only_closure() {
+    let mut stock = vec![3, 0, 1];
+    let total = {
+        let sum = Capture(&mut stock).fun(|s, _: ()| s.iter().sum::<u32>());
+        sum.call(())
+    };
+    stock.push(total);
+    assert_eq!(vec![3, 0, 1, 4], stock);
+}
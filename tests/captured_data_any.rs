@@ -0,0 +1,52 @@
+use orx_closure::*;
+use std::{any::Any, collections::HashMap};
+
+fn reclaim_vec(data: Box<dyn Any>, pool: &mut Vec<Vec<i32>>) -> bool {
+    match data.downcast::<Vec<i32>>() {
+        Ok(vec) => {
+            pool.push(*vec);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[test]
+fn into_captured_data_any_val() {
+    type Union = ClosureOneOf3<Vec<i32>, HashMap<usize, i32>, (), usize, i32>;
+    let mut pool = vec![];
+
+    let fun: Union = Capture(vec![1, 2, 3]).fun(|x, i| x[i]).into_oneof3_var1();
+    assert!(reclaim_vec(fun.into_captured_data_any(), &mut pool));
+
+    let fun: Union = Capture(HashMap::new())
+        .fun(|x, i| *x.get(&i).unwrap_or(&0))
+        .into_oneof3_var2();
+    assert!(!reclaim_vec(fun.into_captured_data_any(), &mut pool));
+
+    let fun: Union = Capture(()).fun(|_, _| 42).into_oneof3_var3();
+    let data = fun.into_captured_data_any();
+    assert!(data.is::<()>());
+
+    assert_eq!(vec![vec![1, 2, 3]], pool);
+}
+
+#[test]
+fn into_captured_data_any_ref_kinds() {
+    let fun: ClosureRefOneOf2<Vec<i32>, String, usize, i32> =
+        Capture(vec![7]).fun_ref(|x, i| &x[i]).into_oneof2_var1();
+    let data = fun.into_captured_data_any();
+    assert_eq!(Some(&vec![7]), data.downcast_ref::<Vec<i32>>());
+
+    let fun: ClosureOptRefOneOf2<Vec<i32>, String, usize, str> = Capture("x".to_string())
+        .fun_option_ref(|x, _| Some(x.as_str()))
+        .into_oneof2_var2();
+    let data = fun.into_captured_data_any();
+    assert_eq!(Some("x"), data.downcast_ref::<String>().map(|x| x.as_str()));
+
+    let fun: ClosureResRefOneOf4<char, bool, u8, Vec<u8>, usize, u8, String> = Capture(vec![1u8])
+        .fun_result_ref(|x, i| x.get(i).ok_or_else(|| "oob".to_string()))
+        .into_oneof4_var4();
+    let data = fun.into_captured_data_any();
+    assert!(data.is::<Vec<u8>>());
+}
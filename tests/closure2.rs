@@ -0,0 +1,89 @@
+use orx_closure::*;
+
+type Matrix = Vec<Vec<i32>>;
+
+fn matrix() -> Matrix {
+    vec![vec![1, 2, 3], vec![4, 5, 6]]
+}
+
+fn sum_generic<F: Fun<(usize, usize), i32>>(fun: &F, indices: &[(usize, usize)]) -> i32 {
+    indices.iter().map(|&ij| fun.call(ij)).sum()
+}
+
+#[test]
+fn closure2_call() {
+    let at = Capture(matrix()).fun2(|m, i: usize, j: usize| m[i][j]);
+
+    assert_eq!(1, at.call(0, 0));
+    assert_eq!(6, at.call(1, 2));
+    assert_eq!(&matrix(), at.captured_data());
+
+    let m = matrix();
+    let weighted = Capture(&m).fun2(|m, i: usize, w: i32| m[i].iter().sum::<i32>() * w);
+    assert_eq!(30, weighted.call(1, 2));
+}
+
+#[test]
+fn closure2_as_fn() {
+    let at = Capture(matrix()).fun2(|m, i: usize, j: usize| m[i][j]);
+    let as_fn = at.as_fn();
+
+    let diagonal: Vec<_> = (0..2).map(|i| as_fn(i, i)).collect();
+    assert_eq!(vec![1, 5], diagonal);
+}
+
+#[test]
+fn closure2_as_fun_over_tuple() {
+    let at = Capture(matrix()).fun2(|m, i: usize, j: usize| m[i][j]);
+    assert_eq!(9, sum_generic(&at, &[(0, 2), (1, 2)]));
+
+    let clone = at.clone();
+    assert_eq!(matrix(), at.into_captured_data());
+    assert_eq!(4, clone.call(1, 0));
+}
+
+#[test]
+fn closure_ref2() {
+    let names = vec![
+        vec![String::from("john"), String::from("doe")],
+        vec![String::from("jane")],
+    ];
+    let name = Capture(names).fun2_ref(|m, i: usize, j: usize| m[i][j].as_str());
+
+    assert_eq!("doe", name.call(0, 1));
+    assert_eq!("jane", FunRef::call(&name, (1, 0)));
+
+    let as_fn = name.as_fn();
+    assert_eq!("john", as_fn(0, 0));
+}
+
+#[test]
+fn closure_opt_ref2() {
+    let at =
+        Capture(matrix()).fun2_option_ref(|m, i: usize, j: usize| m.get(i).and_then(|r| r.get(j)));
+
+    assert_eq!(Some(&5), at.call(1, 1));
+    assert_eq!(None, at.call(1, 3));
+    assert_eq!(None, at.call(2, 0));
+    assert_eq!(Some(&2), FunOptRef::call(&at, (0, 1)));
+
+    let as_fn = at.as_fn();
+    assert_eq!(Some(&3), as_fn(0, 2));
+}
+
+#[test]
+fn closure_res_ref2() {
+    let at = Capture(matrix()).fun2_result_ref(|m, i: usize, j: usize| {
+        m.get(i)
+            .ok_or(format!("row {} out of bounds", i))
+            .and_then(|r| r.get(j).ok_or(format!("col {} out of bounds", j)))
+    });
+
+    assert_eq!(Ok(&4), at.call(1, 0));
+    assert_eq!(Err(String::from("row 2 out of bounds")), at.call(2, 0));
+    assert_eq!(Err(String::from("col 5 out of bounds")), at.call(0, 5));
+    assert_eq!(Ok(&6), FunResRef::call(&at, (1, 2)));
+
+    let as_fn = at.as_fn();
+    assert_eq!(Ok(&1), as_fn(0, 0));
+}
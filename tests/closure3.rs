@@ -0,0 +1,57 @@
+use orx_closure::*;
+
+type Node = usize;
+type Time = u32;
+
+#[derive(Clone)]
+struct Network {
+    distances: Vec<Vec<u32>>,
+    rush_hours: (Time, Time),
+}
+
+fn network() -> Network {
+    Network {
+        distances: vec![vec![0, 4, 9], vec![4, 0, 3], vec![9, 3, 0]],
+        rush_hours: (8, 10),
+    }
+}
+
+fn total_cost<F: Fun<(Node, Node, Time), u32>>(fun: &F, path: &[Node], start: Time) -> u32 {
+    path.windows(2)
+        .zip(start..)
+        .map(|(w, t)| fun.call((w[0], w[1], t)))
+        .sum()
+}
+
+#[test]
+fn closure3_call() {
+    let cost = Capture(network()).fun3(|n, from: Node, to: Node, time: Time| {
+        let d = n.distances[from][to];
+        match (n.rush_hours.0..n.rush_hours.1).contains(&time) {
+            true => 2 * d,
+            false => d,
+        }
+    });
+
+    assert_eq!(4, cost.call(0, 1, 7));
+    assert_eq!(8, cost.call(0, 1, 8));
+    assert_eq!(3, cost.call(2, 1, 10));
+    assert_eq!((8, 10), cost.captured_data().rush_hours);
+}
+
+#[test]
+fn closure3_as_fn_and_fun() {
+    let cost =
+        Capture(network()).fun3(|n, from: Node, to: Node, time: Time| n.distances[from][to] + time);
+
+    {
+        let as_fn = cost.as_fn();
+        assert_eq!(9, as_fn(0, 1, 5));
+    }
+
+    assert_eq!(4 + 3 + 1, total_cost(&cost, &[0, 1, 2], 0));
+
+    let clone = cost.clone();
+    assert_eq!(vec![0, 4, 9], cost.into_captured_data().distances[0]);
+    assert_eq!(9, clone.call(2, 0, 0));
+}
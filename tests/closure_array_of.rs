@@ -0,0 +1,77 @@
+use orx_closure::*;
+
+type Scores = Vec<(i32, i32)>;
+
+fn scoring() -> ClosureArrayOf<3, Scores, usize, i32> {
+    let scores = vec![(1, 10), (2, 20), (3, 30)];
+    let fun = Capture(scores).fun_array_of(
+        [
+            |s, i: usize| s[i].0,
+            |s, i: usize| s[i].1,
+            |s, i: usize| s[i].0 + s[i].1,
+        ],
+        0,
+    );
+    fun.expect("valid selection")
+}
+
+#[test]
+fn array_of_select() {
+    let mut fun = scoring();
+    assert_eq!(0, fun.selected());
+    assert_eq!(2, fun.call(1));
+
+    assert_eq!(Some(0), fun.select(2));
+    assert_eq!(33, fun.call(2));
+
+    assert_eq!(Some(2), fun.select(1));
+    assert_eq!(20, fun.call(1));
+}
+
+#[test]
+fn array_of_invalid_selection() {
+    let mut fun = scoring();
+    assert_eq!(None, fun.select(3));
+    assert_eq!(0, fun.selected());
+
+    let fun = Capture(()).fun_array_of([|_, x: i32| x], 1);
+    assert!(fun.is_none());
+
+    let fun = Capture(()).fun_array_of::<0, i32, i32>([], 0);
+    assert!(fun.is_none());
+}
+
+#[test]
+fn array_of_as_fun() {
+    fn total<F: Fun<usize, i32>>(fun: &F) -> i32 {
+        (0..3).map(|i| fun.call(i)).sum()
+    }
+
+    let mut fun = scoring();
+    assert_eq!(6, total(&fun));
+    fun.select(1);
+    assert_eq!(60, total(&fun));
+    assert_eq!(
+        vec![10, 20, 30],
+        (0..3).map(fun.as_fn()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn array_of_clone_and_data() {
+    let mut fun = scoring();
+    let clone = fun.clone();
+    fun.select(2);
+    assert_eq!(0, clone.selected());
+    assert_eq!(3, clone.captured_data().len());
+    assert_eq!(clone.into_captured_data(), fun.into_captured_data());
+}
+
+#[test]
+fn array_of_debug() {
+    let fun = Capture(42).fun_array_of([|x, _: ()| *x, |x, _: ()| -x], 1);
+    assert_eq!(
+        Some("ClosureArrayOf { capture: 42, selected: 1 }".to_string()),
+        fun.map(|f| format!("{:?}", f))
+    );
+}
@@ -0,0 +1,89 @@
+use orx_closure::*;
+use std::collections::HashSet;
+
+fn pairs(n: usize) -> Vec<(usize, usize)> {
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|(i, j)| (i * 7 + j * 3) % 4 == 1)
+        .collect()
+}
+
+#[test]
+fn bit_matrix_insert_remove_contains() {
+    let mut matrix = BitMatrix::new(3, 130);
+    assert_eq!((3, 130), matrix.dimensions());
+    assert_eq!(0, matrix.count_ones());
+
+    for j in [0, 63, 64, 127, 128, 129] {
+        assert!(matrix.insert(2, j));
+    }
+    assert!(!matrix.insert(2, 64));
+    assert_eq!(6, matrix.count_ones());
+    assert!((0..130).all(|j| !matrix.contains(1, j)));
+
+    assert!(matrix.remove(2, 64));
+    assert!(!matrix.remove(2, 64));
+    assert!(!matrix.contains(2, 64));
+    assert!(matrix.contains(2, 63));
+    assert!(matrix.contains(2, 129));
+
+    assert!(!matrix.contains(3, 0));
+    assert!(!matrix.contains(0, 130));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn bit_matrix_insert_out_of_bounds() {
+    let mut matrix = BitMatrix::new(2, 2);
+    matrix.insert(0, 2);
+}
+
+#[test]
+fn closure_bit_set_matches_hash_sets() {
+    let n = 100;
+    let pairs = pairs(n);
+    let set: HashSet<_> = pairs.iter().copied().collect();
+
+    let packed = ClosureBitSet::from_pairs(n, pairs);
+    for i in 0..n {
+        for j in 0..n {
+            assert_eq!(set.contains(&(i, j)), packed.call((i, j)));
+        }
+    }
+    assert_eq!(set.len(), packed.captured_data().count_ones());
+}
+
+#[test]
+fn closure_bit_set_empty() {
+    let empty = ClosureBitSet::from_pairs(0, []);
+    assert!(!empty.call((0, 0)));
+    assert_eq!(&BitMatrix::new(0, 0), empty.captured_data());
+}
+
+#[test]
+fn closure_bit_set_into_union() {
+    type Predicate = ClosureOneOf2<BitMatrix, (), (usize, usize), bool>;
+
+    let predicates: Vec<Predicate> = vec![
+        ClosureBitSet::from_pairs(2, [(0, 1)]).into_oneof2_var1(),
+        Capture(())
+            .fun(|_, (i, j): (usize, usize)| i < j)
+            .into_oneof2_var2(),
+    ];
+
+    for predicate in &predicates {
+        assert!(predicate.call((0, 1)));
+        assert!(!predicate.call((1, 0)));
+    }
+}
+
+#[test]
+fn closure_bit_set_update_matrix() {
+    let precedes = ClosureBitSet::from_pairs(3, [(0, 1)]);
+    let mut matrix = precedes.into_captured_data();
+    matrix.insert(1, 2);
+
+    let precedes = ClosureBitSet::from_bit_matrix(matrix);
+    assert!(precedes.call((0, 1)));
+    assert!(precedes.call((1, 2)));
+}
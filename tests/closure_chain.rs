@@ -0,0 +1,53 @@
+use orx_closure::*;
+
+fn sum_over<F: Fun<usize, f64>>(fun: &F, n: usize) -> f64 {
+    (0..n).map(|i| fun.call(i)).sum()
+}
+
+#[test]
+fn chain_calls_stages_in_order() {
+    let prices = vec![10.0, 20.0, 30.0];
+    let price = Capture(prices).fun(|p, i: usize| p[i]);
+    let with_tax = Capture(0.1).fun(|rate, x: f64| x * (1.0 + rate));
+
+    let chain = price.then(with_tax);
+    assert_eq!(22.0, (chain.call(1) * 100.0).round() / 100.0);
+    assert_eq!(66.0, (sum_over(&chain, 3) * 100.0).round() / 100.0);
+
+    let (prices, rate) = chain.captured_data();
+    assert_eq!(3, prices.len());
+    assert_eq!(&0.1, rate);
+}
+
+#[test]
+fn chain_is_cloneable_and_splittable() {
+    let chain = Capture(vec!["a", "bb", "ccc"])
+        .fun(|v, i: usize| v[i])
+        .then(Capture(()).fun(|_, s: &str| s.len()));
+
+    let clone = chain.clone();
+    assert_eq!(3, clone.call(2));
+
+    let (first, second) = chain.into_stages();
+    assert_eq!("bb", first.call(1));
+    assert_eq!(2, second.call("xy"));
+
+    let (words, ()) = clone.into_captured_data();
+    assert_eq!(vec!["a", "bb", "ccc"], words);
+}
+
+#[test]
+fn chain_into_union() {
+    type Len = ClosureOneOf2<
+        ClosureChain<Vec<&'static str>, (), usize, &'static str, usize>,
+        (),
+        usize,
+        usize,
+    >;
+
+    let chain = Capture(vec!["a", "bb"])
+        .fun(|v, i: usize| v[i])
+        .then(Capture(()).fun(|_, s: &str| s.len()));
+    let len: Len = Capture(chain).fun(|c, i| c.call(i)).into_oneof2_var1();
+    assert_eq!(2, len.call(1));
+}
@@ -0,0 +1,71 @@
+use orx_closure::*;
+use std::{rc::Rc, sync::Arc};
+
+type Weights = Vec<Vec<i32>>;
+type Scenario = Rc<Weights>;
+
+struct Scenarios {
+    weights: ClosureFactory<Vec<Scenario>, usize, Scenario, (usize, usize), i32>,
+}
+
+impl Scenarios {
+    fn new(scenarios: Vec<Weights>) -> Self {
+        let scenarios = scenarios.into_iter().map(Rc::new).collect();
+        let weights = Capture(scenarios).fun(|s: &Vec<Scenario>, scenario: usize| {
+            Capture(s[scenario].clone()).fun(|w, (i, j): (usize, usize)| w[i][j])
+        });
+        Self { weights }
+    }
+}
+
+#[test]
+fn factory_as_a_field() {
+    let scenarios = Scenarios::new(vec![
+        vec![vec![0, 1], vec![2, 0]],
+        vec![vec![0, 3], vec![4, 0]],
+    ]);
+
+    let first = scenarios.weights.call(0);
+    let second = scenarios.weights.call(1);
+    assert_eq!(2, first.call((1, 0)));
+    assert_eq!(4, second.call((1, 0)));
+
+    assert_eq!(1, scenarios.weights.flat_map_call(0, (0, 1)));
+    assert_eq!(3, scenarios.weights.flat_map_call(1, (0, 1)));
+}
+
+#[test]
+fn created_closures_outlive_factory() {
+    let scenarios = Scenarios::new(vec![vec![vec![5]]]);
+    let weight = scenarios.weights.call(0);
+    drop(scenarios);
+    assert_eq!(5, weight.call((0, 0)));
+}
+
+#[test]
+fn factory_sharing_across_threads() {
+    let data = Arc::new(vec![1, 2, 3]);
+    let factory = Capture(data)
+        .fun(|d, offset: i32| Capture((d.clone(), offset)).fun(|(d, o), i: usize| d[i] + o));
+
+    let inner = factory.call(10);
+    let handle = std::thread::spawn(move || inner.call(2));
+    assert_eq!(Some(13), handle.join().ok());
+    assert_eq!(12, factory.flat_map_call(10, 1));
+}
+
+#[test]
+fn factory_of_fn_pointers() {
+    fn double(x: i32) -> i32 {
+        2 * x
+    }
+    fn negate(x: i32) -> i32 {
+        -x
+    }
+    let pick = Capture(()).fun(|_, negative: bool| match negative {
+        true => negate as fn(i32) -> i32,
+        false => double,
+    });
+    assert_eq!(8, pick.flat_map_call(false, 4));
+    assert_eq!(-4, pick.flat_map_call(true, 4));
+}
@@ -0,0 +1,56 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Stats = (usize, f64);
+
+fn mean_of_known() -> ClosureFold<HashMap<&'static str, f64>, &'static str, Stats> {
+    let values = HashMap::from_iter([("a", 1.0), ("b", 2.0), ("c", 6.0)]);
+    Capture(values).fun_fold(|v, (n, sum), key| match v.get(key) {
+        Some(x) => (n + 1, sum + x),
+        None => (n, sum),
+    })
+}
+
+#[test]
+fn fold_stream() {
+    let fun = mean_of_known();
+    let (n, sum) = fun.fold((0, 0.0), ["a", "x", "c", "y"]);
+    assert_eq!(2, n);
+    assert_eq!(7.0, sum);
+}
+
+#[test]
+fn fold_empty_stream() {
+    let fun = mean_of_known();
+    assert_eq!((0, 0.0), fun.fold((0, 0.0), []));
+}
+
+#[test]
+fn fold_continues_from_accumulated() {
+    let fun = mean_of_known();
+    let acc = fun.fold((0, 0.0), ["a"]);
+    let acc = fun.fold(acc, ["b"]);
+    assert_eq!((2, 3.0), acc);
+    assert_eq!((3, 9.0), fun.call(acc, "c"));
+}
+
+#[test]
+fn fold_as_fn_with_iterators() {
+    let fun = mean_of_known();
+    let acc = ["b", "c"].into_iter().fold((0, 0.0), fun.as_fn());
+    assert_eq!((2, 8.0), acc);
+}
+
+#[test]
+fn fold_as_a_field() {
+    struct Pipeline {
+        reducer: ClosureFold<i64, i64, i64>,
+    }
+    let pipeline = Pipeline {
+        reducer: Capture(10).fun_fold(|m, acc, x| acc.max(x * m)),
+    };
+    let clone = pipeline.reducer.clone();
+    assert_eq!(50, pipeline.reducer.fold(i64::MIN, vec![2, 5, -3]));
+    assert_eq!(&10, clone.captured_data());
+    assert_eq!(10, clone.into_captured_data());
+}
@@ -0,0 +1,83 @@
+use orx_closure::*;
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Job {
+    id: usize,
+    queue: &'static str,
+}
+
+fn jobs() -> Vec<Job> {
+    vec![
+        Job {
+            id: 0,
+            queue: "low",
+        },
+        Job {
+            id: 1,
+            queue: "high",
+        },
+        Job {
+            id: 2,
+            queue: "mid",
+        },
+        Job {
+            id: 3,
+            queue: "high",
+        },
+    ]
+}
+
+fn priority() -> ClosureKey<HashMap<&'static str, u8>, Job, u8> {
+    let table = HashMap::from_iter([("high", 0), ("mid", 1), ("low", 2)]);
+    Capture(table).fun_key(|t, job: &Job| t.get(job.queue).copied().unwrap_or(u8::MAX))
+}
+
+#[test]
+fn sort_by_closure_is_stable() {
+    let mut jobs = jobs();
+    sort_by_closure(&mut jobs, &priority());
+    let ids: Vec<_> = jobs.iter().map(|j| j.id).collect();
+    assert_eq!(vec![1, 3, 2, 0], ids);
+}
+
+#[test]
+fn as_comparator() {
+    let priority = priority();
+    let compare = priority.as_comparator();
+    let jobs = jobs();
+    assert_eq!(Ordering::Greater, compare(&jobs[0], &jobs[1]));
+    assert_eq!(Ordering::Equal, compare(&jobs[1], &jobs[3]));
+
+    let mut sorted = jobs.clone();
+    sorted.sort_by(priority.as_comparator());
+    assert_eq!(Some(0), sorted.last().map(|j| j.id));
+
+    let first = jobs.iter().min_by(|a, b| compare(a, b));
+    assert_eq!(Some(1), first.map(|j| j.id));
+}
+
+#[test]
+fn key_as_fn_and_fun() {
+    fn keys<'a, F: Fun<&'a Job, u8>>(fun: &F, jobs: &'a [Job]) -> Vec<u8> {
+        jobs.iter().map(|j| fun.call(j)).collect()
+    }
+    let priority = priority();
+    let jobs = jobs();
+    assert_eq!(vec![2, 0, 1, 0], keys(&priority, &jobs));
+
+    let mut sorted = jobs.clone();
+    sorted.sort_by_key(priority.as_fn());
+    assert_eq!(
+        vec![1, 3, 2, 0],
+        sorted.iter().map(|j| j.id).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn key_over_unsized() {
+    let lengths = Capture(1).fun_key(|extra, x: &str| x.len() + extra);
+    assert_eq!(4, lengths.call("abc"));
+    assert_eq!(&1, lengths.captured_data());
+    assert_eq!(1, lengths.into_captured_data());
+}
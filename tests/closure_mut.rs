@@ -0,0 +1,91 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+fn fib_memo() -> ClosureMut<HashMap<u64, u64>, u64, u64> {
+    Capture(HashMap::new()).fun_mut(|memo, n: u64| {
+        let (mut a, mut b) = (0, 1);
+        for i in 0..n {
+            if let Some(x) = memo.get(&(i + 1)) {
+                (a, b) = (*x, a + *x);
+                continue;
+            }
+            (a, b) = (b, a + b);
+            memo.insert(i + 1, a);
+        }
+        a
+    })
+}
+
+fn sum_of<F: FunMut<u64, u64>>(fun: &mut F, inputs: &[u64]) -> u64 {
+    inputs.iter().map(|x| fun.call_mut(*x)).sum()
+}
+
+#[test]
+fn closure_mut_keeps_state() {
+    let mut fib = fib_memo();
+    assert_eq!(55, fib.call_mut(10));
+    assert_eq!(10, fib.captured_data().len());
+    assert_eq!(1 + 1 + 2 + 55, sum_of(&mut fib, &[1, 2, 3, 10]));
+    assert_eq!(10, fib.into_captured_data().len());
+}
+
+#[test]
+fn closure_mut_as_fn_mut_with_iterators() {
+    let mut dedup = Capture(Vec::<char>::new()).fun_mut(|seen, c: char| match seen.contains(&c) {
+        true => None,
+        false => {
+            seen.push(c);
+            Some(c)
+        }
+    });
+
+    let unique: String = "mississippi"
+        .chars()
+        .filter_map(dedup.as_fn_mut())
+        .collect();
+    assert_eq!("misp", unique);
+    assert_eq!(&vec!['m', 'i', 's', 'p'], dedup.captured_data());
+}
+
+#[test]
+fn closure_mut_as_fn_mut_with_sort_by_key() {
+    let mut values = vec![3, 1, 2];
+    let mut calls = Capture(0usize).fun_mut(|count, x: i32| {
+        *count += 1;
+        -x
+    });
+    {
+        let mut key = calls.as_fn_mut();
+        values.sort_by_key(|x| key(*x));
+    }
+
+    assert_eq!(vec![3, 2, 1], values);
+    assert!(*calls.captured_data() >= 2);
+}
+
+#[test]
+fn closure_mut_clone_forks_state() {
+    let mut counter = Capture(0).fun_mut(|c, _: ()| {
+        *c += 1;
+        *c
+    });
+    counter.call_mut(());
+    let mut fork = counter.clone();
+
+    assert_eq!(2, counter.call_mut(()));
+    assert_eq!(2, fork.call_mut(()));
+    assert_eq!(3, fork.call_mut(()));
+}
+
+#[test]
+fn closure_mut_trait_object() {
+    let mut funs: Vec<Box<dyn FunMut<u64, u64>>> = vec![
+        Box::new(fib_memo()),
+        Box::new(Capture(0).fun_mut(|total, x: u64| {
+            *total += x;
+            *total
+        })),
+    ];
+    let results: Vec<_> = funs.iter_mut().map(|f| f.call_mut(5)).collect();
+    assert_eq!(vec![5, 5], results);
+}
@@ -0,0 +1,129 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Word = &'static str;
+
+/// Assigns ids to words; either sequentially, by first-seen order, or by word length.
+pub struct Tokenizer {
+    fun: ClosureMutOneOf3<usize, HashMap<Word, usize>, (), Word, usize>,
+}
+impl Tokenizer {
+    fn id(&mut self, word: Word) -> usize {
+        self.fun.call_mut(word)
+    }
+}
+
+fn sequential() -> Tokenizer {
+    let fun = Capture(0).fun_mut(|next, _: Word| {
+        *next += 1;
+        *next - 1
+    });
+    Tokenizer {
+        fun: fun.into_oneof3_var1(),
+    }
+}
+
+fn vocabulary() -> Tokenizer {
+    let fun = Capture(HashMap::new()).fun_mut(|vocab, word: Word| {
+        let next = vocab.len();
+        *vocab.entry(word).or_insert(next)
+    });
+    Tokenizer {
+        fun: fun.into_oneof3_var2(),
+    }
+}
+
+fn by_length() -> Tokenizer {
+    Tokenizer {
+        fun: Capture(())
+            .fun_mut(|_, word: Word| word.len())
+            .into_oneof3_var3(),
+    }
+}
+
+const WORDS: [Word; 5] = ["a", "rose", "is", "a", "rose"];
+
+fn ids(tokenizer: &mut Tokenizer) -> Vec<usize> {
+    WORDS.iter().map(|w| tokenizer.id(w)).collect()
+}
+
+fn ids_as_fn_mut<F: FnMut(Word) -> usize>(fun: F) -> Vec<usize> {
+    WORDS.into_iter().map(fun).collect()
+}
+
+fn ids_as_fun_mut<F: FunMut<Word, usize>>(fun: &mut F) -> Vec<usize> {
+    WORDS.into_iter().map(|w| fun.call_mut(w)).collect()
+}
+
+#[test]
+fn sequential_ids() {
+    let mut tokenizer = sequential();
+    assert_eq!(vec![0, 1, 2, 3, 4], ids(&mut tokenizer));
+    assert_eq!(
+        vec![5, 6, 7, 8, 9],
+        ids_as_fn_mut(tokenizer.fun.as_fn_mut())
+    );
+    assert_eq!(vec![10, 11, 12, 13, 14], ids_as_fun_mut(&mut tokenizer.fun));
+    assert!(matches!(
+        tokenizer.fun.captured_data(),
+        OneOf3::Variant1(15)
+    ));
+}
+
+#[test]
+fn vocabulary_ids() {
+    let mut tokenizer = vocabulary();
+    assert_eq!(vec![0, 1, 2, 0, 1], ids(&mut tokenizer));
+    assert_eq!(
+        vec![0, 1, 2, 0, 1],
+        ids_as_fn_mut(tokenizer.fun.as_fn_mut())
+    );
+
+    let vocab = tokenizer.fun.into_captured_data();
+    assert!(matches!(vocab, OneOf3::Variant2(v) if v.len() == 3 && v["is"] == 2));
+}
+
+#[test]
+fn length_ids() {
+    let mut tokenizer = by_length();
+    assert_eq!(vec![1, 4, 2, 1, 4], ids(&mut tokenizer));
+    assert_eq!(vec![1, 4, 2, 1, 4], ids_as_fun_mut(&mut tokenizer.fun));
+}
+
+#[test]
+fn clone_forks_state() {
+    let mut tokenizer = sequential();
+    tokenizer.id("x");
+    let mut fork = tokenizer.fun.clone();
+    assert_eq!(1, tokenizer.id("y"));
+    assert_eq!(1, fork.call_mut("y"));
+}
+
+#[test]
+fn mut_oneof2_and_oneof4() {
+    let mut sum: ClosureMutOneOf2<i32, Vec<i32>, i32, i32> = Capture(0)
+        .fun_mut(|s, x: i32| {
+            *s += x;
+            *s
+        })
+        .into_oneof2_var1();
+    let mut max: ClosureMutOneOf4<(), char, Option<i32>, bool, i32, i32> = Capture(None)
+        .fun_mut(|m: &mut Option<i32>, x: i32| *m.insert(m.map(|m| m.max(x)).unwrap_or(x)))
+        .into_oneof4_var3();
+
+    let sums: Vec<_> = [3, 1, 4].into_iter().map(sum.as_fn_mut()).collect();
+    let maxs: Vec<_> = [3, 1, 4].into_iter().map(max.as_fn_mut()).collect();
+    assert_eq!(vec![3, 4, 8], sums);
+    assert_eq!(vec![3, 3, 4], maxs);
+}
+
+#[test]
+fn mut_oneof_macro() {
+    let counter = Capture(0).fun_mut(|c, _: ()| {
+        *c += 1;
+        *c
+    });
+    let mut union: ClosureMutOneOf2<(), i32, (), i32> = one_of!(var2: counter);
+    assert_eq!(1, union.call_mut(()));
+    assert_eq!(2, union.call_mut(()));
+}
@@ -0,0 +1,116 @@
+use orx_closure::*;
+
+struct Resource {
+    name: String,
+    released: bool,
+}
+
+fn teardown(name: &str) -> ClosureOnce<Resource, bool, Resource> {
+    Capture(Resource {
+        name: name.to_string(),
+        released: false,
+    })
+    .fun_once(|mut resource, release: bool| {
+        resource.released = release;
+        resource
+    })
+}
+
+#[test]
+fn call_once_moves_capture() {
+    let resource = teardown("db").call_once(true);
+    assert_eq!("db", resource.name);
+    assert!(resource.released);
+}
+
+#[test]
+fn into_captured_data_without_call() {
+    let closure = teardown("cache");
+    assert_eq!("cache", closure.captured_data().name);
+
+    let resource = closure.into_captured_data();
+    assert!(!resource.released);
+}
+
+#[test]
+fn clone_when_capture_is_clone() {
+    let join = Capture(vec![String::from("a"), String::from("b")])
+        .fun_once(|v, sep: char| v.join(&sep.to_string()));
+    let other = join.clone();
+
+    assert_eq!("a,b", join.call_once(','));
+    assert_eq!("a;b", other.call_once(';'));
+}
+
+#[test]
+fn into_fn_once() {
+    let closure = Capture(String::from("x")).fun_once(|s, n: usize| s.repeat(n));
+    let repeated = Some(3).map(closure.into_fn_once());
+    assert_eq!(Some(String::from("xxx")), repeated);
+}
+
+#[test]
+fn fun_once_trait() {
+    fn run_all<F: FunOnce<usize, usize>>(callbacks: Vec<F>) -> Vec<usize> {
+        callbacks.into_iter().map(|f| f.call_once(1)).collect()
+    }
+
+    let callbacks = vec![
+        Capture(vec![1, 2]).fun_once(|v, x: usize| v.len() + x),
+        Capture(vec![1, 2, 3]).fun_once(|v, x: usize| v.into_iter().sum::<usize>() * x),
+    ];
+    assert_eq!(vec![3, 6], run_all(callbacks));
+
+    let owned = String::from("four");
+    assert_eq!(vec![5], run_all(vec![move |x| owned.len() + x]));
+
+    let boxed: Vec<Box<dyn FnOnce(usize) -> usize>> = vec![Box::new(|x| x + 41)];
+    assert_eq!(vec![42], run_all(boxed));
+}
+
+#[test]
+fn fun_once_result() {
+    let open = |name: &str| {
+        Capture(name.to_string()).fun_once_result(|name, read_only: bool| match name.is_empty() {
+            true => Err("empty name"),
+            false => Ok(Resource {
+                name,
+                released: read_only,
+            }),
+        })
+    };
+
+    let resource = open("db").call_once(true);
+    assert!(matches!(resource, Ok(r) if r.name == "db" && r.released));
+
+    assert!(matches!(open("").call_once(false), Err("empty name")));
+
+    let closure = open("cache");
+    assert_eq!("cache", closure.into_captured_data());
+}
+
+#[test]
+fn into_reusable() {
+    let repeat = Capture(String::from("ab"))
+        .fun_once(|mut s, n: usize| {
+            s = s.repeat(n);
+            s
+        })
+        .into_reusable();
+
+    assert_eq!("abab", repeat.call(2));
+    assert_eq!("ab", repeat.call(1));
+
+    let (capture, _) = repeat.into_captured_data();
+    assert_eq!("ab", capture);
+
+    let lengths: Vec<_> = (0..3)
+        .map(
+            Capture(vec![1, 2])
+                .fun_once(|v, x: usize| v.len() + x)
+                .into_reusable()
+                .into_fn(),
+        )
+        .collect();
+    assert_eq!(vec![2, 3, 4], lengths);
+}
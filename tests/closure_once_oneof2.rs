@@ -0,0 +1,90 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Teardown = ClosureOnceOneOf2<Vec<String>, HashMap<String, usize>, bool, Vec<String>>;
+
+struct Job {
+    teardown: Teardown,
+}
+
+fn with_log(lines: &[&str]) -> Job {
+    let lines: Vec<_> = lines.iter().map(|x| x.to_string()).collect();
+    Job {
+        teardown: Capture(lines)
+            .fun_once(|mut lines, verbose: bool| {
+                if verbose {
+                    lines.push(String::from("done"));
+                }
+                lines
+            })
+            .into_oneof2_var1(),
+    }
+}
+
+fn with_counts(counts: &[(&str, usize)]) -> Job {
+    let counts: HashMap<_, _> = counts.iter().map(|(k, v)| (k.to_string(), *v)).collect();
+    Job {
+        teardown: Capture(counts)
+            .fun_once(|counts, verbose: bool| {
+                let mut lines: Vec<_> = counts
+                    .into_iter()
+                    .filter(|(_, v)| verbose || *v > 0)
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect();
+                lines.sort();
+                lines
+            })
+            .into_oneof2_var2(),
+    }
+}
+
+#[test]
+fn call_once_each_variant() {
+    assert_eq!(vec!["a", "done"], with_log(&["a"]).teardown.call_once(true));
+    assert_eq!(vec!["a"], with_log(&["a"]).teardown.call_once(false));
+
+    let job = with_counts(&[("x", 0), ("y", 2)]);
+    assert_eq!(vec!["y=2"], job.teardown.call_once(false));
+}
+
+#[test]
+fn captured_data_and_into_captured_data() {
+    let job = with_counts(&[("x", 1)]);
+    assert!(matches!(job.teardown.captured_data(), OneOf2::Variant2(c) if c.len() == 1));
+
+    match job.teardown.into_captured_data() {
+        OneOf2::Variant2(counts) => assert_eq!(Some(&1), counts.get("x")),
+        OneOf2::Variant1(_) => panic!("expected the second variant"),
+    }
+}
+
+#[test]
+fn into_one_of_and_fun_once() {
+    fn run<F: FunOnce<bool, Vec<String>>>(f: F) -> usize {
+        f.call_once(true).len()
+    }
+
+    let teardown: Teardown = Capture(vec![])
+        .fun_once(|v: Vec<String>, _: bool| v)
+        .into_one_of();
+    assert_eq!(0, run(teardown));
+    assert_eq!(2, run(with_log(&["a"]).teardown));
+    assert_eq!(
+        Some(1),
+        Some(false)
+            .map(with_log(&["b"]).teardown.into_fn_once())
+            .map(|x| x.len())
+    );
+}
+
+#[test]
+fn clone_and_shrink() {
+    let job = with_log(&["a", "b"]);
+    let copy = job.teardown.clone();
+    assert_eq!(3, job.teardown.call_once(true).len());
+
+    match copy.shrink() {
+        OneOf2::Variant1(fun) => assert_eq!(2, fun.call_once(false).len()),
+        OneOf2::Variant2(_) => panic!("expected the first variant"),
+    }
+}
@@ -0,0 +1,73 @@
+use orx_closure::*;
+
+type Names = Vec<&'static str>;
+
+struct Counter;
+
+impl<C: AsRef<[&'static str]>> CaptureVisitor<C> for Counter {
+    type Output = usize;
+    fn visit(&mut self, capture: &C) -> usize {
+        capture.as_ref().len()
+    }
+}
+
+fn name_len() -> Closure<Names, usize, usize> {
+    Capture(vec!["john", "doe"]).fun(|n, i: usize| n[i].len())
+}
+
+#[test]
+fn one_of1_has_union_api() {
+    let fun = name_len().into_oneof1();
+
+    assert_eq!(4, fun.call(0));
+    assert_eq!(3, fun.call_outlined(1));
+    assert_eq!(&vec!["john", "doe"], fun.captured_data());
+    assert_eq!(2, fun.for_each_capture(&mut Counter));
+    assert_eq!(vec![4, 3], (0..2).map(fun.as_fn()).collect::<Vec<_>>());
+    assert_eq!(4, Fun::call(&fun, 0));
+
+    let data = fun.clone().into_captured_data_any();
+    assert_eq!(Some(&vec!["john", "doe"]), data.downcast_ref::<Names>());
+
+    assert_eq!(3, fun.clone().shrink().call(1));
+    assert_eq!(vec!["john", "doe"], fun.into_captured_data());
+}
+
+#[test]
+fn one_of1_through_into_one_of() {
+    let fun: ClosureOneOf1<Names, usize, usize> = one_of!(var1: name_len());
+    assert_eq!(4, fun.call(0));
+
+    let fun = one_of!(var1: name_len() => ClosureOneOf1<Names, usize, usize>);
+    assert_eq!(3, fun.call(1));
+}
+
+#[test]
+fn one_of1_grows_to_larger_unions() {
+    let fun = name_len().into_oneof1();
+
+    let grown: ClosureOneOf2<Names, (), usize, usize> = fun.clone().grow();
+    assert!(matches!(grown.captured_data(), OneOf2::Variant1(_)));
+    assert_eq!(4, grown.call(0));
+
+    let grown: ClosureOneOf3<Names, (), char, usize, usize> = one_of!(var1: fun.clone());
+    assert!(matches!(grown.captured_data(), OneOf3::Variant1(_)));
+    assert_eq!(3, grown.call(1));
+
+    let grown: ClosureOneOf4<Names, (), char, bool, usize, usize> = one_of!(var1: fun);
+    assert!(matches!(grown.captured_data(), OneOf4::Variant1(_)));
+    assert_eq!(3, grown.call(1));
+}
+
+macro_rules! first_variant {
+    ($closure:expr => $union:ty) => {
+        one_of!(var1: $closure => $union)
+    };
+}
+
+#[test]
+fn one_of1_in_generated_code() {
+    let one = first_variant!(name_len() => ClosureOneOf1<Names, usize, usize>);
+    let two = first_variant!(name_len() => ClosureOneOf2<Names, (), usize, usize>);
+    assert_eq!(one.call(0), two.call(0));
+}
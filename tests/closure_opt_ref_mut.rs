@@ -0,0 +1,54 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Inventory = HashMap<String, Vec<u32>>;
+
+fn inventory() -> Inventory {
+    HashMap::from_iter([("a".to_string(), vec![1, 2]), ("b".to_string(), vec![])])
+}
+
+#[test]
+fn opt_ref_mut_hit_and_miss() {
+    let mut items = Capture(inventory()).fun_option_ref_mut(|m, key: &str| m.get_mut(key));
+
+    match items.call_mut("b") {
+        Some(list) => list.push(9),
+        None => unreachable!(),
+    }
+    assert!(items.call_mut("c").is_none());
+
+    let inventory = items.into_captured_data();
+    assert_eq!(vec![9], inventory["b"]);
+    assert_eq!(2, inventory.len());
+}
+
+#[test]
+fn opt_ref_mut_unsized_out() {
+    let mut slice = Capture(inventory())
+        .fun_option_ref_mut(|m, key: &str| m.get_mut(key).map(|x| x.as_mut_slice()));
+    if let Some(x) = slice.call_mut("a") {
+        x.reverse();
+    }
+    assert_eq!(Some(&mut [2, 1][..]), slice.call_mut("a"));
+    assert_eq!(vec![2, 1], slice.captured_data()["a"]);
+}
+
+#[test]
+fn opt_ref_mut_as_trait() {
+    fn double_all<F: FunOptRefMut<usize, u32> + ?Sized>(fun: &mut F) {
+        let mut i = 0;
+        while let Some(x) = fun.call_mut(i) {
+            *x *= 2;
+            i += 1;
+        }
+    }
+
+    let mut numbers = Capture(vec![1, 2, 3]).fun_option_ref_mut(|v, i: usize| v.get_mut(i));
+    double_all(&mut numbers);
+    assert_eq!(&vec![2, 4, 6], numbers.captured_data());
+
+    let mut boxed: Box<dyn FunOptRefMut<usize, u32>> = Box::new(numbers.clone());
+    double_all(boxed.as_mut());
+    assert_eq!(Some(&mut 12), boxed.call_mut(2));
+    assert_eq!(Some(&mut 6), numbers.call_mut(2));
+}
@@ -0,0 +1,78 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Node {
+    label: String,
+    visits: usize,
+}
+
+fn nodes() -> Vec<Node> {
+    vec![
+        Node {
+            label: "a".to_string(),
+            visits: 0,
+        },
+        Node {
+            label: "b".to_string(),
+            visits: 0,
+        },
+    ]
+}
+
+#[test]
+fn ref_mut_mutates_in_place() {
+    let mut visits = Capture(nodes()).fun_ref_mut(|n, i: usize| &mut n[i].visits);
+    for i in [0, 1, 1, 1] {
+        *visits.call_mut(i) += 1;
+    }
+    let nodes = visits.into_captured_data();
+    assert_eq!(
+        vec![1, 3],
+        nodes.iter().map(|n| n.visits).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn ref_mut_unsized_out() {
+    let mut label = Capture(nodes()).fun_ref_mut(|n, i: usize| n[i].label.as_mut_str());
+    label.call_mut(1).make_ascii_uppercase();
+    assert_eq!("B", label.call_mut(1));
+    assert_eq!("B", label.captured_data()[1].label);
+}
+
+#[test]
+fn ref_mut_captured_map() {
+    let map: HashMap<char, Node> = HashMap::from_iter([('x', Node::default())]);
+    let mut entry = Capture(map).fun_ref_mut(|m, c: char| m.entry(c).or_default());
+    entry.call_mut('x').visits = 5;
+    entry.call_mut('y').label.push('y');
+    let map = entry.into_captured_data();
+    assert_eq!(5, map[&'x'].visits);
+    assert_eq!("y", map[&'y'].label);
+}
+
+#[test]
+fn ref_mut_clone_is_independent() {
+    let mut a = Capture(vec![0, 0]).fun_ref_mut(|v, i: usize| &mut v[i]);
+    let mut b = a.clone();
+    *a.call_mut(0) = 1;
+    *b.call_mut(0) = 2;
+    assert_eq!(&vec![1, 0], a.captured_data());
+    assert_eq!(&vec![2, 0], b.captured_data());
+}
+
+#[test]
+fn ref_mut_trait_objects() {
+    fn increment(fun: &mut dyn FunRefMut<usize, usize>, i: usize) {
+        *fun.call_mut(i) += 1;
+    }
+    let mut visits = Capture(nodes()).fun_ref_mut(|n, i: usize| &mut n[i].visits);
+    increment(&mut visits, 0);
+    increment(&mut visits, 0);
+
+    let mut boxed: Box<dyn FunRefMut<usize, usize>> = Box::new(visits);
+    increment(boxed.as_mut(), 1);
+    assert_eq!(&2, boxed.call_mut(0));
+    assert_eq!(&1, boxed.call_mut(1));
+}
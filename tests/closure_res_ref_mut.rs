@@ -0,0 +1,79 @@
+use orx_closure::*;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EditError {
+    Missing(usize),
+    Locked(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Doc {
+    text: String,
+    locked: bool,
+}
+
+fn docs() -> Vec<Doc> {
+    vec![
+        Doc {
+            text: "a".to_string(),
+            locked: false,
+        },
+        Doc {
+            text: "b".to_string(),
+            locked: true,
+        },
+    ]
+}
+
+type Editor = ClosureResRefMut<Vec<Doc>, usize, String, EditError>;
+
+fn editor() -> Editor {
+    Capture(docs()).fun_result_ref_mut(|d, i: usize| match d.get_mut(i) {
+        Some(doc) if doc.locked => Err(EditError::Locked(i)),
+        Some(doc) => Ok(&mut doc.text),
+        None => Err(EditError::Missing(i)),
+    })
+}
+
+fn append_all<F: FunResRefMut<usize, String, EditError> + ?Sized>(
+    fun: &mut F,
+    ids: &[usize],
+) -> Result<(), EditError> {
+    for id in ids {
+        fun.call_mut(*id)?.push('+');
+    }
+    Ok(())
+}
+
+#[test]
+fn res_ref_mut_edits_and_errors() {
+    let mut editor = editor();
+    assert_eq!(Ok(()), append_all(&mut editor, &[0, 0]));
+    assert_eq!(
+        Err(EditError::Locked(1)),
+        append_all(&mut editor, &[0, 1, 0])
+    );
+    assert_eq!(Err(EditError::Missing(9)), append_all(&mut editor, &[9]));
+
+    let docs = editor.into_captured_data();
+    assert_eq!("a+++", docs[0].text);
+    assert_eq!("b", docs[1].text);
+}
+
+#[test]
+fn res_ref_mut_unsized_out() {
+    let mut text = Capture(docs())
+        .fun_result_ref_mut(|d, i: usize| d.get_mut(i).map(|x| x.text.as_mut_str()).ok_or(i));
+    text.call_mut(1)
+        .map(|x| x.make_ascii_uppercase())
+        .unwrap_or_default();
+    assert_eq!("B", text.captured_data()[1].text);
+    assert_eq!(Err(3), text.call_mut(3).map(|x| x.len()));
+}
+
+#[test]
+fn res_ref_mut_trait_object() {
+    let mut boxed: Box<dyn FunResRefMut<usize, String, EditError>> = Box::new(editor());
+    assert_eq!(Ok(()), append_all(boxed.as_mut(), &[0]));
+    assert_eq!(Ok("a+"), boxed.call_mut(0).map(|x| x.as_str()));
+}
@@ -0,0 +1,90 @@
+use orx_closure::*;
+use std::thread;
+
+fn neighbors_within_two_hops() -> ClosureScratch<Vec<Vec<usize>>, Vec<usize>, usize, usize> {
+    let successors = vec![vec![1, 2], vec![2], vec![0], vec![]];
+    Capture(successors).fun_with_scratch(|succ, visited: &mut Vec<usize>, node: usize| {
+        visited.clear();
+        for &a in &succ[node] {
+            visited.push(a);
+            visited.extend(succ[a].iter().copied());
+        }
+        visited.sort();
+        visited.dedup();
+        visited.len()
+    })
+}
+
+#[test]
+fn scratch_is_reused() {
+    let fun = neighbors_within_two_hops();
+
+    let mut scratch = Vec::with_capacity(8);
+    let ptr = scratch.as_ptr();
+
+    assert_eq!(3, fun.call(&mut scratch, 0));
+    assert_eq!(2, fun.call(&mut scratch, 1));
+    assert_eq!(3, fun.call(&mut scratch, 2));
+    assert_eq!(0, fun.call(&mut scratch, 3));
+
+    assert_eq!(ptr, scratch.as_ptr());
+}
+
+#[test]
+fn scratch_as_fn() {
+    let fun = neighbors_within_two_hops();
+    let fun = fun.as_fn();
+    let mut scratch = vec![];
+    assert_eq!(3, fun(&mut scratch, 0));
+}
+
+#[test]
+fn scratch_clone_and_captured_data() {
+    let fun = neighbors_within_two_hops();
+    let clone = fun.clone();
+    assert_eq!(4, clone.captured_data().len());
+    assert_eq!(fun.into_captured_data(), clone.into_captured_data());
+}
+
+#[test]
+fn scratch_pool_reuses_idle() {
+    let pool = ScratchPool::new(Vec::<usize>::new);
+    assert_eq!(0, pool.num_idle());
+
+    let fun = neighbors_within_two_hops();
+    assert_eq!(3, fun.call_pooled(&pool, 0));
+    assert_eq!(1, pool.num_idle());
+    assert_eq!(2, fun.call_pooled(&pool, 1));
+    assert_eq!(1, pool.num_idle());
+
+    let a = pool.get();
+    let b = pool.get();
+    assert_eq!(0, pool.num_idle());
+    drop(a);
+    drop(b);
+    assert_eq!(2, pool.num_idle());
+}
+
+#[test]
+fn scratch_pool_multi_threaded() {
+    let fun = neighbors_within_two_hops();
+    let pool = ScratchPool::new(Vec::new);
+
+    let results: Vec<Vec<usize>> = thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let (fun, pool) = (&fun, &pool);
+                s.spawn(move || (0..4).map(|node| fun.call_pooled(pool, node)).collect())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_default())
+            .collect()
+    });
+
+    for result in results {
+        assert_eq!(vec![3, 2, 3, 0], result);
+    }
+    assert!(pool.num_idle() <= 4);
+}
@@ -0,0 +1,37 @@
+use orx_closure::*;
+
+#[test]
+fn curry_derives_row_accessors() {
+    let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let at = Capture(matrix).fun(|m, (i, j): (usize, usize)| m[i][j]);
+
+    let rows: Vec<_> = (0..2).map(|i| at.clone().curry(i)).collect();
+    let row_sums: Vec<i32> = rows
+        .iter()
+        .map(|row| (0..3).map(|j| row.call(j)).sum())
+        .collect();
+    assert_eq!(vec![6, 15], row_sums);
+}
+
+#[test]
+fn curry_clones_the_bound_argument_per_call() {
+    let greet = Capture("!")
+        .fun(|suffix, (greeting, name): (String, &str)| format!("{} {}{}", greeting, name, suffix));
+
+    let hello = greet.curry(String::from("hello"));
+    assert_eq!("hello john!", hello.call("john"));
+    assert_eq!("hello doe!", hello.call("doe"));
+
+    let as_fn = hello.as_fn();
+    assert_eq!(vec!["hello a!", "hello b!"], ["a", "b"].map(as_fn).to_vec());
+}
+
+#[test]
+fn curry_by_reference_capture() {
+    let matrix = vec![vec![1, 2], vec![3, 4]];
+    let at = Capture(&matrix).fun(|m, (i, j): (usize, usize)| m[i][j]);
+
+    let first_row = at.curry(0);
+    assert_eq!(2, first_row.call(1));
+    assert_eq!(2, first_row.captured_data().0.call2(0, 1));
+}
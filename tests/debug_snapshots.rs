@@ -0,0 +1,267 @@
+//! Golden snapshots of the `Debug` output of every public type with a sample capture.
+//!
+//! Snapshots are stored in `tests/snapshots`; when a `Debug` output is intentionally changed,
+//! the snapshots can be reviewed and accepted with `cargo insta review`.
+
+use insta::assert_snapshot;
+use orx_closure::*;
+
+type Names = Vec<&'static str>;
+type Ids = [u32; 2];
+
+fn names() -> Names {
+    vec!["john", "doe"]
+}
+
+fn dbg<T: std::fmt::Debug>(value: &T) -> String {
+    format!("{:?}", value)
+}
+
+#[test]
+fn snapshot_closures() {
+    let fun = Capture(names()).fun(|x, i: usize| x[i].len());
+    assert_snapshot!("closure", dbg(&fun));
+
+    let fun = Capture(names()).fun_mut(|x, i: usize| x.remove(i));
+    assert_snapshot!("closure_mut", dbg(&fun));
+
+    let fun = Capture(names()).fun_once(|x, i: usize| x.into_iter().nth(i));
+    assert_snapshot!("closure_once", dbg(&fun));
+
+    let setter = Capture(names()).setter(|x, i: usize, name: &'static str| x[i] = name);
+    assert_snapshot!("setter", dbg(&setter));
+
+    let lens = Capture(names()).lens(|x, i: usize| &x[i], |x, i, name| x[i] = name);
+    assert_snapshot!("lens", dbg(&lens));
+
+    let fun = Capture(EnvCapture::new(names(), |x| x.clear())).fun(|x, i: usize| x[i]);
+    assert_snapshot!("env_capture", dbg(&fun));
+
+    let fun = Capture(names())
+        .fun(|x, i: usize| x[i])
+        .then(Capture(1).fun(|k, x: &str| x.len() * k));
+    assert_snapshot!("closure_chain", dbg(&fun));
+
+    let hub = CaptureHub::new(names());
+    let fun = hub.fun(|x, i: usize| x[i]);
+    assert_snapshot!("capture_hub", dbg(&hub));
+    assert_snapshot!("capture_hub_closure", dbg(&fun));
+
+    let guard = defer(Capture(names()).fun_once(|_, _: ()| {}));
+    assert_snapshot!("defer", dbg(&guard));
+
+    let fun = Capture(names()).fun_call_value(|x, i: usize| CallValue::Borrowed(&x[i]));
+    assert_snapshot!("closure_call_value", dbg(&fun));
+
+    let fun = Capture(names()).fun_ref(|x, i: usize| x[i]);
+    assert_snapshot!("closure_ref", dbg(&fun));
+
+    let fun = Capture(names()).fun_ref_mut(|x, i: usize| &mut x[i]);
+    assert_snapshot!("closure_ref_mut", dbg(&fun));
+
+    let fun = Capture(names()).fun_option_ref(|x, i: usize| x.get(i).copied());
+    assert_snapshot!("closure_opt_ref", dbg(&fun));
+
+    let fun = Capture(names()).fun_option_ref_mut(|x, i: usize| x.get_mut(i));
+    assert_snapshot!("closure_opt_ref_mut", dbg(&fun));
+
+    let fun = Capture(names()).fun_result_ref(|x, i: usize| x.get(i).copied().ok_or(i));
+    assert_snapshot!("closure_res_ref", dbg(&fun));
+
+    let fun = Capture(names()).fun_result_ref_mut(|x, i: usize| x.get_mut(i).ok_or(i));
+    assert_snapshot!("closure_res_ref_mut", dbg(&fun));
+
+    let fun = Capture(names()).fun_with_scratch(|x, buf: &mut String, i: usize| {
+        buf.clear();
+        buf.push_str(x[i]);
+        buf.len()
+    });
+    assert_snapshot!("closure_scratch", dbg(&fun));
+
+    let fun = Capture(names()).accumulator(|x, i: usize| x.truncate(i));
+    assert_snapshot!("accumulator", dbg(&fun));
+
+    let fun = Capture(names()).fun_fold(|x, acc: usize, i: usize| acc + x[i].len());
+    assert_snapshot!("closure_fold", dbg(&fun));
+
+    let fun = Capture(names()).fun_array_of([|x, i: usize| x[i].len(), |_, i: usize| i], 1);
+    assert_snapshot!("closure_array_of", dbg(&fun));
+
+    let fun = Capture(names()).fun_key(|x, i: &usize| x[*i].len());
+    assert_snapshot!("closure_key", dbg(&fun));
+
+    let fun = ClosureBitSet::from_pairs(2, [(0, 1)]);
+    assert_snapshot!("closure_bit_set", dbg(&fun));
+}
+
+#[test]
+fn snapshot_arity2_closures() {
+    let fun = Capture(names()).fun2(|x, i: usize, j: usize| x[i].len() + j);
+    assert_snapshot!("closure2", dbg(&fun));
+
+    let fun = Capture(names()).fun2_ref(|x, i: usize, j: usize| &x[i][j..]);
+    assert_snapshot!("closure_ref2", dbg(&fun));
+
+    let fun =
+        Capture(names()).fun2_option_ref(|x, i: usize, j: usize| x.get(i).and_then(|s| s.get(j..)));
+    assert_snapshot!("closure_opt_ref2", dbg(&fun));
+
+    let fun = Capture(names())
+        .fun2_result_ref(|x, i: usize, j: usize| x.get(i).and_then(|s| s.get(j..)).ok_or(i));
+    assert_snapshot!("closure_res_ref2", dbg(&fun));
+}
+
+#[test]
+fn snapshot_arity3_closures() {
+    let fun = Capture(names()).fun3(|x, i: usize, j: usize, k: usize| x[i].len() + j * k);
+    assert_snapshot!("closure3", dbg(&fun));
+}
+
+#[test]
+fn snapshot_closure_val_unions() {
+    let fun: ClosureOneOf1<Names, usize, usize> =
+        Capture(names()).fun(|x, i: usize| x[i].len()).into_oneof1();
+    assert_snapshot!("closure_oneof1", dbg(&fun));
+
+    let fun: ClosureOneOf2<Names, Ids, usize, usize> = Capture(names())
+        .fun(|x, i: usize| x[i].len())
+        .into_oneof2_var1();
+    assert_snapshot!("closure_oneof2", dbg(&fun));
+
+    let fun: ClosureOneOf3<Names, Ids, (), usize, usize> = Capture([1, 2])
+        .fun(|x, i: usize| x[i] as usize)
+        .into_oneof3_var2();
+    assert_snapshot!("closure_oneof3", dbg(&fun));
+
+    let fun: ClosureOneOf4<Names, Ids, (), char, usize, usize> =
+        Capture('x').fun(|_, i: usize| i).into_oneof4_var4();
+    assert_snapshot!("closure_oneof4", dbg(&fun));
+}
+
+#[test]
+fn snapshot_closure_mut_unions() {
+    let fun: ClosureMutOneOf2<Names, Ids, usize, usize> = Capture(names())
+        .fun_mut(|x, i: usize| x.remove(i).len())
+        .into_oneof2_var1();
+    assert_snapshot!("closure_mut_oneof2", dbg(&fun));
+
+    let fun: ClosureMutOneOf3<Names, Ids, (), usize, usize> = Capture([1, 2])
+        .fun_mut(|x, i: usize| x[i] as usize)
+        .into_oneof3_var2();
+    assert_snapshot!("closure_mut_oneof3", dbg(&fun));
+
+    let fun: ClosureMutOneOf4<Names, Ids, (), char, usize, usize> =
+        Capture('x').fun_mut(|_, i: usize| i).into_oneof4_var4();
+    assert_snapshot!("closure_mut_oneof4", dbg(&fun));
+}
+
+#[test]
+fn snapshot_closure_once_unions() {
+    let fun: ClosureOnceOneOf2<Names, Ids, usize, usize> = Capture([1, 2])
+        .fun_once(|x, i: usize| x[i] as usize)
+        .into_oneof2_var2();
+    assert_snapshot!("closure_once_oneof2", dbg(&fun));
+}
+
+#[test]
+fn snapshot_closure_ref_unions() {
+    let fun: ClosureRefOneOf2<Names, Ids, usize, str> = Capture(names())
+        .fun_ref(|x, i: usize| x[i])
+        .into_oneof2_var1();
+    assert_snapshot!("closure_ref_oneof2", dbg(&fun));
+
+    let fun: ClosureRefOneOf3<Names, Ids, (), usize, str> =
+        Capture(()).fun_ref(|_, _: usize| "").into_oneof3_var3();
+    assert_snapshot!("closure_ref_oneof3", dbg(&fun));
+
+    let fun: ClosureRefOneOf4<Names, Ids, (), char, usize, str> =
+        Capture([1, 2]).fun_ref(|_, _: usize| "").into_oneof4_var2();
+    assert_snapshot!("closure_ref_oneof4", dbg(&fun));
+}
+
+#[test]
+fn snapshot_closure_opt_ref_unions() {
+    let fun: ClosureOptRefOneOf2<Names, Ids, usize, str> = Capture([1, 2])
+        .fun_option_ref(|_, _: usize| None)
+        .into_oneof2_var2();
+    assert_snapshot!("closure_opt_ref_oneof2", dbg(&fun));
+
+    let fun: ClosureOptRefOneOf3<Names, Ids, (), usize, str> = Capture(names())
+        .fun_option_ref(|x, i: usize| x.get(i).copied())
+        .into_oneof3_var1();
+    assert_snapshot!("closure_opt_ref_oneof3", dbg(&fun));
+
+    let fun: ClosureOptRefOneOf4<Names, Ids, (), char, usize, str> = Capture(())
+        .fun_option_ref(|_, _: usize| None)
+        .into_oneof4_var3();
+    assert_snapshot!("closure_opt_ref_oneof4", dbg(&fun));
+}
+
+#[test]
+fn snapshot_closure_res_ref_unions() {
+    let fun: ClosureResRefOneOf2<Names, Ids, usize, str, usize> = Capture(names())
+        .fun_result_ref(|x, i: usize| x.get(i).copied().ok_or(i))
+        .into_oneof2_var1();
+    assert_snapshot!("closure_res_ref_oneof2", dbg(&fun));
+
+    let fun: ClosureResRefOneOf3<Names, Ids, (), usize, str, usize> = Capture([1, 2])
+        .fun_result_ref(|_, i: usize| Err(i))
+        .into_oneof3_var2();
+    assert_snapshot!("closure_res_ref_oneof3", dbg(&fun));
+
+    let fun: ClosureResRefOneOf4<Names, Ids, (), char, usize, str, usize> = Capture('x')
+        .fun_result_ref(|_, i: usize| Err(i))
+        .into_oneof4_var4();
+    assert_snapshot!("closure_res_ref_oneof4", dbg(&fun));
+}
+
+#[test]
+fn snapshot_one_of() {
+    let one_of: OneOf2<Names, Ids> = OneOf2::Variant2([1, 2]);
+    assert_snapshot!("one_of2", dbg(&one_of));
+
+    let one_of: OneOf3<Names, Ids, ()> = OneOf3::Variant1(names());
+    assert_snapshot!("one_of3", dbg(&one_of));
+
+    let one_of: OneOf4<Names, Ids, (), char> = OneOf4::Variant4('x');
+    assert_snapshot!("one_of4", dbg(&one_of));
+}
+
+#[test]
+fn snapshot_scratch_pool() {
+    let pool = ScratchPool::new(|| String::from("scratch"));
+    assert_snapshot!("scratch_pool_empty", dbg(&pool));
+
+    let guard = pool.get();
+    assert_snapshot!("scratch_guard", dbg(&guard));
+    drop(guard);
+    assert_snapshot!("scratch_pool_idle", dbg(&pool));
+}
+
+#[test]
+fn snapshot_formatters() {
+    let formatter: formatters::Formatter<Vec<i32>> = formatters::template("{} -> {}");
+    assert_snapshot!("formatter", dbg(&formatter));
+
+    let segments = formatters::segments("a/b");
+    assert_snapshot!("formatter_segments", dbg(&segments));
+}
+
+#[test]
+#[cfg(feature = "testing")]
+fn snapshot_mock_fun() {
+    let mock = testing::MockFun::new_fallible()
+        .returns(0, "john")
+        .returns_none(1)
+        .returns_err(2, 'x');
+    let _ = FunOptRef::<_, str>::call(&mock, 0);
+    assert_snapshot!("mock_fun", dbg(&mock));
+}
+
+#[test]
+fn snapshot_piecewise() {
+    let pieces = Piecewise::<i32, fn(i32) -> i32>::new()
+        .on(10..20, |x| x + 1)
+        .on(0..10, |x| x - 1);
+    assert_snapshot!("piecewise", dbg(&pieces));
+}
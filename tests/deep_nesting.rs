@@ -0,0 +1,110 @@
+use orx_closure::*;
+use std::any::type_name;
+
+type Base = Closure<Vec<i64>, usize, i64>;
+
+fn base() -> Base {
+    Capture(vec![1, 2, 3]).fun(|x, i| x[i])
+}
+
+fn add<C>(inner: Closure<C, usize, i64>) -> Closure<(Closure<C, usize, i64>, i64), usize, i64> {
+    Capture((inner, 1)).fun(|(inner, a), i| inner.call(i) + a)
+}
+
+fn type_len<T>(_: &T) -> usize {
+    type_name::<T>().len()
+}
+
+macro_rules! nest {
+    ($f:expr) => { $f };
+    ($f:expr, _ $(, $rest:tt)*) => {
+        add(nest!($f $(, $rest)*))
+    };
+}
+
+#[test]
+fn nesting_depth_20() {
+    let deep = nest!(
+        base(),
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        _
+    );
+    assert_eq!(1 + 20, deep.call(0));
+    assert_eq!(3 + 20, deep.call(2));
+}
+
+#[test]
+fn type_length_grows_with_nesting_and_seal_collapses_it() {
+    let depth5 = nest!(base(), _, _, _, _, _);
+    let depth10 = nest!(base(), _, _, _, _, _, _, _, _, _, _);
+    let depth15 = nest!(base(), _, _, _, _, _, _, _, _, _, _, _, _, _, _, _);
+
+    let (l5, l10, l15) = (type_len(&depth5), type_len(&depth10), type_len(&depth15));
+    assert!(l5 < l10 && l10 < l15);
+    assert!(l15 - l10 >= l10 - l5);
+
+    let sealed5 = depth5.seal();
+    let sealed10 = depth10.seal();
+    let sealed15 = depth15.seal();
+
+    assert_eq!(type_len(&sealed5), type_len(&sealed10));
+    assert_eq!(type_len(&sealed10), type_len(&sealed15));
+    assert!(type_len(&sealed15) < l10);
+
+    let sealed = [sealed5, sealed10, sealed15];
+    let results: Vec<_> = sealed.iter().map(|f| f.call(1)).collect();
+    assert_eq!(vec![7, 12, 17], results);
+}
+
+#[test]
+fn sealing_in_between_keeps_types_small() {
+    let mut fun = base().seal();
+    for _ in 0..50 {
+        fun = add(fun).seal();
+    }
+    assert_eq!(51, fun.call(0));
+    assert_eq!(53, fun.call(2));
+}
+
+#[test]
+fn deep_nesting_ref_kinds() {
+    fn deref<C>(
+        inner: ClosureRef<C, usize, i64>,
+    ) -> ClosureRef<ClosureRef<C, usize, i64>, usize, i64> {
+        Capture(inner).fun_ref(|inner, i| inner.call(i))
+    }
+
+    let fun = Capture(vec![4, 5]).fun_ref(|x, i: usize| &x[i]);
+    let fun = deref(deref(deref(deref(deref(fun)))));
+    let sealed = deref(deref(fun.seal())).seal();
+    assert_eq!(&5, sealed.call(1));
+
+    fn opt<C>(
+        inner: ClosureOptRef<C, usize, i64>,
+    ) -> ClosureOptRef<ClosureOptRef<C, usize, i64>, usize, i64> {
+        Capture(inner).fun_option_ref(|inner, i| inner.call(i).filter(|x| **x > 0))
+    }
+    let fun = Capture(vec![4, -5]).fun_option_ref(|x, i: usize| x.get(i));
+    let sealed = opt(opt(opt(fun))).seal();
+    assert_eq!(Some(&4), sealed.call(0));
+    assert_eq!(None, sealed.call(1));
+    assert_eq!(None, sealed.call(2));
+}
@@ -0,0 +1,91 @@
+use orx_closure::*;
+
+struct Graph {
+    num_nodes: usize,
+    is_active: Closure<(), usize, bool>,
+    weight_of: Closure<(), (usize, usize), u32>,
+    name_of: ClosureOptRef<(), usize, str>,
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self {
+            num_nodes: 3,
+            is_active: defaults::always_true(),
+            weight_of: defaults::always_one(),
+            name_of: defaults::always_none(),
+        }
+    }
+}
+
+static IS_ACTIVE: Closure<(), usize, bool> = defaults::USIZE_ALWAYS_TRUE;
+
+#[test]
+fn defaults_as_default_impl_body() {
+    let graph = Graph::default();
+    assert!((0..graph.num_nodes).all(|i| graph.is_active.call(i)));
+    assert_eq!(1, graph.weight_of.call((0, 2)));
+    assert_eq!(None, graph.name_of.call(0));
+}
+
+#[test]
+fn defaults_constants() {
+    assert!(IS_ACTIVE.call(3));
+    assert!(!defaults::USIZE_ALWAYS_FALSE.call(3));
+    assert_eq!(3, defaults::USIZE_IDENTITY.call(3));
+}
+
+#[test]
+fn defaults_functions() {
+    let zero: Closure<(), &str, i64> = defaults::always_zero();
+    let default: Closure<(), &str, Vec<i32>> = defaults::always_default();
+    let identity: Closure<(), (i32, char), (i32, char)> = defaults::identity();
+    let reject: Closure<(), f32, bool> = defaults::always_false();
+
+    assert_eq!(0, zero.call("x"));
+    assert!(default.call("x").is_empty());
+    assert_eq!((1, 'a'), identity.call((1, 'a')));
+    assert!(!reject.call(1.0));
+}
+
+#[test]
+fn defaults_into_unions() {
+    let data = vec![false, true];
+    let custom = Capture(data).fun(|d, i: usize| d[i]);
+
+    let funs: Vec<ClosureOneOf2<Vec<bool>, (), usize, bool>> = vec![
+        custom.into_oneof2_var1(),
+        defaults::always_true().into_oneof2_var2(),
+    ];
+    assert_eq!(
+        vec![false, true],
+        funs.iter().map(|f| f.call(0)).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn zero_and_one_for_all_primitive_numbers() {
+    fn check<T: defaults::Number + PartialEq + std::fmt::Debug + From<bool>>() {
+        let zero: Closure<(), usize, T> = defaults::always_zero();
+        let one: Closure<(), usize, T> = defaults::always_one();
+        assert_eq!(T::from(false), zero.call(3));
+        assert_eq!(T::from(true), one.call(3));
+    }
+    check::<i8>();
+    check::<i16>();
+    check::<i32>();
+    check::<i64>();
+    check::<i128>();
+    check::<isize>();
+    check::<u8>();
+    check::<u16>();
+    check::<u32>();
+    check::<u64>();
+    check::<u128>();
+    check::<usize>();
+
+    let zero: Closure<(), usize, f32> = defaults::always_zero();
+    assert_eq!(0.0, zero.call(0));
+    let one: Closure<(), usize, f64> = defaults::always_one();
+    assert_eq!(1.0, one.call(0));
+}
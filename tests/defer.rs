@@ -0,0 +1,53 @@
+use orx_closure::*;
+use std::{cell::RefCell, rc::Rc};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn guard(log: &Log, name: &str) -> Defer<(Log, String)> {
+    defer(
+        Capture((log.clone(), name.to_string()))
+            .fun_once(|(log, name), _: ()| log.borrow_mut().push(name)),
+    )
+}
+
+#[test]
+fn defer_runs_on_drop() {
+    let log = Log::default();
+    {
+        let _guard = guard(&log, "a");
+        assert!(log.borrow().is_empty());
+    }
+    assert_eq!(vec!["a"], *log.borrow());
+}
+
+#[test]
+fn defer_runs_in_reverse_order_of_creation() {
+    let log = Log::default();
+    {
+        let _first = guard(&log, "first");
+        let _second = guard(&log, "second");
+    }
+    assert_eq!(vec!["second", "first"], *log.borrow());
+}
+
+#[test]
+fn defer_cancel() {
+    let log = Log::default();
+    let g = guard(&log, "a");
+    assert_eq!("a", g.captured_data().1);
+
+    let (_, name) = g.cancel();
+    assert_eq!("a", name);
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn defer_runs_on_unwind() {
+    let log = Log::default();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _guard = guard(&log, "unwound");
+        panic!("failure");
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec!["unwound"], *log.borrow());
+}
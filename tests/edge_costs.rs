@@ -0,0 +1,84 @@
+#![cfg(feature = "petgraph")]
+
+use orx_closure::{edge_costs, *};
+use petgraph::{
+    algo::{astar, dijkstra},
+    graph::{DiGraph, EdgeIndex, NodeIndex},
+};
+use std::collections::HashMap;
+
+type Costs = Vec<Vec<u32>>;
+type Overrides = HashMap<(usize, usize), u32>;
+type Cost = ClosureOneOf2<Costs, Overrides, (NodeIndex, NodeIndex), u32>;
+
+fn graph() -> (DiGraph<(), u32>, [NodeIndex; 4]) {
+    let mut graph = DiGraph::new();
+    let nodes = [(); 4].map(|_| graph.add_node(()));
+    let [a, b, c, d] = nodes;
+    graph.extend_with_edges([(a, b, 1), (b, d, 1), (a, c, 2), (c, d, 2)]);
+    (graph, nodes)
+}
+
+fn matrix_cost() -> Cost {
+    let mut costs = vec![vec![0; 4]; 4];
+    costs[0][1] = 5;
+    costs[1][3] = 5;
+    costs[0][2] = 1;
+    costs[2][3] = 1;
+    Capture(costs)
+        .fun(|c, (i, j): (NodeIndex, NodeIndex)| c[i.index()][j.index()])
+        .into_oneof2_var1()
+}
+
+fn override_cost() -> Cost {
+    let overrides = Overrides::from_iter([((0, 1), 0)]);
+    Capture(overrides)
+        .fun(|o, (i, j): (NodeIndex, NodeIndex)| {
+            o.get(&(i.index(), j.index())).copied().unwrap_or(10)
+        })
+        .into_oneof2_var2()
+}
+
+#[test]
+fn union_costs_by_endpoints() {
+    let (graph, [a, b, c, d]) = graph();
+
+    let costs = dijkstra(&graph, a, None, edge_costs::by_endpoints(&matrix_cost()));
+    assert_eq!(Some(&2), costs.get(&d));
+    assert_eq!(Some(&5), costs.get(&b));
+
+    let cost = override_cost();
+    let (total, path) = astar(
+        &graph,
+        a,
+        |n| n == d,
+        edge_costs::by_endpoints(&cost),
+        |_| 0,
+    )
+    .expect("reachable");
+    assert_eq!(10, total);
+    assert_eq!(vec![a, b, d], path);
+    assert_eq!(
+        Some(&10),
+        dijkstra(&graph, a, None, edge_costs::by_endpoints(&cost)).get(&c)
+    );
+}
+
+#[test]
+fn costs_by_weight_and_id() {
+    let (graph, [a, _, _, d]) = graph();
+
+    let scaled = Capture(3).fun(|k, w: u32| k * w);
+    assert_eq!(
+        Some(&6),
+        dijkstra(&graph, a, None, edge_costs::by_weight(&scaled)).get(&d)
+    );
+
+    let closed = vec![EdgeIndex::new(0)];
+    let toll =
+        Capture(closed).fun(|closed, e: EdgeIndex| if closed.contains(&e) { 100 } else { 1 });
+    assert_eq!(
+        Some(&2),
+        dijkstra(&graph, a, None, edge_costs::by_id(&toll)).get(&d)
+    );
+}
@@ -0,0 +1,100 @@
+use orx_closure::*;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+type Registry = Arc<Mutex<Vec<&'static str>>>;
+
+struct Listener {
+    name: &'static str,
+    registry: Registry,
+}
+
+fn listen(registry: &Registry, name: &'static str) -> EnvCapture<Listener> {
+    registry.lock().expect("poisoned").push(name);
+    let listener = Listener {
+        name,
+        registry: registry.clone(),
+    };
+    EnvCapture::new(listener, |l| {
+        l.registry
+            .lock()
+            .expect("poisoned")
+            .retain(|x| *x != l.name)
+    })
+}
+
+fn registered(registry: &Registry) -> Vec<&'static str> {
+    registry.lock().expect("poisoned").clone()
+}
+
+#[test]
+fn cleanup_on_drop() {
+    let registry = Registry::default();
+    {
+        let name_len = Capture(listen(&registry, "a")).fun(|l, x: usize| l.name.len() + x);
+        assert_eq!(2, name_len.call(1));
+        assert_eq!(vec!["a"], registered(&registry));
+    }
+    assert!(registered(&registry).is_empty());
+}
+
+#[test]
+fn cleanup_runs_once_through_clones() {
+    let num_cleanups = Arc::new(AtomicUsize::new(0));
+    let env = EnvCapture::new(num_cleanups.clone(), |n| {
+        n.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let fun = Capture(env).fun(|n, _: ()| n.load(Ordering::SeqCst));
+    let clones: Vec<_> = (0..3).map(|_| fun.clone()).collect();
+    assert_eq!(4, fun.captured_data().num_handles());
+
+    drop(fun);
+    assert_eq!(0, clones[0].call(()));
+
+    drop(clones);
+    assert_eq!(1, num_cleanups.load(Ordering::SeqCst));
+}
+
+#[test]
+fn cleanup_through_union_conversions() {
+    type Union = ClosureRefOneOf2<EnvCapture<Listener>, String, (), str>;
+
+    let registry = Registry::default();
+    let union: Union = Capture(listen(&registry, "b"))
+        .fun_ref(|l, _: ()| l.name)
+        .into_oneof2_var1();
+    assert_eq!("b", union.call(()));
+
+    let shrunk = union.shrink();
+    assert_eq!(vec!["b"], registered(&registry));
+
+    let data = match shrunk {
+        OneOf2::Variant1(fun) => fun.into_captured_data(),
+        OneOf2::Variant2(_) => panic!("expected the first variant"),
+    };
+    assert_eq!(vec!["b"], registered(&registry));
+
+    drop(data);
+    assert!(registered(&registry).is_empty());
+}
+
+#[test]
+fn cleanup_across_threads() {
+    let registry = Registry::default();
+    let fun = Capture(listen(&registry, "t")).fun(|l, x: usize| l.name.len() * x);
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let fun = fun.clone();
+            std::thread::spawn(move || fun.call(i))
+        })
+        .collect();
+    drop(fun);
+
+    let total: usize = handles.into_iter().map(|h| h.join().unwrap_or(0)).sum();
+    assert_eq!(6, total);
+    assert!(registered(&registry).is_empty());
+}
@@ -0,0 +1,100 @@
+use orx_closure::*;
+use std::{error::Error, fmt, num::ParseIntError};
+
+#[derive(Debug, PartialEq)]
+struct Missing(usize);
+
+impl fmt::Display for Missing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing {}", self.0)
+    }
+}
+
+impl Error for Missing {}
+
+type Union = ClosureResRefOneOf2<Vec<i32>, Vec<Vec<i32>>, usize, i32, Missing>;
+
+fn flat() -> Union {
+    Capture(vec![1, 2, 3])
+        .fun_result_ref(|v, i: usize| v.get(i).ok_or(Missing(i)))
+        .into_oneof2_var1()
+}
+
+fn jagged() -> Union {
+    Capture(vec![vec![1], vec![2, 3]])
+        .fun_result_ref(|v, i: usize| v.iter().flatten().nth(i).ok_or(Missing(i)))
+        .into_oneof2_var2()
+}
+
+#[test]
+fn errors_to_string_union() {
+    for fun in [flat(), jagged()] {
+        let fun = fun.errors_to_string();
+        assert_eq!(Ok(&3), fun.call(2));
+        assert_eq!(Err(String::from("missing 5")), fun.call(5));
+    }
+}
+
+#[test]
+fn errors_to_string_unifies_error_types() {
+    let parsing: ClosureResRefOneOf3<Vec<i32>, (), (), usize, i32, ParseIntError> =
+        Capture(vec![4])
+            .fun_result_ref(|v, i: usize| v.get(i).ok_or_else(|| "-".parse::<i32>().unwrap_err()))
+            .into_oneof3_var1();
+
+    let providers: Vec<Box<dyn FunResRef<usize, i32, String>>> = vec![
+        Box::new(flat().errors_to_string()),
+        Box::new(parsing.errors_to_string()),
+    ];
+
+    let errors: Vec<_> = providers.iter().map(|p| p.call(9).unwrap_err()).collect();
+    assert_eq!(
+        vec![
+            String::from("missing 9"),
+            String::from("invalid digit found in string")
+        ],
+        errors
+    );
+}
+
+#[test]
+fn errors_boxed_with_question_mark() {
+    fn sum(
+        fun: &impl FunResRef<usize, i32, Box<dyn Error + Send + Sync>>,
+        indices: &[usize],
+    ) -> Result<i32, Box<dyn Error + Send + Sync>> {
+        let mut total = 0;
+        for i in indices {
+            total += fun.call(*i)?;
+        }
+        Ok(total)
+    }
+
+    let fun = jagged().errors_boxed();
+    assert_eq!(6, sum(&fun, &[0, 1, 2]).unwrap_or_default());
+
+    let error = sum(&fun, &[0, 4]).unwrap_err();
+    assert_eq!(Some(&Missing(4)), error.downcast_ref::<Missing>());
+}
+
+#[test]
+fn errors_of_closure_res_ref() {
+    let fun = Capture(vec![1]).fun_result_ref(|v, i: usize| v.get(i).ok_or(Missing(i)));
+    let fun = fun.errors_boxed();
+    assert_eq!(Some(&1), fun.call(0).ok());
+    assert_eq!("missing 3", fun.call(3).unwrap_err().to_string());
+}
+
+#[test]
+fn errors_boxed_from_messages() {
+    let fun = Capture(vec![1]).fun_result_ref(|v, i: usize| v.get(i).ok_or("out of bounds"));
+    let fun = fun.errors_boxed();
+    assert_eq!(Some(&1), fun.call(0).ok());
+    assert_eq!("out of bounds", fun.call(3).unwrap_err().to_string());
+
+    let fun: ClosureResRefOneOf2<Vec<i32>, (), usize, i32, String> = Capture(vec![1])
+        .fun_result_ref(|v, i: usize| v.get(i).ok_or(format!("missing {}", i)))
+        .into_oneof2_var1();
+    let fun = fun.errors_boxed();
+    assert_eq!("missing 2", fun.call(2).unwrap_err().to_string());
+}
@@ -0,0 +1,102 @@
+use orx_closure::*;
+
+type Jagged = Vec<Vec<i32>>;
+type Flat = (usize, Vec<i32>);
+
+fn jagged() -> Jagged {
+    vec![vec![0, 1, 2], vec![3, 4, 5]]
+}
+
+fn flat() -> Flat {
+    (3, vec![0, 1, 2, 3, 4, 5])
+}
+
+#[test]
+fn eval_grid_closure() {
+    let fun = Capture(jagged()).fun(|x, (i, j): (usize, usize)| x[i][j]);
+    assert_eq!(vec![0, 1, 2, 3, 4, 5], fun.eval_grid(2, 3));
+    assert_eq!(vec![0, 3], fun.eval_grid(2, 1));
+    assert!(fun.eval_grid(2, 0).is_empty());
+    assert_eq!(
+        fun.eval_grid(2, 3),
+        fun.eval_grid_iter(2, 3).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn eval_grid_oneof2() {
+    type Weight = ClosureOneOf2<Jagged, Flat, (usize, usize), i32>;
+    let a: Weight = Capture(jagged())
+        .fun(|x, (i, j): (usize, usize)| x[i][j])
+        .into_oneof2_var1();
+    let b: Weight = Capture(flat())
+        .fun(|(n, x), (i, j): (usize, usize)| x[i * n + j])
+        .into_oneof2_var2();
+
+    assert_eq!(a.eval_grid(2, 3), b.eval_grid(2, 3));
+    assert_eq!(
+        a.eval_grid_iter(2, 2).collect::<Vec<_>>(),
+        b.eval_grid_iter(2, 2).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn eval_grid_oneof3_and_oneof4() {
+    let c: ClosureOneOf3<Jagged, Flat, (), (usize, usize), i32> = Capture(())
+        .fun(|_, (i, j): (usize, usize)| (i + j) as i32)
+        .into_oneof3_var3();
+    assert_eq!(vec![0, 1, 1, 2], c.eval_grid(2, 2));
+
+    let d: ClosureOneOf4<Jagged, Flat, (), i32, (usize, usize), i32> =
+        Capture(7).fun(|x, _: (usize, usize)| *x).into_oneof4_var4();
+    assert_eq!(vec![7; 6], d.eval_grid(3, 2));
+    assert_eq!(42, d.eval_grid_iter(3, 2).sum::<i32>());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_eval_grid() {
+    let fun = Capture(jagged()).fun(|x, (i, j): (usize, usize)| x[i][j]);
+    assert_eq!(fun.eval_grid(2, 3), fun.par_eval_grid(2, 3));
+    assert!(fun.par_eval_grid(0, 0).is_empty());
+
+    let b: ClosureOneOf2<Jagged, Flat, (usize, usize), i32> = Capture(flat())
+        .fun(|(n, x), (i, j): (usize, usize)| x[i * n + j])
+        .into_oneof2_var2();
+    assert_eq!(b.eval_grid(2, 3), b.par_eval_grid(2, 3));
+}
+
+#[test]
+fn eval_grid_iter_unions_internal_iteration() {
+    let weight: ClosureOneOf3<(), Jagged, usize, (usize, usize), i32> = Capture(jagged())
+        .fun(|x, (i, j): (usize, usize)| x[i][j])
+        .into_oneof3_var2();
+    let expected: Vec<_> = weight.eval_grid(2, 2);
+
+    let mut visited = vec![];
+    weight.eval_grid_iter(2, 2).for_each(|x| visited.push(x));
+    assert_eq!(expected, visited);
+    assert_eq!(6, weight.eval_grid_iter(2, 3).count());
+}
+
+#[test]
+fn one_of_iterators() {
+    let iter: OneOf4<
+        std::ops::Range<i32>,
+        std::vec::IntoIter<i32>,
+        std::iter::Empty<i32>,
+        std::option::IntoIter<i32>,
+    > = OneOf4::Variant2(vec![3, 4].into_iter());
+    assert_eq!(vec![3, 4], iter.collect::<Vec<_>>());
+
+    let mut iter: OneOf2<std::ops::Range<i32>, std::iter::Once<i32>> = OneOf2::Variant1(0..3);
+    assert_eq!(Some(0), iter.next());
+    assert_eq!(3, iter.sum::<i32>());
+}
+
+#[test]
+#[should_panic(expected = "overflows usize")]
+fn eval_grid_overflowing_size_panics() {
+    let fun = Capture(()).fun(|_, (i, j): (usize, usize)| i + j);
+    let _ = fun.eval_grid(usize::MAX, 2);
+}
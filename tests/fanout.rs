@@ -0,0 +1,52 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Edge = (usize, usize);
+
+#[test]
+fn fanout_weight_and_capacity() {
+    let weights = HashMap::from([((0, 1), 3), ((1, 2), 5)]);
+    let weight = Capture(weights).fun(|w, e: Edge| w.get(&e).copied().unwrap_or(0));
+    let capacity = Capture(vec![7, 8, 9]).fun(|c, (_, j): Edge| c[j]);
+
+    let edge = weight.fanout(capacity);
+    assert_eq!((3, 8), edge.call((0, 1)));
+    assert_eq!((5, 9), edge.call((1, 2)));
+    assert_eq!((0, 7), edge.call((2, 0)));
+
+    let total: (i32, i32) = [(0, 1), (1, 2)]
+        .into_iter()
+        .map(edge.as_fn())
+        .fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+    assert_eq!((8, 17), total);
+}
+
+#[test]
+fn fanout_nested_and_captures() {
+    let a = Capture(1).fun(|k, x: i32| x + k);
+    let b = Capture(2).fun(|k, x: i32| x * k);
+    let c = Capture("x").fun(|s, x: i32| format!("{}{}", s, x));
+
+    let all = a.fanout(b).fanout(c);
+    assert_eq!(((4, 6), String::from("x3")), all.call(3));
+
+    let cloned = all.clone();
+    assert_eq!(((1, 0), String::from("x0")), cloned.call(0));
+
+    let (ab, c) = all.into_captured_data();
+    assert_eq!("x", c.into_captured_data());
+    let (a, b) = ab.into_captured_data();
+    assert_eq!((1, 2), (a.into_captured_data(), b.into_captured_data()));
+}
+
+#[test]
+fn fanout_equals_zip_with_tuple() {
+    let first = || Capture(vec![1, 2]).fun(|v, i: usize| v[i]);
+    let second = || Capture(vec!['a', 'b']).fun(|v, i: usize| v[i]);
+
+    let fanout = first().fanout(second());
+    let zipped = first().zip_with(second(), |a, b| (a, b));
+    for i in 0..2 {
+        assert_eq!(zipped.call(i), fanout.call(i));
+    }
+}
@@ -0,0 +1,31 @@
+use orx_closure::features;
+
+#[test]
+fn feature_flags_match_build() {
+    assert_eq!(cfg!(feature = "either"), features::EITHER);
+    assert_eq!(cfg!(feature = "mmap"), features::MMAP);
+    assert_eq!(cfg!(feature = "petgraph"), features::PETGRAPH);
+    assert_eq!(cfg!(feature = "rayon"), features::RAYON);
+    assert_eq!(cfg!(feature = "serde"), features::SERDE);
+    assert_eq!(cfg!(feature = "testing"), features::TESTING);
+}
+
+#[test]
+fn feature_flags_all() {
+    let names: Vec<_> = features::ALL.iter().map(|x| x.0).collect();
+    assert_eq!(
+        vec!["either", "mmap", "petgraph", "rayon", "serde", "testing"],
+        names
+    );
+
+    for (name, enabled) in features::ALL {
+        match name {
+            "either" => assert_eq!(features::EITHER, enabled),
+            "mmap" => assert_eq!(features::MMAP, enabled),
+            "petgraph" => assert_eq!(features::PETGRAPH, enabled),
+            "rayon" => assert_eq!(features::RAYON, enabled),
+            "serde" => assert_eq!(features::SERDE, enabled),
+            _ => assert_eq!(features::TESTING, enabled),
+        }
+    }
+}
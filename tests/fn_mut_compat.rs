@@ -0,0 +1,104 @@
+//! `as_fn` returns an `impl Fn`, which is also an `FnMut` and an `FnOnce`.
+//! Therefore, it can directly be passed to std and ecosystem APIs requiring `FnMut` callbacks without `|x| c.call(x)` shims.
+
+use orx_closure::*;
+use std::collections::HashMap;
+
+fn names() -> Vec<String> {
+    vec![
+        String::from("john"),
+        String::from("doe"),
+        String::from("jane"),
+    ]
+}
+
+#[test]
+fn iterator_adapters() {
+    let len = Capture(names()).fun(|n, i: usize| n[i].len());
+    let name = Capture(names()).fun_ref(|n, i: usize| n[i].as_str());
+    let find = Capture(names()).fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()));
+
+    assert_eq!(vec![4, 3, 4], (0..3).map(len.as_fn()).collect::<Vec<_>>());
+    assert_eq!("johndoejane", (0..3).map(name.as_fn()).collect::<String>());
+    assert_eq!(
+        vec!["doe"],
+        [1, 7]
+            .into_iter()
+            .filter_map(find.as_fn())
+            .collect::<Vec<_>>()
+    );
+
+    // the closures are only borrowed; they can be used again
+    assert_eq!(3, len.call(1));
+    assert_eq!("jane", name.call(2));
+}
+
+#[test]
+fn slice_and_vec_methods_with_reference_inputs() {
+    let priorities = HashMap::from([("low", 2), ("high", 0), ("mid", 1)]);
+    let priority = Capture(priorities).fun(|p, x: &str| p[x]);
+
+    // `as_fn` is not higher-ranked over the lifetime of its input, while methods such as
+    // `sort_by_key` or `retain` require `for<'a> FnMut(&'a T)`; map over owned or copied items instead
+    let levels = ["low", "high", "mid"];
+    let keys: Vec<_> = levels.iter().copied().map(priority.as_fn()).collect();
+    assert_eq!(vec![2, 0, 1], keys);
+
+    // or pass a thin shim where the consumer itself hands out short-lived references
+    let mut levels = levels.to_vec();
+    levels.sort_by_key(|x| priority.call(x));
+    assert_eq!(vec!["high", "mid", "low"], levels);
+}
+
+#[test]
+fn unions_as_fn_mut() {
+    type Weight = ClosureOneOf2<Vec<i32>, i32, usize, i32>;
+
+    let from_vec: Weight = Capture(vec![3, 1, 2])
+        .fun(|v, i: usize| v[i])
+        .into_oneof2_var1();
+    let constant: Weight = Capture(5).fun(|c, _: usize| *c).into_oneof2_var2();
+
+    for (weight, expected) in [(from_vec, 6), (constant, 15)] {
+        assert_eq!(expected, (0..3).map(weight.as_fn()).sum::<i32>());
+    }
+
+    let name: ClosureRefOneOf2<Vec<String>, String, usize, str> = Capture(names())
+        .fun_ref(|n, i: usize| n[i].as_str())
+        .into_oneof2_var1();
+    let mut sorted: Vec<_> = (0..3).map(name.as_fn()).collect();
+    sorted.sort();
+    assert_eq!(vec!["doe", "jane", "john"], sorted);
+}
+
+#[test]
+fn reborrow_bound_fn_across_consumers() {
+    let len = Capture(names()).fun(|n, i: usize| n[i].len());
+
+    // binding once and passing `&mut f` avoids moving the adapter into the first consumer
+    let mut f = len.as_fn();
+    let first: Vec<_> = (0..2).map(&mut f).collect();
+    let second: Vec<_> = (1..3).map(&mut f).collect();
+    assert_eq!(vec![4, 3], first);
+    assert_eq!(vec![3, 4], second);
+
+    // since the adapter is `Fn`, a shared reference works as well, and the adapter is `Copy`
+    let g = len.as_fn();
+    let total: usize = (0..3).map(&g).sum::<usize>() + (0..3).map(g).sum::<usize>();
+    assert_eq!(22, total);
+}
+
+#[test]
+fn generic_fn_mut_consumer() {
+    fn apply_all<F: FnMut(usize) -> usize>(f: F, n: usize) -> Vec<usize> {
+        (0..n).map(f).collect()
+    }
+
+    let len = Capture(names()).fun(|n, i: usize| n[i].len());
+    let chain = Capture(names())
+        .fun(|n, i: usize| n[i].len())
+        .then(Capture(10).fun(|k, x: usize| k * x));
+
+    assert_eq!(vec![4, 3], apply_all(len.as_fn(), 2));
+    assert_eq!(vec![40, 30], apply_all(chain.as_fn(), 2));
+}
@@ -0,0 +1,134 @@
+use orx_closure::*;
+use std::{collections::HashMap, fmt::Debug};
+
+#[derive(Default)]
+struct Persist(Vec<String>);
+
+impl<C: Debug> CaptureVisitor<C> for Persist {
+    type Output = ();
+    fn visit(&mut self, capture: &C) {
+        self.0.push(format!("{:?}", capture));
+    }
+}
+
+struct Validate;
+
+impl CaptureVisitor<f64> for Validate {
+    type Output = Result<(), String>;
+    fn visit(&mut self, capture: &f64) -> Self::Output {
+        match capture.is_finite() {
+            true => Ok(()),
+            false => Err(format!("non-finite constant {}", capture)),
+        }
+    }
+}
+
+impl CaptureVisitor<Vec<f64>> for Validate {
+    type Output = Result<(), String>;
+    fn visit(&mut self, capture: &Vec<f64>) -> Self::Output {
+        match capture.iter().all(|x| *x >= 0.0) {
+            true => Ok(()),
+            false => Err(String::from("negative cost")),
+        }
+    }
+}
+
+impl CaptureVisitor<HashMap<usize, f64>> for Validate {
+    type Output = Result<(), String>;
+    fn visit(&mut self, capture: &HashMap<usize, f64>) -> Self::Output {
+        match capture.is_empty() {
+            true => Err(String::from("empty lookup")),
+            false => Ok(()),
+        }
+    }
+}
+
+type Cost = ClosureOneOf3<f64, Vec<f64>, HashMap<usize, f64>, usize, f64>;
+
+fn costs(constant: f64, per_index: Vec<f64>, lookup: HashMap<usize, f64>) -> Vec<Cost> {
+    vec![
+        Capture(constant).fun(|c, _: usize| *c).into_oneof3_var1(),
+        Capture(per_index)
+            .fun(|c, i: usize| c[i])
+            .into_oneof3_var2(),
+        Capture(lookup)
+            .fun(|c, i: usize| c.get(&i).copied().unwrap_or(0.0))
+            .into_oneof3_var3(),
+    ]
+}
+
+#[test]
+fn for_each_capture_generic_visitor() {
+    let costs = costs(1.5, vec![1.0, 2.0], HashMap::from_iter([(3, 4.0)]));
+
+    let mut persist = Persist::default();
+    for cost in &costs {
+        cost.for_each_capture(&mut persist);
+    }
+    assert_eq!(vec!["1.5", "[1.0, 2.0]", "{3: 4.0}"], persist.0);
+}
+
+#[test]
+fn for_each_capture_visitor_per_capture_type() {
+    let valid = costs(1.5, vec![1.0, 2.0], HashMap::from_iter([(3, 4.0)]));
+    let errors: Vec<_> = valid
+        .iter()
+        .filter_map(|c| c.for_each_capture(&mut Validate).err())
+        .collect();
+    assert!(errors.is_empty());
+
+    let invalid = costs(f64::NAN, vec![1.0, -2.0], HashMap::new());
+    let errors: Vec<_> = invalid
+        .iter()
+        .filter_map(|c| c.for_each_capture(&mut Validate).err())
+        .collect();
+    assert_eq!(
+        vec!["non-finite constant NaN", "negative cost", "empty lookup"],
+        errors
+    );
+}
+
+#[test]
+fn for_each_capture_ref_kinds() {
+    let mut persist = Persist::default();
+
+    let name: ClosureRefOneOf2<String, Vec<String>, usize, str> = Capture(String::from("x"))
+        .fun_ref(|s, _: usize| s.as_str())
+        .into_oneof2_var1();
+    name.for_each_capture(&mut persist);
+
+    let get: ClosureOptRefOneOf4<(), Vec<i32>, char, bool, usize, i32> = Capture(vec![1, 2])
+        .fun_option_ref(|v, i: usize| v.get(i))
+        .into_oneof4_var2();
+    get.for_each_capture(&mut persist);
+
+    let try_get: ClosureResRefOneOf2<Vec<i32>, char, usize, i32, &'static str> = Capture('a')
+        .fun_result_ref(|_, _: usize| Err("no value"))
+        .into_oneof2_var2();
+    try_get.for_each_capture(&mut persist);
+
+    assert_eq!(vec!["\"x\"", "[1, 2]", "'a'"], persist.0);
+}
+
+#[test]
+fn for_each_capture_mut_and_once() {
+    let mut persist = Persist::default();
+
+    let mut history: ClosureMutOneOf2<usize, Vec<i32>, i32, usize> = Capture(vec![])
+        .fun_mut(|h, x: i32| {
+            h.push(x);
+            h.len()
+        })
+        .into_oneof2_var2();
+    history.call_mut(3);
+    history.call_mut(4);
+    history.for_each_capture(&mut persist);
+
+    let release: ClosureOnceOneOf2<Vec<i32>, String, (), usize> = Capture(String::from("ab"))
+        .fun_once(|s, _: ()| s.len())
+        .into_oneof2_var2();
+    release.for_each_capture(&mut persist);
+    assert_eq!(2, release.call_once(()));
+
+    assert_eq!(vec!["[3, 4]", "\"ab\""], persist.0);
+}
@@ -0,0 +1,71 @@
+use orx_closure::formatters::*;
+use orx_closure::*;
+use std::borrow::Cow;
+
+#[test]
+fn template_rendering() {
+    let fmt = template("({}, {})");
+    assert_eq!("(1, 2)", fmt.call(vec![1, 2]));
+    assert_eq!("(1, {})", fmt.call(vec![1]));
+    assert_eq!("(1, 2)", fmt.call(vec![1, 2, 3]));
+    assert_eq!("({}, {})", fmt.call(vec![]));
+
+    let fmt = template("no placeholders");
+    assert_eq!("no placeholders", fmt.call(["x"]));
+
+    let fmt = template("{}{}");
+    assert_eq!("ab", fmt.call(["a", "b"]));
+}
+
+#[test]
+fn owned_and_borrowed_templates() {
+    let prefix = "node";
+    let owned: Formatter<Vec<String>> = template(format!("{}-{{}}", prefix));
+    assert_eq!("node-x", owned.call(vec!["x".to_string()]));
+    assert!(matches!(owned.captured_data(), Cow::Owned(_)));
+
+    let borrowed: Formatter<Vec<String>> = template("edge-{}");
+    assert!(matches!(borrowed.captured_data(), Cow::Borrowed(_)));
+}
+
+#[test]
+fn constant_and_template_share_type() {
+    struct Labels {
+        node: Formatter<[usize; 1]>,
+    }
+
+    let verbose = Labels {
+        node: template("node {}"),
+    };
+    let quiet = Labels {
+        node: constant("node"),
+    };
+
+    assert_eq!("node 4", verbose.node.call([4]));
+    assert_eq!("node", quiet.node.call([4]));
+}
+
+#[test]
+fn formatter_in_union() {
+    type Label = ClosureOneOf2<Template, Vec<String>, Vec<usize>, String>;
+
+    let from_template: Label = template("#{}").into_oneof2_var1();
+    let from_names: Label = Capture(vec!["a".to_string(), "b".to_string()])
+        .fun(|names, ids: Vec<usize>| {
+            let names: Vec<_> = ids.iter().map(|i| names[*i].as_str()).collect();
+            names.join(",")
+        })
+        .into_oneof2_var2();
+
+    assert_eq!("#1", from_template.call(vec![1]));
+    assert_eq!("b,a", from_names.call(vec![1, 0]));
+}
+
+#[test]
+fn segments_of_template() {
+    let segment = segments("a{}b{}");
+    assert_eq!(Some("a"), segment.call(0));
+    assert_eq!(Some("b"), segment.call(1));
+    assert_eq!(Some(""), segment.call(2));
+    assert_eq!(None, segment.call(3));
+}
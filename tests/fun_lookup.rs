@@ -0,0 +1,78 @@
+use orx_closure::*;
+use std::collections::{BTreeMap, HashMap};
+
+fn validate_lookup<L: FunLookup<usize, str>>(lookup: L) {
+    assert_eq!(Some("john"), lookup.get(0));
+    assert_eq!(Some("doe"), lookup.get(1));
+    assert_eq!(None, lookup.get(2));
+
+    assert!(lookup.contains(0));
+    assert!(lookup.contains(1));
+    assert!(!lookup.contains(2));
+
+    assert_eq!(None, lookup.len_hint());
+}
+
+#[test]
+fn lookup_opt_ref() {
+    let fun = Capture(vec!["john".to_string(), "doe".to_string()])
+        .fun_option_ref(|x, i: usize| x.get(i).map(|x| x.as_str()));
+    validate_lookup(fun);
+
+    let map: HashMap<usize, String> =
+        HashMap::from_iter([(0usize, "john".to_string()), (1, "doe".to_string())]);
+    let fun = Capture(map).fun_option_ref(|x, i: usize| x.get(&i).map(|x| x.as_str()));
+    validate_lookup(fun);
+}
+
+#[test]
+fn lookup_closure_capturing_by_ref() {
+    let names = vec!["john".to_string(), "doe".to_string()];
+    let fun = Capture(&names).fun(|x, i: usize| x.get(i).map(|x| x.as_str()));
+    validate_lookup(fun);
+}
+
+#[test]
+fn lookup_opt_ref_union() {
+    let vec = vec!["john".to_string(), "doe".to_string()];
+    let map = BTreeMap::from_iter([(0usize, "john".to_string()), (1, "doe".to_string())]);
+
+    type Union = ClosureOptRefOneOf2<Vec<String>, BTreeMap<usize, String>, usize, str>;
+
+    let fun: Union = Capture(vec)
+        .fun_option_ref(|x, i: usize| x.get(i).map(|x| x.as_str()))
+        .into_oneof2_var1();
+    validate_lookup(fun);
+
+    let fun: Union = Capture(map)
+        .fun_option_ref(|x, i: usize| x.get(&i).map(|x| x.as_str()))
+        .into_oneof2_var2();
+    validate_lookup(fun);
+}
+
+#[test]
+fn lookup_as_dyn() {
+    let names = vec!["john".to_string(), "doe".to_string()];
+    let fun = Capture(names).fun_option_ref(|x, i: usize| x.get(i).map(|x| x.as_str()));
+    let lookup: &dyn FunLookup<usize, str> = &fun;
+    assert!(lookup.contains(1));
+    assert_eq!(Some("doe"), lookup.get(1));
+}
+
+#[test]
+fn lookup_collections_know_their_len() {
+    let names = vec!["john".to_string(), "doe".to_string(), "jane".to_string()];
+    let lookup = names.as_slice();
+    assert_eq!(Some(3), lookup.len_hint());
+    assert_eq!(Some(&"doe".to_string()), FunLookup::get(lookup, 1));
+    assert!(!FunLookup::contains(lookup, 3));
+
+    let map: HashMap<usize, &str> = HashMap::from_iter([(0, "john"), (7, "doe")]);
+    assert_eq!(Some(2), map.len_hint());
+    assert_eq!(Some(&"doe"), FunLookup::get(&map, 7));
+    assert!(!FunLookup::contains(&map, 1));
+
+    let map: BTreeMap<usize, &str> = BTreeMap::from_iter([(3, "jane")]);
+    assert_eq!(Some(1), map.len_hint());
+    assert!(FunLookup::contains(&map, 3));
+}
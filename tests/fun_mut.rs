@@ -0,0 +1,55 @@
+use orx_closure::*;
+
+fn drain<F: FunMut<(), Option<i32>>>(fun: &mut F) -> Vec<i32> {
+    let mut items = vec![];
+    while let Some(x) = fun.call_mut(()) {
+        items.push(x);
+    }
+    items
+}
+
+#[test]
+fn fun_mut_for_std_fn_mut() {
+    let mut stack = vec![1, 2, 3];
+    let mut pop = |_: ()| stack.pop();
+    assert_eq!(vec![3, 2, 1], drain(&mut pop));
+    assert!(stack.is_empty());
+}
+
+#[test]
+fn fun_mut_for_std_fn() {
+    let mut constant = |_: ()| None;
+    assert!(drain(&mut constant).is_empty());
+}
+
+#[test]
+fn fun_mut_for_fn_pointer() {
+    fn never(_: ()) -> Option<i32> {
+        None
+    }
+    let mut fun = never;
+    assert!(drain(&mut fun).is_empty());
+}
+
+#[test]
+fn fun_mut_for_closure_mut_and_std_closure_alike() {
+    let mut closure = Capture(vec![4, 5]).fun_mut(|s, _: ()| s.pop());
+    let mut items = vec![4, 5];
+    let mut std_closure = move |_: ()| items.pop();
+
+    assert_eq!(drain(&mut closure), drain(&mut std_closure));
+}
+
+#[test]
+fn fun_mut_boxed_std_closures() {
+    let mut count = 0;
+    let mut funs: Vec<Box<dyn FnMut(()) -> Option<i32>>> = vec![
+        Box::new(|_| None),
+        Box::new(move |_| {
+            count += 1;
+            (count < 3).then_some(count)
+        }),
+    ];
+    let items: Vec<_> = funs.iter_mut().map(drain).collect();
+    assert_eq!(vec![vec![], vec![1, 2]], items);
+}
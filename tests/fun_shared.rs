@@ -0,0 +1,62 @@
+use orx_closure::*;
+use std::{collections::HashMap, rc::Rc, sync::Arc, thread};
+
+fn names() -> Vec<String> {
+    vec!["john".to_string(), "doe".to_string()]
+}
+
+#[test]
+fn rc_fun_ref() {
+    let fun = Rc::new(Capture(names()).fun_ref(|x, i: usize| x[i].as_str()));
+    let clone = fun.clone();
+    assert_eq!("john", FunRef::call(&fun, 0));
+    assert_eq!("doe", FunRef::call(&clone, 1));
+
+    let dyn_fun: Rc<dyn FunRef<usize, str>> = fun;
+    assert_eq!("doe", dyn_fun.call(1));
+}
+
+#[test]
+fn arc_fun_opt_ref() {
+    let fun: Arc<dyn FunOptRef<usize, str> + Send + Sync> =
+        Arc::new(Capture(names()).fun_option_ref(|x, i: usize| x.get(i).map(|x| x.as_str())));
+
+    let clone = fun.clone();
+    let handle = thread::spawn(move || clone.call(1).map(|x| x.to_string()));
+
+    assert_eq!(Some("john"), fun.call(0));
+    assert_eq!(None, fun.call(2));
+    assert_eq!(Some("doe".to_string()), handle.join().ok().flatten());
+}
+
+#[test]
+fn rc_fun_res_ref() {
+    let map: HashMap<usize, String> = HashMap::from_iter([(0, "john".to_string())]);
+    let fun = Capture(map).fun_result_ref(|x, i: usize| x.get(&i).map(|x| x.as_str()).ok_or(i));
+    let shared: Rc<dyn FunResRef<usize, str, usize>> = Rc::new(fun);
+
+    assert_eq!(Ok("john"), shared.call(0));
+    assert_eq!(Err(7), shared.call(7));
+}
+
+#[test]
+fn shared_union_stored_in_container() {
+    type Union = ClosureRefOneOf2<Vec<String>, HashMap<usize, String>, usize, str>;
+
+    let map: HashMap<usize, String> = HashMap::from_iter([(0, "jane".to_string())]);
+    let funs: Vec<Rc<Union>> = vec![
+        Rc::new(
+            Capture(names())
+                .fun_ref(|x, i: usize| x[i].as_str())
+                .into_oneof2_var1(),
+        ),
+        Rc::new(
+            Capture(map)
+                .fun_ref(|x, i: usize| x[&i].as_str())
+                .into_oneof2_var2(),
+        ),
+    ];
+
+    let first: Vec<&str> = funs.iter().map(|f| FunRef::call(f, 0)).collect();
+    assert_eq!(vec!["john", "jane"], first);
+}
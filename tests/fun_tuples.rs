@@ -0,0 +1,58 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+fn sum_of_outputs<F: Fun<usize, (i32, i32)>>(fun: F, inputs: &[usize]) -> (i32, i32) {
+    inputs.iter().fold((0, 0), |(a, b), i| {
+        let (x, y) = fun.call(*i);
+        (a + x, b + y)
+    })
+}
+
+#[test]
+fn pair_of_closures() {
+    let weights = vec![1, 2, 3];
+    let capacities: HashMap<usize, i32> = HashMap::from_iter([(0, 10), (2, 30)]);
+
+    let weight = Capture(weights).fun(|x, i: usize| x[i]);
+    let capacity = Capture(capacities).fun(|x, i: usize| *x.get(&i).unwrap_or(&0));
+
+    assert_eq!((6, 40), sum_of_outputs((weight, capacity), &[0, 1, 2]));
+}
+
+#[test]
+fn pair_mixing_closures_and_fns() {
+    let weight = Capture(vec![1, 2, 3]).fun(|x, i: usize| x[i]);
+    let fun = (weight, |i: usize| i as i32 * 100);
+    assert_eq!((3, 100), sum_of_outputs(fun, &[0, 1]));
+}
+
+#[test]
+fn pair_of_unions() {
+    type Union = ClosureOneOf2<i32, Vec<i32>, usize, i32>;
+    let constant: Union = Capture(7).fun(|x, _: usize| *x).into_oneof2_var1();
+    let from_vec: Union = Capture(vec![1, 2])
+        .fun(|x, i: usize| x[i])
+        .into_oneof2_var2();
+    assert_eq!((14, 3), sum_of_outputs((constant, from_vec), &[0, 1]));
+}
+
+#[test]
+fn triple_and_quadruple() {
+    let a = Capture(1).fun(|x, i: usize| x + i);
+    let b = Capture("abc".to_string()).fun(|x, i: usize| x.chars().nth(i));
+    let c = Capture(vec![true, false]).fun(|x, i: usize| x.get(i).copied());
+
+    let fun = (a.clone(), b.clone(), c.clone());
+    assert_eq!((2, Some('b'), Some(false)), fun.call(1));
+
+    let fun = (a, b, c, |i: usize| i * 2);
+    assert_eq!((3, Some('c'), None, 4), fun.call(2));
+}
+
+#[test]
+fn tuple_as_dyn_fun() {
+    let a = Capture(2).fun(|x, i: i32| x * i);
+    let b = Capture(3).fun(|x, i: i32| x + i);
+    let fun: Box<dyn Fun<i32, (i32, i32)>> = Box::new((a, b));
+    assert_eq!((20, 13), fun.call(10));
+}
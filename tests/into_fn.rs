@@ -0,0 +1,50 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Weights = ClosureOneOf2<HashMap<(usize, usize), u32>, u32, (usize, usize), u32>;
+
+fn weights(sparse: Option<HashMap<(usize, usize), u32>>) -> Box<dyn Fn((usize, usize)) -> u32> {
+    let closure: Weights = match sparse {
+        Some(map) => Capture(map)
+            .fun(|m, ij: (usize, usize)| m.get(&ij).copied().unwrap_or(0))
+            .into_oneof2_var1(),
+        None => Capture(1).fun(|w, _: (usize, usize)| *w).into_oneof2_var2(),
+    };
+    Box::new(closure.into_fn())
+}
+
+fn assert_static<T: 'static>(_: &T) {}
+
+#[test]
+fn into_fn_closure() {
+    let names = vec![String::from("john"), String::from("doe")];
+    let len_of = Capture(names).fun(|n, i: usize| n[i].len()).into_fn();
+    assert_static(&len_of);
+
+    let lengths: Vec<_> = (0..2).map(&len_of).collect();
+    assert_eq!(vec![4, 3], lengths);
+    assert_eq!(4, len_of(0));
+}
+
+#[test]
+fn into_fn_union_returned_from_fn() {
+    let uniform = weights(None);
+    assert_eq!(1, uniform((3, 4)));
+
+    let sparse = weights(Some(HashMap::from([((0, 1), 7)])));
+    assert_eq!(7, sparse((0, 1)));
+    assert_eq!(0, sparse((1, 0)));
+}
+
+#[test]
+fn into_fn_union_arity_4() {
+    type Union = ClosureOneOf4<i32, Vec<i32>, (), String, usize, i32>;
+    let closure: Union = Capture(vec![3, 2, 1])
+        .fun(|v, i: usize| v[i])
+        .into_oneof4_var2();
+
+    let fun = closure.into_fn();
+    let mut indices = vec![0, 1, 2];
+    indices.sort_by_key(|i| fun(*i));
+    assert_eq!(vec![2, 1, 0], indices);
+}
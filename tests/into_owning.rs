@@ -0,0 +1,63 @@
+use orx_closure::*;
+use std::thread;
+
+type Edge = (usize, usize);
+
+fn weights() -> Vec<Vec<i32>> {
+    vec![vec![0, 4, 7], vec![4, 0, 2], vec![7, 2, 0]]
+}
+
+fn prototype(weights: &Vec<Vec<i32>>) -> Closure<&Vec<Vec<i32>>, Edge, i32> {
+    Capture(weights).fun(|w, (i, j): Edge| w[i][j])
+}
+
+#[test]
+fn into_owning_outlives_borrowed_data() {
+    let owned = {
+        let weights = weights();
+        let borrowing = prototype(&weights);
+        assert_eq!(2, borrowing.call((1, 2)));
+        borrowing.into_owning(|w, (i, j)| w[i][j])
+    };
+    assert_eq!(2, owned.call((1, 2)));
+    assert_eq!(&weights(), owned.captured_data());
+}
+
+#[test]
+fn into_owning_can_be_sent() {
+    let weights = weights();
+    let owned = prototype(&weights).into_owning(|w, (i, j)| w[i][j]);
+    let handle = thread::spawn(move || owned.call((0, 2)));
+    assert_eq!(Some(7), handle.join().ok());
+}
+
+#[test]
+fn to_owned_capture_keeps_borrowing_closure() {
+    let weights = weights();
+    let borrowing = prototype(&weights);
+    let doubled = borrowing.to_owned_capture(|w, (i, j)| 2 * w[i][j]);
+
+    assert_eq!(4, borrowing.call((0, 1)));
+    assert_eq!(8, doubled.call((0, 1)));
+}
+
+#[test]
+fn into_owning_ref_kinds() {
+    let names = vec![String::from("john"), String::from("doe")];
+
+    let by_ref = Capture(&names)
+        .fun_ref(|n, i: usize| n[i].as_str())
+        .into_owning(|n, i| n[i].as_str());
+    let by_opt = Capture(&names)
+        .fun_option_ref(|n, i: usize| n.get(i).map(|x| x.as_str()))
+        .into_owning(|n, i| n.get(i).map(|x| x.as_str()));
+    let by_res = Capture(&names)
+        .fun_result_ref(|n, i: usize| n.get(i).map(|x| x.as_str()).ok_or(i))
+        .into_owning(|n, i| n.get(i).map(|x| x.as_str()).ok_or(i));
+    drop(names);
+
+    assert_eq!("john", by_ref.call(0));
+    assert_eq!(None, by_opt.call(5));
+    assert_eq!(Err(5), by_res.call(5));
+    assert_eq!(Ok("doe"), by_res.call(1));
+}
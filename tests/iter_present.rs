@@ -0,0 +1,37 @@
+use orx_closure::*;
+use std::collections::BTreeMap;
+
+struct Sparse {
+    rows: BTreeMap<usize, Vec<f64>>,
+}
+
+#[test]
+fn iter_present_skips_missing_entries() {
+    let sparse = Sparse {
+        rows: BTreeMap::from_iter([(2, vec![1.0, 2.0]), (5, vec![3.0])]),
+    };
+    let row = Capture(sparse).fun_option_ref(|s, i: usize| s.rows.get(&i).map(|r| r.as_slice()));
+
+    let present: Vec<_> = row.iter_present(0..10).collect();
+    assert_eq!(vec![(2, &[1.0, 2.0][..]), (5, &[3.0][..])], present);
+
+    let total: f64 = row
+        .iter_present([5, 5, 7])
+        .map(|(_, r)| r.iter().sum::<f64>())
+        .sum();
+    assert_eq!(6.0, total);
+
+    assert_eq!(0, row.iter_present(Vec::<usize>::new()).count());
+}
+
+#[test]
+fn iter_present_over_borrowed_inputs() {
+    let names = [String::from("john"), String::from("doe")];
+    let ages = Capture(vec![("john", 42)])
+        .fun_option_ref(|a, name: &str| a.iter().find(|x| x.0 == name).map(|x| &x.1));
+
+    let known: Vec<_> = ages
+        .iter_present(names.iter().map(|x| x.as_str()))
+        .collect();
+    assert_eq!(vec![("john", &42)], known);
+}
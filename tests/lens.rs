@@ -0,0 +1,54 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Config = HashMap<&'static str, String>;
+
+fn config_lens(config: Config) -> Lens<Config, &'static str, String> {
+    Capture(config).lens(
+        |c, key: &'static str| &c[key],
+        |c, key, value| {
+            c.insert(key, value);
+        },
+    )
+}
+
+#[test]
+fn lens_get_set() {
+    let mut lens = config_lens(HashMap::from([("host", String::from("localhost"))]));
+    assert_eq!("localhost", lens.get("host"));
+
+    lens.set("host", String::from("example.com"));
+    lens.set("port", String::from("80"));
+
+    assert_eq!("example.com", lens.get("host"));
+    assert_eq!("80", lens.get("port"));
+    assert_eq!(2, lens.captured_data().len());
+}
+
+#[test]
+fn lens_update() {
+    let mut lens = config_lens(HashMap::from([("path", String::from("/usr"))]));
+    lens.update("path", |p| format!("{}/bin", p));
+    assert_eq!("/usr/bin", lens.get("path"));
+
+    let config = lens.into_captured_data();
+    assert_eq!("/usr/bin", config["path"]);
+}
+
+#[test]
+fn lens_over_tuple_positions() {
+    let mut cell = Capture(vec![vec![0; 3]; 2]).lens(
+        |m, (i, j): (usize, usize)| &m[i][j],
+        |m, (i, j), value| m[i][j] = value,
+    );
+
+    for i in 0..2 {
+        for j in 0..3 {
+            cell.set((i, j), i * 3 + j);
+        }
+    }
+    cell.update((1, 2), |x| x * 10);
+
+    assert_eq!(&4, cell.get((1, 1)));
+    assert_eq!(&50, cell.get((1, 2)));
+}
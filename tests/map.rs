@@ -0,0 +1,67 @@
+use orx_closure::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[test]
+fn map_converts_output() {
+    let celsius = Capture(vec![0.0, 100.0]).fun(|t, i: usize| t[i]);
+    let fahrenheit = celsius.map(|c| c * 1.8 + 32.0);
+
+    assert_eq!(32.0, fahrenheit.call(0));
+    assert_eq!(212.0, fahrenheit.call(1));
+
+    let label = fahrenheit.map(|f| format!("{}F", f));
+    assert_eq!("212F", label.call(1));
+}
+
+#[test]
+fn map_keeps_capture_and_clone() {
+    let counts = Capture(vec![3, 5]).fun(|c, i: usize| c[i]).map(|c| c * 2);
+    let cloned = counts.clone();
+    assert_eq!(10, cloned.call(1));
+
+    let (inner, _) = counts.into_captured_data();
+    assert_eq!(vec![3, 5], inner.into_captured_data());
+}
+
+#[test]
+fn map_ref_to_val_clones_referenced_value() {
+    let points = vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }];
+    let point = Capture(points).fun_ref(|p, i: usize| &p[i]);
+
+    let owned = point.map_ref_to_val(Point::clone);
+    assert_eq!(Point { x: 3.0, y: 4.0 }, owned.call(1));
+
+    let norm = owned.map(|p| (p.x * p.x + p.y * p.y).sqrt());
+    assert_eq!(5.0, norm.call(1));
+}
+
+#[test]
+fn map_ref_to_val_unsized_output() {
+    let words = Capture(String::from("hello world"))
+        .fun_ref(|s, i: usize| s.split(' ').nth(i).unwrap_or(""));
+    let upper = words.map_ref_to_val(str::to_uppercase);
+
+    assert_eq!("WORLD", upper.call(1));
+    assert_eq!("", upper.call(2));
+}
+
+#[test]
+fn map_ref_to_val_option_and_result() {
+    let data = vec![1, 2, 3];
+
+    let get = Capture(data.clone()).fun_option_ref(|v, i: usize| v.get(i));
+    let get = get.map_ref_to_val(|x| *x as f64);
+    assert_eq!(Some(3.0), get.call(2));
+    assert_eq!(None, get.call(3));
+
+    let try_get =
+        Capture(data).fun_result_ref(|v, i: usize| v.get(i).ok_or(format!("no index {}", i)));
+    let try_get = try_get.map_ref_to_val(|x| x * 10);
+    assert_eq!(Ok(20), try_get.call(1));
+    assert_eq!(Err(String::from("no index 7")), try_get.call(7));
+}
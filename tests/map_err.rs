@@ -0,0 +1,101 @@
+use orx_closure::*;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    Missing(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(key) => write!(f, "missing key: {}", key),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+fn missing(key: &'static str) -> ConfigError {
+    ConfigError::Missing(key.to_string())
+}
+
+type Entries = Vec<(&'static str, String)>;
+
+fn lookup(entries: &Entries, key: &'static str) -> Result<&'static str, &'static str> {
+    match entries.iter().any(|(k, _)| *k == key) {
+        true => Ok(key),
+        false => Err(key),
+    }
+}
+
+fn entries() -> Entries {
+    vec![
+        ("host", String::from("localhost")),
+        ("port", String::from("80")),
+    ]
+}
+
+#[test]
+fn map_err_adapts_error_type() {
+    let get = Capture(entries()).fun_result_ref(|e, key: &'static str| {
+        e.iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+            .ok_or(key)
+    });
+    let get = get.map_err(missing);
+
+    assert_eq!(Ok("80"), get.call("port"));
+    assert_eq!(Err(missing("user")), get.call("user"));
+    assert!(lookup(get.captured_data().0.captured_data(), "host").is_ok());
+}
+
+#[test]
+fn map_err_result_composes_with_question_mark() {
+    fn connection(
+        get: &ClosureResRef<impl Sized, &'static str, str, ConfigError>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(format!("{}:{}", get.call("host")?, get.call("port")?))
+    }
+
+    let get = Capture(entries())
+        .fun_result_ref(|e, key: &'static str| {
+            e.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.as_str())
+                .ok_or(key)
+        })
+        .map_err(missing);
+    assert_eq!("localhost:80", connection(&get).unwrap());
+
+    let get = Capture(Entries::new())
+        .fun_result_ref(|e, key: &'static str| {
+            e.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.as_str())
+                .ok_or(key)
+        })
+        .map_err(missing);
+    assert_eq!(
+        "missing key: host",
+        connection(&get).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn map_err_on_unions() {
+    let get: ClosureResRefOneOf2<Entries, (), &'static str, str, &'static str> = Capture(())
+        .fun_result_ref(|_, key: &'static str| Err(key))
+        .into_oneof2_var2();
+    let get = get.map_err(missing);
+    assert_eq!(Err(missing("any")), get.call("any"));
+
+    type Row = ClosureResRefOneOf4<Vec<Vec<i32>>, (), char, bool, usize, [i32], &'static str>;
+    let row: Row = Capture(vec![vec![1, 2]])
+        .fun_result_ref(|m, i: usize| m.get(i).map(|r| r.as_slice()).ok_or("no row"))
+        .into_oneof4_var1();
+    let row = row.map_err(|e| e.len());
+    assert_eq!(Ok(&[1, 2][..]), row.call(0));
+    assert_eq!(Err(6), row.call(1));
+}
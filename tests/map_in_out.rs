@@ -0,0 +1,47 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct City(u8);
+
+type Km = HashMap<(u8, u8), u32>;
+
+fn distance() -> Closure<Km, (u8, u8), u32> {
+    let km = HashMap::from([((0, 1), 120), ((1, 2), 80)]);
+    Capture(km).fun(|km, (a, b): (u8, u8)| *km.get(&(a.min(b), a.max(b))).unwrap_or(&0))
+}
+
+#[test]
+fn map_in_out_adapts_both_ends() {
+    let miles = distance().map_in_out(|(a, b): (City, City)| (a.0, b.0), |km| km as f64 / 1.6);
+
+    assert_eq!(75.0, miles.call((City(0), City(1))));
+    assert_eq!(50.0, miles.call((City(2), City(1))));
+    assert_eq!(0.0, miles.call((City(0), City(2))));
+}
+
+#[test]
+fn map_in_out_identity() {
+    let same = distance().map_in_out(|x| x, |x| x);
+    assert_eq!(distance().call((1, 0)), same.call((1, 0)));
+}
+
+#[test]
+fn map_in_out_keeps_capture() {
+    let fun = distance().map_in_out(|x: u8| (x, x + 1), |x| x > 100);
+    assert!(fun.call(0));
+    assert!(!fun.call(1));
+
+    let (inner, _, _) = fun.into_captured_data();
+    assert_eq!(2, inner.captured_data().len());
+}
+
+#[test]
+fn map_in_out_into_union() {
+    let direct = Capture(()).fun(|_, (a, b): (City, City)| a.0.abs_diff(b.0) as f64);
+    let via_map = distance().map_in_out(|(a, b): (City, City)| (a.0, b.0), |km| km as f64);
+
+    let funs = [via_map.into_oneof2_var1(), direct.into_oneof2_var2()];
+    let values: Vec<_> = funs.iter().map(|f| f.call((City(1), City(2)))).collect();
+    assert_eq!(vec![80.0, 1.0], values);
+}
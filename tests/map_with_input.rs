@@ -0,0 +1,62 @@
+use orx_closure::*;
+
+#[derive(Debug)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn people() -> Vec<Person> {
+    vec![
+        Person {
+            name: String::from("john"),
+            age: 42,
+        },
+        Person {
+            name: String::from("jane"),
+            age: 35,
+        },
+    ]
+}
+
+#[test]
+fn map_with_input_pairs() {
+    let person = Capture(people()).fun_ref(|p, i: usize| &p[i]);
+    let name_of = person.map_with_input(|i, p| (i, p.name.clone()));
+
+    let pairs: Vec<_> = (0..2).map(name_of.as_fn()).collect();
+    assert_eq!(
+        vec![(0, String::from("john")), (1, String::from("jane"))],
+        pairs
+    );
+}
+
+#[test]
+fn map_with_input_borrowed_input() {
+    let text = String::from("hello world");
+    let keys = [String::from("hello"), String::from("bye")];
+
+    let contains = Capture(text)
+        .fun_ref(|t, _: &String| t.as_str())
+        .map_with_input(|key, t| t.contains(key.as_str()));
+
+    let found: Vec<_> = keys.iter().map(contains.as_fn()).collect();
+    assert_eq!(vec![true, false], found);
+}
+
+#[test]
+fn map_with_input_unsized_output_and_captured_data() {
+    let age_at = Capture(people())
+        .fun_ref(|p, (i, _): (usize, u32)| &p[i])
+        .map_with_input(|(_, year), p| p.age + year - 2024);
+
+    assert_eq!(43, age_at.call((0, 2025)));
+
+    let row = Capture(vec![vec![1, 2, 3]])
+        .fun_ref(|m, i: usize| m[i].as_slice())
+        .map_with_input(|i, row| row.iter().sum::<i32>() * i as i32);
+    assert_eq!(0, row.call(0));
+
+    let (inner, _) = age_at.into_captured_data();
+    assert_eq!(2, inner.into_captured_data().len());
+}
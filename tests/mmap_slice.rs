@@ -0,0 +1,87 @@
+#![cfg(feature = "mmap")]
+
+use bytemuck::{Pod, Zeroable};
+use memmap2::Mmap;
+use orx_closure::*;
+use std::{fs::File, io::Write, path::PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Edge {
+    from: u32,
+    to: u32,
+    weight: f32,
+}
+
+// repr(C) with three 4-byte fields: no padding, and any bit pattern is valid
+unsafe impl Zeroable for Edge {}
+unsafe impl Pod for Edge {}
+
+fn write_table(name: &str, bytes: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("orx_closure_{}.bin", name));
+    File::create(&path).unwrap().write_all(bytes).unwrap();
+    path
+}
+
+fn map(path: &PathBuf) -> Mmap {
+    unsafe { Mmap::map(&File::open(path).unwrap()) }.unwrap()
+}
+
+#[test]
+fn mmap_slice_of_primitives() {
+    let values: Vec<u64> = (0..1000).map(|i| i * i).collect();
+    let path = write_table("primitives", bytemuck::cast_slice(&values));
+
+    let table = MmapSlice::<u64>::new(map(&path)).unwrap();
+    assert_eq!(1000, table.len());
+    assert_eq!(values.as_slice(), table.as_slice());
+
+    let square = Capture(table).fun_ref(|t, i: usize| &t[i]);
+    assert_eq!(&81, square.call(9));
+
+    let range = Capture(square.into_captured_data()).fun_ref(|t, (a, b): (usize, usize)| &t[a..b]);
+    assert_eq!(&[4, 9, 16], range.call((2, 5)));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn mmap_slice_of_pod_structs() {
+    let edges = [
+        Edge {
+            from: 0,
+            to: 1,
+            weight: 0.5,
+        },
+        Edge {
+            from: 1,
+            to: 2,
+            weight: 1.5,
+        },
+    ];
+    let path = write_table("structs", bytemuck::cast_slice(&edges));
+
+    let table = MmapSlice::<Edge>::new(map(&path)).unwrap();
+    let find = Capture(table).fun_option_ref(|t, (from, to): (u32, u32)| {
+        t.iter().find(|e| e.from == from && e.to == to)
+    });
+
+    assert_eq!(Some(&edges[1]), find.call((1, 2)));
+    assert_eq!(None, find.call((2, 1)));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn mmap_slice_rejects_incompatible_length() {
+    let path = write_table("incompatible", &[1u8; 10]);
+
+    assert!(MmapSlice::<u32>::new(map(&path)).is_err());
+    let table = MmapSlice::<u16>::new(map(&path)).unwrap();
+    assert_eq!(5, table.len());
+    assert_eq!("MmapSlice { len: 5 }", format!("{:?}", table));
+
+    assert_eq!(10, table.into_mmap().len());
+
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "testing")]
+
+use orx_closure::testing::MockFun;
+use orx_closure::*;
+
+// consumers under test
+
+fn path_length<F: Fun<(usize, usize), u32>>(weight: &F, path: &[usize]) -> u32 {
+    path.windows(2).map(|w| weight.call((w[0], w[1]))).sum()
+}
+
+fn longest_name<'a, F: FunRef<usize, str>>(name_of: &'a F, ids: &[usize]) -> Option<&'a str> {
+    ids.iter().map(|i| name_of.call(*i)).max_by_key(|x| x.len())
+}
+
+fn count_known<F: FunLookup<u32, str>>(lookup: &F, ids: &[u32]) -> usize {
+    ids.iter().filter(|i| lookup.contains(**i)).count()
+}
+
+fn sum_or_first_error<F: FunResRef<usize, i32, String>>(
+    fun: &F,
+    ids: &[usize],
+) -> Result<i32, String> {
+    ids.iter().map(|i| fun.call(*i).copied()).sum()
+}
+
+fn try_sum<F: TryFun<usize, i32, String>>(fun: &F, ids: &[usize]) -> Result<i32, String> {
+    let mut sum = 0;
+    for i in ids {
+        sum += fun.try_call(*i)?;
+    }
+    Ok(sum)
+}
+
+fn increment_all<F: FunRefMut<usize, i32>>(fun: &mut F, ids: &[usize]) {
+    for i in ids {
+        *fun.call_mut(*i) += 1;
+    }
+}
+
+fn increment_present<F: FunOptRefMut<usize, i32>>(fun: &mut F, ids: &[usize]) {
+    for i in ids {
+        if let Some(x) = fun.call_mut(*i) {
+            *x += 1;
+        }
+    }
+}
+
+fn generate<F: FunMut<(), u8>>(fun: &mut F, n: usize) -> Vec<u8> {
+    (0..n).map(|_| fun.call_mut(())).collect()
+}
+
+// tests
+
+#[test]
+fn mock_fun() {
+    let weight = MockFun::new().returns((0, 1), 3).returns((1, 2), 4);
+    assert_eq!(7, path_length(&weight, &[0, 1, 2]));
+    assert_eq!(3, path_length(&weight, &[0, 1]));
+
+    assert_eq!(3, weight.num_calls());
+    weight.assert_num_calls_with(&(0, 1), 2);
+    weight.assert_num_calls_with(&(1, 2), 1);
+    weight.assert_all_called();
+}
+
+#[test]
+fn mock_fun_ref_borrowed_out() {
+    let name_of = MockFun::new()
+        .returns(0, String::from("john"))
+        .returns(1, String::from("jennifer"));
+    assert_eq!(Some("jennifer"), longest_name(&name_of, &[0, 1]));
+    assert_eq!(None, longest_name(&name_of, &[]));
+}
+
+#[test]
+fn mock_fun_lookup() {
+    let lookup = MockFun::new()
+        .returns(1, "x".to_string())
+        .returns_none(2)
+        .returns(3, "z".to_string());
+    assert_eq!(2, count_known(&lookup, &[1, 2, 3, 2]));
+    lookup.assert_num_calls_with(&2, 2);
+}
+
+#[test]
+fn mock_fun_res_ref_and_try_fun() {
+    let fun = MockFun::new_fallible()
+        .returns(0, 10)
+        .returns(1, 20)
+        .returns_err(2, "broken".to_string());
+
+    assert_eq!(Ok(30), sum_or_first_error(&fun, &[0, 1]));
+    assert_eq!(
+        Err("broken".to_string()),
+        sum_or_first_error(&fun, &[0, 2, 1])
+    );
+    assert_eq!(Ok(10), try_sum(&fun, &[0]));
+    assert_eq!(Err("broken".to_string()), try_sum(&fun, &[2]));
+}
+
+#[test]
+fn mock_fun_mutable_traits() {
+    let mut fun = MockFun::new().returns(0, 10).returns(1, 20).returns_none(2);
+    increment_all(&mut fun, &[0, 0, 1]);
+    increment_present(&mut fun, &[1, 2]);
+
+    assert_eq!(Some(&12), FunOptRef::call(&fun, 0));
+    assert_eq!(Some(&22), FunOptRef::call(&fun, 1));
+    assert_eq!(None, FunOptRef::<_, i32>::call(&fun, 2));
+
+    let mut constant = MockFun::new().returns((), 7u8);
+    assert_eq!(vec![7, 7, 7], generate(&mut constant, 3));
+    constant.assert_num_calls_with(&(), 3);
+}
+
+#[test]
+fn mock_fun_override_response() {
+    let fun = MockFun::new().returns(0, 1).returns_none(0);
+    assert_eq!(None, FunOptRef::<_, i32>::call(&fun, 0));
+}
+
+#[test]
+fn mock_fun_trait_object() {
+    let fun: Box<dyn Fun<(usize, usize), u32>> = Box::new(MockFun::new().returns((0, 1), 5));
+    assert_eq!(5, fun.call((0, 1)));
+}
+
+#[test]
+#[should_panic(expected = "unexpected input (1, 2)")]
+fn mock_fun_panics_on_unexpected_input() {
+    let weight = MockFun::new().returns((0, 1), 3);
+    path_length(&weight, &[0, 1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "cannot be returned by Fun")]
+fn mock_fun_panics_on_unrepresentable_response() {
+    let weight: MockFun<(usize, usize), u32> = MockFun::new().returns_none((0, 1));
+    path_length(&weight, &[0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "expected 2 call(s) with input 0")]
+fn mock_fun_panics_on_unmet_call_count() {
+    let fun = MockFun::new().returns(0, 'a');
+    let _ = Fun::call(&fun, 0);
+    fun.assert_num_calls_with(&0, 2);
+}
+
+#[test]
+#[should_panic(expected = "never called with inputs [1]")]
+fn mock_fun_panics_on_unused_expectation() {
+    let fun = MockFun::new().returns(0, 'a').returns(1, 'b');
+    let _ = Fun::call(&fun, 0);
+    fun.assert_all_called();
+}
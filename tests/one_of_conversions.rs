@@ -0,0 +1,56 @@
+use orx_closure::*;
+
+#[test]
+fn oneof2_result_round_trip() {
+    let data: OneOf2<Vec<i32>, String> = OneOf2::Variant2("x".to_string());
+    let result = data.clone().into_result();
+    assert_eq!(Err("x".to_string()), result);
+    assert_eq!(data, OneOf2::from(result));
+}
+
+#[test]
+fn captured_data_into_result() {
+    type Union = ClosureOneOf2<Vec<i32>, i32, usize, i32>;
+    let fun: Union = Capture(vec![1, 2]).fun(|x, i| x[i]).into_oneof2_var1();
+    match fun.into_captured_data().into_result() {
+        Ok(vec) => assert_eq!(vec![1, 2], vec),
+        Err(_) => panic!("expected the first variant"),
+    }
+}
+
+#[test]
+fn nested_unions() {
+    type U3 = OneOf3<i32, bool, char>;
+    assert_eq!(
+        OneOf2::Variant2(OneOf2::Variant1(true)),
+        U3::Variant2(true).into_nested()
+    );
+
+    type U4 = OneOf4<i32, bool, char, u8>;
+    assert_eq!(
+        OneOf2::Variant2(OneOf2::Variant2(OneOf2::Variant2(7))),
+        U4::Variant4(7).into_nested()
+    );
+    assert_eq!(OneOf2::Variant1(1), U4::Variant1(1).into_nested());
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn either_interop() {
+    use either::Either;
+
+    let data: OneOf2<i32, bool> = OneOf2::Variant1(3);
+    let either: Either<i32, bool> = data.clone().into();
+    assert_eq!(Either::Left(3), either);
+    assert_eq!(data, OneOf2::from(either));
+
+    type U4 = OneOf4<i32, bool, char, u8>;
+    assert_eq!(
+        Either::Right(Either::Right(Either::Right(7))),
+        U4::Variant4(7).into_nested_either()
+    );
+    assert_eq!(
+        Either::Right(Either::Left(true)),
+        OneOf3::<i32, bool, char>::Variant2(true).into_nested_either()
+    );
+}
@@ -0,0 +1,65 @@
+use orx_closure::*;
+
+type Weight = ClosureOneOf4<Vec<i32>, (), i32, String, usize, i32>;
+type Name = ClosureRefOneOf3<Vec<String>, String, (), usize, str>;
+type Find = ClosureOptRefOneOf2<Vec<i32>, [i32; 2], usize, i32>;
+type Fetch = ClosureResRefOneOf2<Vec<i32>, (), usize, i32, String>;
+
+fn weight(variant: usize) -> Weight {
+    match variant {
+        1 => one_of!(var1: Capture(vec![10, 20]).fun(|v, i: usize| v[i])),
+        2 => one_of!(var2: Capture(()).fun(|_, _: usize| 1)),
+        3 => one_of!(var3: Capture(5).fun(|x, i: usize| x * i as i32)),
+        _ => one_of!(var4: Capture("abc".to_string()).fun(|s, i: usize| s.len() as i32 + i as i32)),
+    }
+}
+
+#[test]
+fn one_of_inferred_val() {
+    assert_eq!(20, weight(1).call(1));
+    assert_eq!(1, weight(2).call(1));
+    assert_eq!(15, weight(3).call(3));
+    assert_eq!(4, weight(4).call(1));
+}
+
+#[test]
+fn one_of_explicit_val() {
+    let fun = one_of!(var2: Capture(()).fun(|_, _: usize| 1) => Weight);
+    assert_eq!(1, fun.call(0));
+    assert!(matches!(fun.captured_data(), OneOf4::Variant2(&())));
+}
+
+#[test]
+fn one_of_ref() {
+    let name: Name = one_of!(var2: Capture("x".to_string()).fun_ref(|s, _: usize| s.as_str()));
+    assert_eq!("x", name.call(3));
+
+    let name =
+        one_of!(var1: Capture(vec!["a".to_string()]).fun_ref(|v, i: usize| v[i].as_str()) => Name);
+    assert_eq!("a", name.call(0));
+}
+
+#[test]
+fn one_of_opt_ref() {
+    let find: Find = one_of!(var2: Capture([1, 2]).fun_option_ref(|a, i: usize| a.get(i)));
+    assert_eq!(Some(&2), find.call(1));
+    assert_eq!(None, find.call(2));
+}
+
+#[test]
+fn one_of_res_ref() {
+    let fetch: Fetch = one_of!(var1: Capture(vec![7]).fun_result_ref(|v, i: usize| v
+        .get(i)
+        .ok_or(format!("{} is out of bounds", i))));
+    assert_eq!(Ok(&7), fetch.call(0));
+    assert_eq!(Err("1 is out of bounds".to_string()), fetch.call(1));
+}
+
+#[test]
+fn one_of_equivalent_to_into_oneof() {
+    let a: Weight = one_of!(var3: Capture(2).fun(|x, i: usize| x + i as i32));
+    let b = Capture(2)
+        .fun(|x, i: usize| x + i as i32)
+        .into_oneof4_var3::<Vec<i32>, (), String>();
+    assert_eq!(a.call(4), b.call(4));
+}
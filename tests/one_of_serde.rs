@@ -0,0 +1,51 @@
+#![cfg(feature = "serde")]
+
+use orx_closure::*;
+use std::collections::HashMap;
+
+#[test]
+fn oneof2_json_round_trip() {
+    let data: OneOf2<i32, String> = OneOf2::Variant1(42);
+    let json = serde_json::to_string(&data).expect("serializable");
+    assert_eq!(r#"{"Variant1":42}"#, json);
+
+    let back: OneOf2<i32, String> = serde_json::from_str(&json).expect("deserializable");
+    assert_eq!(data, back);
+}
+
+#[test]
+fn oneof3_and_oneof4_json_round_trip() {
+    let data: OneOf3<(), Vec<u8>, char> = OneOf3::Variant2(vec![1, 2]);
+    let json = serde_json::to_string(&data).expect("serializable");
+    assert_eq!(data, serde_json::from_str(&json).expect("deserializable"));
+
+    let data: OneOf4<(), bool, char, String> = OneOf4::Variant4("x".to_string());
+    let json = serde_json::to_string(&data).expect("serializable");
+    assert_eq!(r#"{"Variant4":"x"}"#, json);
+    assert_eq!(data, serde_json::from_str(&json).expect("deserializable"));
+}
+
+#[test]
+fn config_describes_variant_to_construct() {
+    type Weights = OneOf2<u32, HashMap<String, u32>>;
+    type Weight = ClosureOneOf2<u32, HashMap<String, u32>, &'static str, u32>;
+
+    fn weight(config: &str) -> Weight {
+        let weights: Weights = serde_json::from_str(config).expect("valid config");
+        match weights {
+            OneOf2::Variant1(w) => Capture(w).fun(|w, _: &str| *w).into_oneof2_var1(),
+            OneOf2::Variant2(map) => Capture(map)
+                .fun(|m, key: &str| m.get(key).copied().unwrap_or(0))
+                .into_oneof2_var2(),
+        }
+    }
+
+    assert_eq!(3, weight(r#"{"Variant1":3}"#).call("a"));
+    let sparse = weight(r#"{"Variant2":{"a":7}}"#);
+    assert_eq!(7, sparse.call("a"));
+    assert_eq!(0, sparse.call("b"));
+
+    // persisting the captured data extracted from the closure
+    let json = serde_json::to_string(&sparse.into_captured_data()).expect("serializable");
+    assert_eq!(r#"{"Variant2":{"a":7}}"#, json);
+}
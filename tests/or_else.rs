@@ -0,0 +1,49 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Layer = HashMap<String, String>;
+
+fn layer(entries: &[(&str, &str)]) -> ClosureOptRef<Layer, &'static str, str> {
+    let map = entries
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    Capture(map).fun_option_ref(|m: &Layer, key: &'static str| m.get(key).map(|x| x.as_str()))
+}
+
+#[test]
+fn or_else_layered_lookup() {
+    let cli = layer(&[("port", "8080")]);
+    let env = layer(&[("port", "80"), ("host", "example.com")]);
+    let defaults = layer(&[("port", "1"), ("host", "localhost"), ("log", "info")]);
+
+    let config = cli.or_else(env).or_else(defaults);
+
+    assert_eq!(Some("8080"), config.call("port"));
+    assert_eq!(Some("example.com"), config.call("host"));
+    assert_eq!(Some("info"), config.call("log"));
+    assert_eq!(None, config.call("user"));
+}
+
+#[test]
+fn or_else_exposes_both_captures() {
+    let config = layer(&[("a", "1")]).or_else(layer(&[("b", "2")]));
+
+    let (first, second) = config.captured_data();
+    assert!(first.captured_data().contains_key("a"));
+    assert!(second.captured_data().contains_key("b"));
+
+    let (first, second) = config.into_captured_data();
+    assert_eq!(1, first.into_captured_data().len());
+    assert_eq!(1, second.into_captured_data().len());
+}
+
+#[test]
+fn or_else_through_fun_opt_ref() {
+    fn resolve<F: FunOptRef<&'static str, str>>(lookup: &F, keys: &[&'static str]) -> String {
+        keys.iter().map(|k| lookup.call(k).unwrap_or("?")).collect()
+    }
+
+    let config = layer(&[("x", "1")]).or_else(layer(&[("y", "2")]));
+    assert_eq!("12?", resolve(&config, &["x", "y", "z"]));
+}
@@ -0,0 +1,62 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+#[test]
+fn fun_get_jagged() {
+    let jagged = vec![vec![1, 2, 3], vec![4]];
+    let weight = Capture(jagged).fun_get(-1, |x, (i, j): (usize, usize)| {
+        x.get(i).and_then(|row| row.get(j)).copied()
+    });
+
+    assert_eq!(2, weight.call((0, 1)));
+    assert_eq!(4, weight.call((1, 0)));
+    assert_eq!(-1, weight.call((1, 1)));
+    assert_eq!(-1, weight.call((7, 0)));
+
+    let (data, default, _) = weight.into_captured_data();
+    assert_eq!(2, data.len());
+    assert_eq!(-1, default);
+}
+
+#[test]
+fn fun_ref_get_map() {
+    let map: HashMap<&str, Vec<u32>> = HashMap::from_iter([("john", vec![1, 2])]);
+    let fun = Capture(map).fun_ref_get(vec![], |m, name: &str| m.get(name));
+
+    assert_eq!(&vec![1, 2], fun.call("john"));
+    assert!(fun.call("doe").is_empty());
+}
+
+#[test]
+fn fun_ref_get_into_union() {
+    type Union = ClosureRefOneOf2<
+        (
+            Vec<String>,
+            String,
+            fn(&Vec<String>, usize) -> Option<&String>,
+        ),
+        String,
+        usize,
+        String,
+    >;
+
+    let names = vec!["john".to_string(), "doe".to_string()];
+    let fun: Union = Capture(names)
+        .fun_ref_get("?".to_string(), |x, i| x.get(i))
+        .into_oneof2_var1();
+    assert_eq!("doe", fun.call(1));
+    assert_eq!("?", fun.call(2));
+
+    let fun: Union = Capture("same".to_string())
+        .fun_ref(|x, _| x)
+        .into_oneof2_var2();
+    assert_eq!("same", fun.call(2));
+}
+
+#[test]
+fn fun_ref_get_clone() {
+    let fun = Capture(vec![1, 2, 3]).fun_ref_get(0, |x, i: usize| x.get(i));
+    let clone = fun.clone();
+    assert_eq!(&3, clone.call(2));
+    assert_eq!(&0, clone.call(3));
+}
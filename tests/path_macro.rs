@@ -0,0 +1,85 @@
+use orx_closure::*;
+
+struct Address {
+    city: String,
+    zip: Option<String>,
+}
+
+struct User {
+    name: String,
+    address: Option<Address>,
+}
+
+struct Registry {
+    users: Vec<User>,
+    owner: User,
+}
+
+fn registry() -> Registry {
+    Registry {
+        users: vec![
+            User {
+                name: String::from("john"),
+                address: Some(Address {
+                    city: String::from("paris"),
+                    zip: None,
+                }),
+            },
+            User {
+                name: String::from("jane"),
+                address: None,
+            },
+        ],
+        owner: User {
+            name: String::from("doe"),
+            address: Some(Address {
+                city: String::from("rome"),
+                zip: Some(String::from("00100")),
+            }),
+        },
+    }
+}
+
+#[test]
+fn path_without_input() {
+    let owner = Capture(registry()).fun_ref(path!(Registry => owner.name));
+    assert_eq!("doe", owner.call(()));
+
+    let num_users = Capture(registry()).fun_ref(path!(Registry => users));
+    assert_eq!(2, num_users.call(()).len());
+}
+
+#[test]
+fn path_with_input() {
+    let name = Capture(registry()).fun_ref(path!(Registry, i: usize => users[i].name));
+    assert_eq!("john", name.call(0));
+    assert_eq!("jane", name.call(1));
+}
+
+#[test]
+fn path_through_options() {
+    let city = Capture(registry())
+        .fun_option_ref(path!(Registry, i: usize => ? users.get(i)?.address.as_ref()?.city));
+    assert_eq!(Some(&String::from("paris")), city.call(0));
+    assert_eq!(None, city.call(1));
+    assert_eq!(None, city.call(2));
+
+    let zip = Capture(registry()).fun_option_ref(path!(Registry => ? owner.address.as_ref()?.zip));
+    assert_eq!(Some(&Some(String::from("00100"))), zip.call(()));
+}
+
+#[test]
+fn path_into_union() {
+    type Name = ClosureRefOneOf2<Registry, Vec<String>, usize, String>;
+
+    let names: Vec<Name> = vec![
+        Capture(registry())
+            .fun_ref(path!(Registry, i: usize => users[i].name))
+            .into_oneof2_var1(),
+        Capture(vec![String::from("x")])
+            .fun_ref(|names, i: usize| &names[i])
+            .into_oneof2_var2(),
+    ];
+    assert_eq!("jane", names[0].call(1));
+    assert_eq!("x", names[1].call(0));
+}
@@ -0,0 +1,133 @@
+use orx_closure::*;
+
+type Cost = Closure<Vec<f64>, f64, f64>;
+
+fn tiers() -> Vec<f64> {
+    vec![1.0, 0.8, 0.5]
+}
+
+#[test]
+fn dispatches_on_ranges_and_default() {
+    let cost = Piecewise::new()
+        .on(0.0..10.0, Capture(tiers()).fun(|r, x: f64| r[0] * x))
+        .on(10.0..100.0, Capture(tiers()).fun(|r, x: f64| r[1] * x))
+        .otherwise(Capture(tiers()).fun(|r, x: f64| r[2] * x));
+
+    assert_eq!(0.0, cost.call(0.0));
+    assert_eq!(9.0, cost.call(9.0));
+    assert_eq!(8.0, cost.call(10.0)); // start is inclusive
+    assert_eq!(50.0, cost.call(100.0)); // end is exclusive
+    assert_eq!(-5.0, cost.call(-10.0)); // below all ranges
+}
+
+#[test]
+fn pieces_in_any_order_with_gaps() {
+    let pieces: Vec<(std::ops::Range<i64>, i64)> = vec![
+        (50..60, 5),
+        (0..10, 0),
+        (30..40, 3),
+        (10..20, 1),
+        (-20..-10, -2),
+    ];
+
+    let mut builder = Piecewise::new();
+    for (range, tag) in pieces.iter().cloned() {
+        builder = builder.on(range, Capture(tag).fun(|t, _: i64| *t));
+    }
+    assert_eq!(5, builder.len());
+    let fun = builder.otherwise(Capture(i64::MAX).fun(|t, _: i64| *t));
+
+    let expected = |x: i64| {
+        pieces
+            .iter()
+            .find(|(r, _)| r.contains(&x))
+            .map(|(_, t)| *t)
+            .unwrap_or(i64::MAX)
+    };
+    for x in -30..70 {
+        assert_eq!(expected(x), fun.call(x), "input {}", x);
+    }
+}
+
+#[test]
+fn many_pieces() {
+    let mut builder = Piecewise::<usize, fn(usize) -> usize>::new();
+    for i in (0..1000).rev() {
+        builder = builder.on(i * 10..i * 10 + 5, |x| x / 10);
+    }
+    let fun = builder.otherwise(|_| usize::MAX);
+
+    assert_eq!(0, fun.call(4));
+    assert_eq!(usize::MAX, fun.call(5));
+    assert_eq!(123, fun.call(1234));
+    assert_eq!(usize::MAX, fun.call(1236));
+    assert_eq!(usize::MAX, fun.call(10_000));
+}
+
+#[test]
+fn piece_lookup() {
+    let builder = Piecewise::new()
+        .on(0..10, Capture(1).fun(|a, x: i32| a + x))
+        .on(20..30, Capture(2).fun(|a, x: i32| a * x));
+
+    assert!(builder.piece(&-1).is_none());
+    assert_eq!(Some(&1), builder.piece(&9).map(|f| f.captured_data()));
+    assert!(builder.piece(&10).is_none());
+    assert!(builder.piece(&15).is_none());
+    assert_eq!(Some(&2), builder.piece(&20).map(|f| f.captured_data()));
+    assert!(builder.piece(&30).is_none());
+}
+
+#[test]
+fn union_pieces() {
+    type Price = ClosureOneOf2<f64, Vec<f64>, usize, f64>;
+
+    let flat: Price = Capture(2.5).fun(|p, _: usize| *p).into_oneof2_var1();
+    let listed: Price = Capture(vec![1.0, 2.0, 3.0])
+        .fun(|p, i: usize| p[i % 3])
+        .into_oneof2_var2();
+    let free: Price = Capture(0.0).fun(|p, _: usize| *p).into_oneof2_var1();
+
+    let price = Piecewise::new()
+        .on(0..3, listed)
+        .on(3..10, flat)
+        .otherwise(free);
+
+    assert_eq!(
+        vec![1.0, 2.0, 3.0, 2.5, 2.5, 0.0],
+        [0, 1, 2, 3, 9, 10].map(price.as_fn())
+    );
+}
+
+#[test]
+fn into_captured_data() {
+    let cost: Closure<(Piecewise<f64, Cost>, Cost), f64, f64> = Piecewise::new()
+        .on(0.0..1.0, Capture(tiers()).fun(|r, x: f64| r[0] * x))
+        .otherwise(Capture(tiers()).fun(|r, x: f64| r[2] * x));
+
+    let (pieces, default) = cost.into_captured_data();
+    assert_eq!(1, pieces.len());
+    assert_eq!(&tiers(), default.captured_data());
+}
+
+#[test]
+#[should_panic(expected = "piecewise ranges cannot overlap")]
+fn overlapping_ranges_panic() {
+    let _ = Piecewise::<i32, fn(i32) -> i32>::new()
+        .on(0..10, |x| x)
+        .on(9..20, |x| x);
+}
+
+#[test]
+#[should_panic(expected = "piecewise ranges cannot overlap")]
+fn enclosing_ranges_panic() {
+    let _ = Piecewise::<i32, fn(i32) -> i32>::new()
+        .on(5..6, |x| x)
+        .on(0..10, |x| x);
+}
+
+#[test]
+#[should_panic(expected = "piecewise ranges cannot be empty")]
+fn empty_range_panics() {
+    let _ = Piecewise::<i32, fn(i32) -> i32>::new().on(3..3, |x| x);
+}
@@ -0,0 +1,61 @@
+use orx_closure::*;
+
+#[test]
+fn pipeline_single_stage() {
+    let double = pipeline!(Capture(2).fun(|k, x: i32| k * x));
+    assert_eq!(8, double.call(4));
+    assert_eq!(&2, double.captured_data().captured_data());
+}
+
+#[test]
+fn pipeline_two_stages_matches_then() {
+    let scale = || Capture(3).fun(|k, x: i32| k * x);
+    let shift = || Capture(1).fun(|b, x: i32| x + b);
+
+    let pipeline = pipeline!(scale(), shift());
+    let chain = scale().then(shift());
+
+    for x in -3..3 {
+        assert_eq!(chain.call(x), pipeline.call(x));
+    }
+}
+
+#[test]
+fn pipeline_changes_types_across_stages() {
+    let words = pipeline!(
+        Capture(vec!["a b", "c d e"]).fun(|l, i: usize| l[i]),
+        Capture(' ').fun(|sep, line: &str| line.split(*sep).count()),
+        Capture(10.0).fun(|w, n: usize| n as f64 * w),
+        Capture("words").fun(|unit, x: f64| format!("{} {}", x, unit)),
+    );
+    assert_eq!("20 words", words.call(0));
+    assert_eq!("30 words", words.call(1));
+}
+
+#[test]
+fn pipeline_clone_and_into_captured_data() {
+    let pipeline = pipeline!(
+        Capture(vec![1, 2, 3]).fun(|v, i: usize| v[i]),
+        Capture(10).fun(|k, x: i32| k * x),
+        Capture(-1).fun(|b, x: i32| x + b),
+    );
+
+    let cloned = pipeline.clone();
+    assert_eq!(29, cloned.call(2));
+
+    let (values, (scale, shift)) = pipeline.into_captured_data();
+    assert_eq!(vec![1, 2, 3], values.into_captured_data());
+    assert_eq!(10, scale.into_captured_data());
+    assert_eq!(-1, shift.into_captured_data());
+}
+
+#[test]
+fn pipeline_of_unions_and_pipelines() {
+    type Weight = ClosureOneOf2<Vec<i32>, i32, usize, i32>;
+    let weight: Weight = Capture(7).fun(|k, _: usize| *k).into_oneof2_var2();
+
+    let inner = pipeline!(weight, Capture(2).fun(|k, x: i32| k * x));
+    let outer = pipeline!(inner, Capture(()).fun(|_, x: i32| x.to_string()));
+
+    assert_eq!("14", outer.call(0));
+}
@@ -0,0 +1,99 @@
+use orx_closure::*;
+
+#[derive(Clone, Copy)]
+struct Edge {
+    from: usize,
+    to: usize,
+}
+
+fn edge_pair(e: Edge) -> (usize, usize) {
+    (e.from, e.to)
+}
+
+type Jagged = Vec<Vec<i32>>;
+
+fn jagged() -> Jagged {
+    vec![vec![0, 3], vec![5, 0]]
+}
+
+#[test]
+fn premap_closure() {
+    let weight = Capture(jagged()).fun(|w, (i, j): (usize, usize)| w[i][j]);
+    let weight = weight.premap(edge_pair);
+
+    assert_eq!(3, weight.call(Edge { from: 0, to: 1 }));
+    assert_eq!(5, weight.call(Edge { from: 1, to: 0 }));
+
+    let (original, _) = weight.into_captured_data();
+    assert_eq!(3, original.call((0, 1)));
+}
+
+#[test]
+fn premap_reference_returning_closures() {
+    let names = vec![vec![String::from("a"), String::from("b")]];
+    let name = Capture(names).fun_ref(|n, (i, j): (usize, usize)| n[i][j].as_str());
+    let name = name.premap(edge_pair);
+    assert_eq!("b", name.call(Edge { from: 0, to: 1 }));
+
+    let get = Capture(jagged())
+        .fun_option_ref(|w, (i, j): (usize, usize)| w.get(i).and_then(|r| r.get(j)));
+    let get = get.premap(edge_pair);
+    assert_eq!(Some(&5), get.call(Edge { from: 1, to: 0 }));
+    assert_eq!(None, get.call(Edge { from: 2, to: 0 }));
+
+    let try_get = Capture(jagged())
+        .fun_result_ref(|w, (i, j): (usize, usize)| w.get(i).and_then(|r| r.get(j)).ok_or(i));
+    let try_get = try_get.premap(edge_pair);
+    assert_eq!(Ok(&0), try_get.call(Edge { from: 1, to: 1 }));
+    assert_eq!(Err(7), try_get.call(Edge { from: 7, to: 0 }));
+}
+
+#[test]
+fn premap_unions() {
+    type Weight = ClosureOneOf3<Jagged, i32, (), (usize, usize), i32>;
+
+    let weights: Vec<Weight> = vec![
+        Capture(jagged())
+            .fun(|w, (i, j): (usize, usize)| w[i][j])
+            .into_oneof3_var1(),
+        Capture(42)
+            .fun(|c, _: (usize, usize)| *c)
+            .into_oneof3_var2(),
+        Capture(())
+            .fun(|_, (i, j): (usize, usize)| (i + j) as i32)
+            .into_oneof3_var3(),
+    ];
+
+    let edge = Edge { from: 1, to: 0 };
+    let values: Vec<_> = weights
+        .into_iter()
+        .map(|w| w.premap(edge_pair).call(edge))
+        .collect();
+    assert_eq!(vec![5, 42, 1], values);
+
+    let name: ClosureRefOneOf2<Vec<String>, String, usize, str> = Capture(String::from("const"))
+        .fun_ref(|s, _: usize| s.as_str())
+        .into_oneof2_var2();
+    let name = name.premap(|e: Edge| e.to);
+    assert_eq!("const", name.call(edge));
+
+    let get: ClosureOptRefOneOf4<Jagged, (), bool, char, (usize, usize), i32> = Capture(jagged())
+        .fun_option_ref(|w, (i, j): (usize, usize)| w.get(i).and_then(|r| r.get(j)))
+        .into_oneof4_var1();
+    assert_eq!(
+        Some(&3),
+        get.premap(edge_pair).call(Edge { from: 0, to: 1 })
+    );
+
+    let try_get: ClosureResRefOneOf2<Jagged, (), (usize, usize), i32, String> = Capture(jagged())
+        .fun_result_ref(|w, (i, j): (usize, usize)| {
+            w.get(i)
+                .and_then(|r| r.get(j))
+                .ok_or(format!("{}-{}", i, j))
+        })
+        .into_oneof2_var1();
+    assert_eq!(
+        Err(String::from("3-3")),
+        try_get.premap(edge_pair).call(Edge { from: 3, to: 3 })
+    );
+}
@@ -0,0 +1,97 @@
+use orx_closure::*;
+
+fn names() -> Vec<String> {
+    vec!["john".to_string(), "doe".to_string(), "jane".to_string()]
+}
+
+#[test]
+fn recording_fun() {
+    let fun = Capture(10).fun(|x, i: i32| x * i);
+    let recording = Recording::new(fun, 3);
+    assert_eq!(3, recording.capacity());
+
+    let outputs: Vec<_> = (0..5).map(|i| recording.call(i)).collect();
+    assert_eq!(vec![0, 10, 20, 30, 40], outputs);
+
+    assert_eq!(vec![(2, 20), (3, 30), (4, 40)], recording.take_log());
+    assert!(recording.take_log().is_empty());
+}
+
+#[test]
+fn recording_zero_capacity() {
+    let fun = Capture(()).fun(|_, i: i32| i);
+    let recording = Recording::new(fun, 0);
+    assert_eq!(7, recording.call(7));
+    assert!(recording.take_log().is_empty());
+}
+
+#[test]
+fn recording_fun_ref() {
+    let fun = Capture(names()).fun_ref(|n, i: usize| n[i].as_str());
+    let recording = Recording::new(fun, 4);
+    assert_eq!("doe", recording.call(1));
+    assert_eq!("john", recording.call(0));
+    assert_eq!(
+        vec![(1, "doe".to_string()), (0, "john".to_string())],
+        recording.take_log()
+    );
+}
+
+#[test]
+fn recording_fun_opt_ref() {
+    let fun = Capture(names()).fun_option_ref(|n, i: usize| n.get(i));
+    let recording = Recording::new(fun, 4);
+    assert_eq!(Some(&"jane".to_string()), recording.call(2));
+    assert_eq!(None, recording.call(3));
+    assert_eq!(
+        vec![(2, Some("jane".to_string())), (3, None)],
+        recording.take_log()
+    );
+}
+
+#[test]
+fn recording_fun_res_ref() {
+    let fun = Capture(names()).fun_result_ref(|n, i: usize| n.get(i).ok_or(i));
+    let recording = Recording::new(fun, 4);
+    assert_eq!(Ok(&"john".to_string()), recording.call(0));
+    assert_eq!(Err(5), recording.call(5));
+    assert_eq!(
+        vec![(0, Ok("john".to_string())), (5, Err(5))],
+        recording.take_log()
+    );
+}
+
+#[test]
+fn recording_as_generic_fun() {
+    fn sum<F: Fun<usize, usize>>(fun: &F) -> usize {
+        (0..3).map(|i| fun.call(i)).sum()
+    }
+    let fun = Capture(names()).fun(|n, i: usize| n[i].len());
+    let recording = Recording::new(fun, 8);
+    assert_eq!(11, sum(&recording));
+    let (fun, log) = recording.into_inner();
+    assert_eq!(vec![(0, 4), (1, 3), (2, 4)], log);
+    assert_eq!(3, fun.call(1));
+}
+
+#[test]
+fn replay_serves_recorded_answers() {
+    let fun = Capture(names()).fun_ref(|n, i: usize| n[i].as_str());
+    let recording = Recording::new(fun, 8);
+    recording.call(2);
+    recording.call(0);
+    assert_eq!(&names(), recording.inner().captured_data());
+
+    let replay: Replay<usize, String> = recording.into_replay();
+    assert_eq!(Some(&"jane".to_string()), replay.call(2));
+    assert_eq!(Some(&"john".to_string()), replay.call(0));
+    assert_eq!(None, replay.call(1));
+}
+
+#[test]
+fn replay_from_production_trace() {
+    let trace = vec![("a", 1), ("b", 2)];
+    let replay: Replay<&str, i32> = Capture(trace)
+        .fun_option_ref(|log, input| log.iter().find(|(x, _)| *x == input).map(|(_, out)| out));
+    assert_eq!(Some(&2), replay.call("b"));
+}
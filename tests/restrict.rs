@@ -0,0 +1,86 @@
+use orx_closure::*;
+
+fn numbers() -> Vec<i32> {
+    (0..10).map(|x| x * 10).collect()
+}
+
+#[test]
+fn restrict_closure() {
+    let fun = Capture(numbers()).fun(|x, i: usize| x[i] + 1);
+    let window = fun.restrict(4..7);
+    assert_eq!(
+        vec![41, 51, 61],
+        (0..3).map(|i| window.call(i)).collect::<Vec<_>>()
+    );
+    assert_eq!(&(4..7), &window.captured_data().1);
+}
+
+#[test]
+fn restrict_closure_ref() {
+    let fun = Capture(numbers()).fun_ref(|x, i: usize| &x[i]);
+    let window = fun.restrict(8..10);
+    assert_eq!(&80, window.call(0));
+    assert_eq!(&90, window.call(1));
+}
+
+#[test]
+fn restrict_closure_opt_ref() {
+    let fun = Capture(numbers()).fun_option_ref(|x, i: usize| x.get(i));
+
+    let window = fun.clone().restrict(2..4);
+    let values: Vec<_> = (0..4).map(|i| window.call(i)).collect();
+    assert_eq!(vec![Some(&20), Some(&30), None, None], values);
+
+    // window exceeding the data
+    let window = fun.clone().restrict(9..12);
+    assert_eq!(Some(&90), window.call(0));
+    assert_eq!(None, window.call(1));
+    assert_eq!(None, window.call(3));
+
+    // empty window
+    let window = fun.restrict(5..5);
+    assert_eq!(None, window.call(0));
+}
+
+#[test]
+fn restrict_closure_res_ref() {
+    let fun = Capture(numbers()).fun_result_ref(|x, i: usize| x.get(i).ok_or("out of bounds"));
+    let window = fun.restrict(9..10, |_| "out of bounds");
+    assert_eq!(Ok(&90), window.call(0));
+    assert_eq!(Err("out of bounds"), window.call(1));
+}
+
+#[test]
+fn restrict_nested_windows() {
+    let fun = Capture(numbers()).fun_option_ref(|x, i: usize| x.get(i));
+    let outer = fun.restrict(2..8);
+    let inner = outer.restrict(1..3);
+    assert_eq!(Some(&30), inner.call(0));
+    assert_eq!(Some(&40), inner.call(1));
+    assert_eq!(None, inner.call(2));
+}
+
+#[test]
+#[should_panic(expected = "out of the restricted window")]
+fn restrict_closure_outside_window_panics() {
+    let fun = Capture(numbers()).fun(|x, i: usize| x[i]);
+    let window = fun.restrict(2..4);
+    window.call(2);
+}
+
+#[test]
+#[should_panic(expected = "out of the restricted window")]
+fn restrict_closure_ref_overflowing_index_panics() {
+    let fun = Capture(numbers()).fun_ref(|x, i: usize| &x[i]);
+    let window = fun.restrict(2..4);
+    window.call(usize::MAX);
+}
+
+#[test]
+fn restrict_closure_res_ref_outside_window_is_err() {
+    let fun = Capture(numbers()).fun_result_ref(|x, i: usize| x.get(i).ok_or(i));
+    let window = fun.restrict(2..4, |i| i);
+    assert_eq!(Ok(&30), window.call(1));
+    assert_eq!(Err(4), window.call(2));
+    assert_eq!(Err(usize::MAX), window.call(usize::MAX));
+}
@@ -0,0 +1,71 @@
+use orx_closure::*;
+
+#[test]
+fn routes_by_predicate() {
+    let is_even = Capture(2).fun_key(|m, x: &i32| x % m == 0);
+    let half = Capture(2).fun(|d, x: i32| x / d);
+    let triple_plus_one = Capture((3, 1)).fun(|(a, b), x: i32| a * x + b);
+
+    let collatz = select_by(is_even, half, triple_plus_one);
+
+    assert_eq!(vec![4, 10, 8, 22], [8, 3, 16, 7].map(collatz.as_fn()));
+}
+
+#[test]
+fn input_is_moved_into_branch_without_clone() {
+    struct NoClone(String);
+
+    let is_long = |x: &NoClone| x.0.len() > 3;
+    let upper = Capture(()).fun(|_, x: NoClone| x.0.to_uppercase());
+    let lower = Capture(()).fun(|_, x: NoClone| x.0.to_lowercase());
+
+    let fun = select_by(is_long, upper, lower);
+
+    assert_eq!("JOHN", fun.call(NoClone("John".to_string())));
+    assert_eq!("doe", fun.call(NoClone("Doe".to_string())));
+}
+
+#[test]
+fn branches_of_different_types() {
+    let is_known = Capture(vec!["a", "b"]).fun_key(|k, x: &&str| k.contains(x));
+    let lookup = Capture(vec![("a", 1), ("b", 2)]).fun(|m, x: &str| {
+        m.iter()
+            .find(|(k, _)| *k == x)
+            .map(|(_, v)| *v)
+            .unwrap_or(0)
+    });
+    let unknown = |_: &str| -1;
+
+    let value = select_by(is_known, lookup, unknown);
+
+    assert_eq!(1, value.call("a"));
+    assert_eq!(2, value.call("b"));
+    assert_eq!(-1, value.call("z"));
+}
+
+#[test]
+fn nested_selection() {
+    let sign = select_by(
+        |x: &i32| *x < 0,
+        |_: i32| -1,
+        select_by(|x: &i32| *x == 0, |_: i32| 0, |_: i32| 1),
+    );
+
+    assert_eq!(vec![-1, 0, 1], [-5, 0, 5].map(sign.as_fn()));
+}
+
+#[test]
+fn into_captured_data() {
+    let pred = Capture(10).fun_key(|t, x: &u32| x > t);
+    let high = Capture(100).fun(|c, x: u32| c + x);
+    let low = Capture(0).fun(|c, x: u32| c + x);
+
+    let fun = select_by(pred, high, low);
+    assert_eq!(111, fun.call(11));
+    assert_eq!(10, fun.call(10));
+
+    let (pred, high, low) = fun.into_captured_data();
+    assert_eq!(&10, pred.captured_data());
+    assert_eq!(&100, high.captured_data());
+    assert_eq!(&0, low.captured_data());
+}
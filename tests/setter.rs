@@ -0,0 +1,41 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+#[test]
+fn setter_writes_captured_data() {
+    let mut set = Capture(vec![vec![0u8; 2]; 2]).setter(|m, (i, j): (usize, usize), v: u8| {
+        m[i][j] = v;
+    });
+
+    set.set((0, 1), 1);
+    set.set((1, 1), 2);
+    set.set((0, 1), 3);
+
+    assert_eq!(&vec![vec![0, 3], vec![0, 2]], set.captured_data());
+}
+
+#[test]
+fn setter_over_borrowed_store() {
+    let mut store = HashMap::new();
+    {
+        let mut set = Capture(&mut store).setter(|s, key: &'static str, value: i32| {
+            s.insert(key, value);
+        });
+        set.set("a", 1);
+        set.set("b", 2);
+    }
+    assert_eq!(2, store.len());
+    assert_eq!(Some(&2), store.get("b"));
+}
+
+#[test]
+fn setter_clone() {
+    let mut first = Capture(vec![0; 2]).setter(|v, i: usize, x: i32| v[i] = x);
+    let mut second = first.clone();
+
+    first.set(0, 1);
+    second.set(1, 1);
+
+    assert_eq!(vec![1, 0], first.into_captured_data());
+    assert_eq!(vec![0, 1], second.into_captured_data());
+}
@@ -0,0 +1,60 @@
+use orx_closure::*;
+
+#[test]
+fn shr_two_closures_matches_then() {
+    let double = || Capture(2).fun(|k, x: i32| k * x);
+    let increment = || Capture(1).fun(|b, x: i32| x + b);
+
+    let by_op = double() >> increment();
+    let by_then = double().then(increment());
+
+    for x in -5..5 {
+        assert_eq!(by_then.call(x), by_op.call(x));
+    }
+    assert_eq!((&2, &1), by_op.captured_data());
+}
+
+#[test]
+fn shr_long_chain() {
+    let lines = Capture(vec!["3,4", "10,2"]).fun(|l, i: usize| l[i]);
+    let parse = Capture(',').fun(|sep, line: &str| {
+        let (a, b) = line.split_once(*sep).unwrap();
+        (a.parse::<u32>().unwrap(), b.parse::<u32>().unwrap())
+    });
+    let multiply = Capture(()).fun(|_, (a, b): (u32, u32)| a * b);
+    let format = Capture("area").fun(|label, x: u32| format!("{}={}", label, x));
+
+    let pipeline = lines >> parse >> multiply >> format;
+
+    assert_eq!("area=12", pipeline.call(0));
+    assert_eq!("area=20", pipeline.call(1));
+
+    let values: Vec<_> = (0..2).map(pipeline.as_fn()).collect();
+    assert_eq!(vec!["area=12", "area=20"], values);
+}
+
+#[test]
+fn shr_chain_clone_and_captures() {
+    let pipeline = Capture(10).fun(|k, x: i32| k * x)
+        >> Capture(5).fun(|b, x: i32| x - b)
+        >> Capture(2).fun(|d, x: i32| x / d);
+
+    let cloned = pipeline.clone();
+    assert_eq!(12, cloned.call(3));
+
+    let (first_two, last) = pipeline.into_captured_data();
+    assert_eq!(2, last);
+    assert_eq!((10, 5), first_two.into_captured_data());
+}
+
+#[test]
+fn shr_chain_as_fun() {
+    fn apply<F: Fun<i32, i32>>(f: &F, x: i32) -> i32 {
+        f.call(x)
+    }
+
+    let pipeline = Capture(1).fun(|b, x: i32| x + b)
+        >> Capture(1).fun(|b, x: i32| x + b)
+        >> Capture(1).fun(|b, x: i32| x + b);
+    assert_eq!(3, apply(&pipeline, 0));
+}
@@ -0,0 +1,85 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Weights = ClosureOneOf3<u64, Vec<Vec<u64>>, HashMap<(usize, usize), u64>, (usize, usize), u64>;
+
+fn sum_of_weights(weights: Weights, pairs: &[(usize, usize)]) -> u64 {
+    // single dispatch on the variant, monomorphic calls afterwards
+    fn sum<F: Fun<(usize, usize), u64>>(fun: F, pairs: &[(usize, usize)]) -> u64 {
+        pairs.iter().map(|x| fun.call(*x)).sum()
+    }
+    match weights.shrink() {
+        OneOf3::Variant1(fun) => sum(fun, pairs),
+        OneOf3::Variant2(fun) => sum(fun, pairs),
+        OneOf3::Variant3(fun) => sum(fun, pairs),
+    }
+}
+
+#[test]
+fn shrink_val() {
+    let pairs = [(0, 1), (1, 0), (1, 1)];
+
+    let uniform: Weights = Capture(3).fun(|w, _: (usize, usize)| *w).into_oneof3_var1();
+    assert_eq!(9, sum_of_weights(uniform, &pairs));
+
+    let dense: Weights = Capture(vec![vec![0, 1], vec![2, 3]])
+        .fun(|w, (i, j): (usize, usize)| w[i][j])
+        .into_oneof3_var2();
+    assert_eq!(6, sum_of_weights(dense, &pairs));
+
+    let sparse: Weights = Capture(HashMap::from([((1, 1), 10)]))
+        .fun(|w, ij: (usize, usize)| w.get(&ij).copied().unwrap_or(0))
+        .into_oneof3_var3();
+    assert_eq!(10, sum_of_weights(sparse, &pairs));
+}
+
+#[test]
+fn shrink_keeps_captured_data() {
+    let closure: ClosureRefOneOf2<Vec<String>, String, usize, str> = Capture(String::from("x"))
+        .fun_ref(|s, _: usize| s.as_str())
+        .into_oneof2_var2();
+
+    match closure.shrink() {
+        OneOf2::Variant2(fun) => {
+            assert_eq!("x", fun.call(42));
+            assert_eq!("x", fun.into_captured_data());
+        }
+        OneOf2::Variant1(_) => panic!("expected the second variant"),
+    }
+}
+
+#[test]
+fn shrink_opt_ref_and_res_ref() {
+    let closure: ClosureOptRefOneOf2<Vec<i32>, i32, usize, i32> = Capture(vec![1, 2])
+        .fun_option_ref(|v, i: usize| v.get(i))
+        .into_oneof2_var1();
+    assert!(matches!(closure.shrink(), OneOf2::Variant1(f) if f.call(1) == Some(&2)));
+
+    let closure: ClosureResRefOneOf4<i32, i32, i32, Vec<i32>, usize, i32, String> =
+        Capture(vec![1, 2])
+            .fun_result_ref(|v, i: usize| v.get(i).ok_or(format!("no {}", i)))
+            .into_oneof4_var4();
+    match closure.shrink() {
+        OneOf4::Variant4(fun) => assert_eq!(Err(String::from("no 7")), fun.call(7)),
+        _ => panic!("expected the fourth variant"),
+    }
+}
+
+#[test]
+fn shrink_mut() {
+    let closure: ClosureMutOneOf2<usize, Vec<i32>, i32, usize> = Capture(0)
+        .fun_mut(|count, _: i32| {
+            *count += 1;
+            *count
+        })
+        .into_oneof2_var1();
+
+    match closure.shrink() {
+        OneOf2::Variant1(mut fun) => {
+            fun.call_mut(1);
+            assert_eq!(2, fun.call_mut(2));
+            assert_eq!(2, fun.into_captured_data());
+        }
+        OneOf2::Variant2(_) => panic!("expected the first variant"),
+    }
+}
@@ -0,0 +1,67 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Dataset = HashMap<String, Vec<i32>>;
+
+fn dataset() -> Dataset {
+    HashMap::from_iter([
+        ("Alpha".to_string(), vec![1, 2]),
+        ("beta".to_string(), vec![3]),
+    ])
+}
+
+fn strict<'a>(data: &'a Dataset, key: &str) -> Option<&'a [i32]> {
+    data.get(key).map(|x| x.as_slice())
+}
+
+fn lenient<'a>(data: &'a Dataset, key: &str) -> Option<&'a [i32]> {
+    data.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, v)| v.as_slice())
+}
+
+#[test]
+fn swap_fun_returns_previous() {
+    let mut lookup = Capture(dataset()).fun_option_ref(strict);
+    assert_eq!(None, lookup.call("alpha"));
+
+    let previous = lookup.swap_fun(lenient);
+    assert_eq!(Some([1, 2].as_slice()), lookup.call("alpha"));
+
+    let previous = lookup.swap_fun(previous);
+    assert_eq!(None, lookup.call("alpha"));
+    assert_eq!(
+        Some([3].as_slice()),
+        previous(lookup.captured_data(), "BETA")
+    );
+}
+
+#[test]
+fn swap_fun_keeps_capture() {
+    let mut fun = Capture(vec![1, 2, 3]).fun(|x, i: usize| x[i]);
+    let ptr = fun.captured_data().as_ptr();
+    fun.swap_fun(|x, i| 10 * x[i]);
+    assert_eq!(20, fun.call(1));
+    assert_eq!(ptr, fun.captured_data().as_ptr());
+}
+
+#[test]
+fn with_fun_all_kinds() {
+    let fun = Capture(vec![1, 2, 3])
+        .fun(|x, i: usize| x[i])
+        .with_fun(|x, i| -x[i]);
+    assert_eq!(-3, fun.call(2));
+
+    let fun = Capture(vec![1, 2, 3])
+        .fun_ref(|x, i: usize| &x[i])
+        .with_fun(|x, _| &x[0]);
+    assert_eq!(&1, fun.call(2));
+
+    let fun = Capture(dataset()).fun_option_ref(strict).with_fun(lenient);
+    assert_eq!(Some([3].as_slice()), fun.call("Beta"));
+
+    let fun = Capture(vec![1, 2, 3])
+        .fun_result_ref(|x, i: usize| x.get(i).ok_or(i))
+        .with_fun(|_, i| Err(i + 1));
+    assert_eq!(Err(1), fun.call(0));
+}
@@ -0,0 +1,44 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+struct Graph {
+    weights: Vec<Vec<i32>>,
+    names: Option<HashMap<usize, String>>,
+}
+
+#[test]
+fn take_map_keeps_closure_alive() {
+    let graph = Graph {
+        weights: vec![vec![0, 3], vec![4, 0]],
+        names: Some(HashMap::from_iter([(0, "a".to_string())])),
+    };
+    let weight = Capture(graph).fun(|g, (i, j): (usize, usize)| g.weights[i][j]);
+
+    let (names, weight) = weight.take_map(|mut g| (g.names.take(), g));
+
+    assert_eq!(
+        Some("a"),
+        names.as_ref().and_then(|n| n.get(&0)).map(|x| x.as_str())
+    );
+    assert!(weight.captured_data().names.is_none());
+    assert_eq!(3, weight.call((0, 1)));
+    assert_eq!(4, weight.call((1, 0)));
+}
+
+#[test]
+fn take_map_ref_kinds() {
+    let fun = Capture((vec![1, 2], 10)).fun_ref(|(v, _), i: usize| &v[i]);
+    let (ten, fun) = fun.take_map(|(v, x)| (x, (v, 0)));
+    assert_eq!(10, ten);
+    assert_eq!(&2, fun.call(1));
+
+    let fun = Capture((vec![1, 2], vec![3])).fun_option_ref(|(v, _), i: usize| v.get(i));
+    let (taken, fun) = fun.take_map(|(v, w)| (w, (v, vec![])));
+    assert_eq!(vec![3], taken);
+    assert_eq!(None, fun.call(2));
+
+    let fun = Capture((vec![1, 2], 'x')).fun_result_ref(|(v, _), i: usize| v.get(i).ok_or(i));
+    let (c, fun) = fun.take_map(|(v, c)| (c, (v, 'y')));
+    assert_eq!('x', c);
+    assert_eq!(Err(5), fun.call(5));
+}
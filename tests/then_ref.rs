@@ -0,0 +1,74 @@
+use orx_closure::*;
+
+struct Address {
+    city: String,
+}
+
+struct Person {
+    name: String,
+    address: Address,
+    scores: Vec<u32>,
+}
+
+fn people() -> Vec<Person> {
+    vec![
+        Person {
+            name: String::from("john"),
+            address: Address {
+                city: String::from("rome"),
+            },
+            scores: vec![3, 4],
+        },
+        Person {
+            name: String::from("jane"),
+            address: Address {
+                city: String::from("oslo"),
+            },
+            scores: vec![],
+        },
+    ]
+}
+
+#[test]
+fn then_ref_projects_without_allocation() {
+    let person = Capture(people()).fun_ref(|p, i: usize| &p[i]);
+    let name = person.then_ref(|p| p.name.as_str());
+
+    let first: *const str = name.call(0);
+    let again: *const str = name.call(0);
+    assert_eq!(first, again);
+    assert_eq!("jane", name.call(1));
+}
+
+#[test]
+fn then_ref_chained_and_unsized() {
+    let city = Capture(people())
+        .fun_ref(|p, i: usize| &p[i])
+        .then_ref(|p| &p.address)
+        .then_ref(|a| a.city.as_str());
+    assert_eq!("oslo", city.call(1));
+
+    let scores = Capture(people())
+        .fun_ref(|p, i: usize| &p[i])
+        .then_ref(|p| p.scores.as_slice());
+    assert_eq!(&[3, 4], scores.call(0));
+    assert!(scores.call(1).is_empty());
+}
+
+#[test]
+fn then_ref_works_through_fun_ref_and_as_fn() {
+    fn lengths<F: FunRef<usize, str>>(f: &F) -> Vec<usize> {
+        (0..2).map(|i| f.call(i).len()).collect()
+    }
+
+    let name = Capture(people())
+        .fun_ref(|p, i: usize| &p[i])
+        .then_ref(|p| p.name.as_str());
+    assert_eq!(vec![4, 4], lengths(&name));
+
+    let names: Vec<_> = (0..2).map(name.as_fn()).collect();
+    assert_eq!(vec!["john", "jane"], names);
+
+    let (person, _) = name.into_captured_data();
+    assert_eq!(2, person.into_captured_data().len());
+}
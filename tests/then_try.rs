@@ -0,0 +1,74 @@
+use orx_closure::*;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    UnknownUser,
+    NoAddress,
+}
+
+struct User {
+    address: Option<String>,
+}
+
+#[test]
+fn then_try_propagates_the_error_of_the_fallible_stage() {
+    let names = vec!["john", "doe"];
+    let index_of = Capture(names).fun(|names, name: &str| names.iter().position(|x| *x == name));
+
+    let users = vec![
+        User {
+            address: Some(String::from("paris")),
+        },
+        User { address: None },
+    ];
+    let address_of = Capture(users).fun_result_ref(|users, i: Option<usize>| {
+        let user = users
+            .get(i.ok_or(Error::UnknownUser)?)
+            .ok_or(Error::UnknownUser)?;
+        user.address.as_deref().ok_or(Error::NoAddress)
+    });
+
+    let address = index_of.then_try(address_of);
+    assert_eq!(Ok("paris"), address.call("john"));
+    assert_eq!(Err(Error::NoAddress), address.call("doe"));
+    assert_eq!(Err(Error::UnknownUser), address.call("jane"));
+
+    let as_fn = address.as_fn();
+    assert_eq!(Ok("paris"), as_fn("john"));
+}
+
+#[test]
+fn then_try_accepts_unions_of_fallible_stages() {
+    type Lookup = ClosureResRefOneOf2<Vec<String>, String, usize, str, usize>;
+
+    let double = Capture(2).fun(|k, i: usize| k * i);
+    let lookup: Lookup = Capture(vec![
+        String::from("a"),
+        String::from("b"),
+        String::from("c"),
+    ])
+    .fun_result_ref(|v, i: usize| v.get(i).map(|x| x.as_str()).ok_or(i))
+    .into_oneof2_var1();
+
+    let chained = double.then_try(lookup);
+    assert_eq!(Ok("c"), chained.call(1));
+    assert_eq!(Err(4), chained.call(2));
+}
+
+#[test]
+fn try_then_maps_only_successful_outputs() {
+    let checked_div = Capture(100).fun(|n, d: i32| match d {
+        0 => Err("division by zero"),
+        d => Ok(n / d),
+    });
+    let describe = Capture("result").fun(|label, x: i32| format!("{}: {}", label, x));
+
+    let chained = checked_div.try_then(describe);
+    assert_eq!(Ok(String::from("result: 25")), chained.call(4));
+    assert_eq!(Err("division by zero"), chained.call(0));
+
+    let with_fn = Capture(())
+        .fun(|_, x: u8| Ok::<_, ()>(x))
+        .try_then(|x: u8| x as u32 * 1000);
+    assert_eq!(Ok(255_000), with_fn.call(255));
+}
@@ -0,0 +1,91 @@
+use orx_closure::*;
+use std::{sync::Arc, thread};
+
+type Weight = ClosureOneOf2<Vec<i32>, i32, usize, i32>;
+type Label = ClosureRefOneOf3<Vec<String>, String, char, usize, str>;
+
+struct Callbacks {
+    weight: Box<dyn Fn(usize) -> i32>,
+    label: Box<dyn Fn(usize) -> String>,
+}
+
+fn callbacks(weight: Arc<Weight>, label: Arc<Label>) -> Callbacks {
+    Callbacks {
+        weight: Box::new(weight.to_fn()),
+        label: Box::new(label.to_fn()),
+    }
+}
+
+#[test]
+fn to_fn_outlives_closure_binding() {
+    let weight: Weight = Capture(vec![3, 7])
+        .fun(|v, i: usize| v[i])
+        .into_oneof2_var1();
+    let label: Label = Capture(String::from("node"))
+        .fun_ref(|s, _: usize| s.as_str())
+        .into_oneof3_var2();
+
+    let callbacks = callbacks(Arc::new(weight), Arc::new(label));
+
+    assert_eq!(7, (callbacks.weight)(1));
+    assert_eq!("node", (callbacks.label)(0));
+}
+
+#[test]
+fn to_fn_shares_closure() {
+    let weight: Weight = Capture(5)
+        .fun(|k, i: usize| *k * i as i32)
+        .into_oneof2_var2();
+    let weight = Arc::new(weight);
+
+    let first = Arc::clone(&weight).to_fn();
+    let second = Arc::clone(&weight).to_fn();
+    assert_eq!(3, Arc::strong_count(&weight));
+
+    assert_eq!(10, first(2));
+    assert_eq!(15, second(3));
+
+    drop(first);
+    drop(second);
+    assert_eq!(1, Arc::strong_count(&weight));
+}
+
+#[test]
+fn to_fn_across_threads() {
+    let lookup: ClosureOptRefOneOf2<Vec<String>, String, usize, str> =
+        Capture(vec![String::from("a"), String::from("b")])
+            .fun_option_ref(|v, i: usize| v.get(i).map(|x| x.as_str()))
+            .into_oneof2_var1();
+    let lookup = Arc::new(lookup);
+
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let lookup = Arc::clone(&lookup).to_fn();
+            thread::spawn(move || lookup(i))
+        })
+        .collect();
+    let found: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(
+        vec![Some(String::from("a")), Some(String::from("b")), None],
+        found
+    );
+}
+
+#[test]
+fn to_fn_result_converts_output_to_owned() {
+    let row: ClosureResRefOneOf4<Vec<Vec<i32>>, (), char, bool, usize, [i32], String> =
+        Capture(vec![vec![1, 2], vec![3]])
+            .fun_result_ref(|m, i: usize| {
+                m.get(i)
+                    .map(|r| r.as_slice())
+                    .ok_or_else(|| format!("no row {}", i))
+            })
+            .into_oneof4_var1();
+
+    let rows: Vec<_> = [0, 1, 2].into_iter().map(Arc::new(row).to_fn()).collect();
+    assert_eq!(
+        vec![Ok(vec![1, 2]), Ok(vec![3]), Err(String::from("no row 2"))],
+        rows
+    );
+}
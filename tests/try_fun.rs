@@ -0,0 +1,73 @@
+use orx_closure::*;
+use std::{rc::Rc, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LookupError {
+    OutOfBounds(usize),
+}
+
+fn sum_all<F: TryFun<usize, i32, LookupError>>(
+    fun: &F,
+    indices: &[usize],
+) -> Result<i32, LookupError> {
+    let mut sum = 0;
+    for i in indices {
+        sum += fun.try_call(*i)?;
+    }
+    Ok(sum)
+}
+
+fn numbers() -> Vec<i32> {
+    vec![1, 2, 3]
+}
+
+#[test]
+fn try_fun_closure_by_ref() {
+    let numbers = numbers();
+    let fun = Capture(&numbers).fun(|x, i: usize| x.get(i).ok_or(LookupError::OutOfBounds(i)));
+    assert_eq!(Ok(6), sum_all(&fun, &[0, 1, 2]));
+    assert_eq!(Err(LookupError::OutOfBounds(5)), sum_all(&fun, &[0, 5, 7]));
+}
+
+#[test]
+fn try_fun_closure_res_ref() {
+    let fun = Capture(numbers())
+        .fun_result_ref(|x, i: usize| x.get(i).ok_or(LookupError::OutOfBounds(i)));
+    assert_eq!(Ok(4), sum_all(&fun, &[0, 2]));
+    assert_eq!(Err(LookupError::OutOfBounds(3)), sum_all(&fun, &[3]));
+}
+
+#[test]
+fn try_fun_unions() {
+    let fun: ClosureResRefOneOf2<Vec<i32>, i32, usize, i32, LookupError> = Capture(7)
+        .fun_result_ref(|x, i: usize| match i {
+            0 => Ok(x),
+            _ => Err(LookupError::OutOfBounds(i)),
+        })
+        .into_oneof2_var2();
+    assert_eq!(Ok(14), sum_all(&fun, &[0, 0]));
+    assert_eq!(Err(LookupError::OutOfBounds(1)), sum_all(&fun, &[0, 1]));
+
+    let fun: ClosureResRefOneOf3<(), Vec<i32>, char, usize, i32, LookupError> = Capture(numbers())
+        .fun_result_ref(|x, i: usize| x.get(i).ok_or(LookupError::OutOfBounds(i)))
+        .into_oneof3_var2();
+    assert_eq!(Ok(3), sum_all(&fun, &[2]));
+
+    let fun: ClosureResRefOneOf4<(), char, bool, Vec<i32>, usize, i32, LookupError> =
+        Capture(numbers())
+            .fun_result_ref(|x, i: usize| x.get(i).ok_or(LookupError::OutOfBounds(i)))
+            .into_oneof4_var4();
+    assert_eq!(Ok(3), sum_all(&fun, &[0, 1]));
+}
+
+#[test]
+fn try_fun_trait_objects_and_shared() {
+    let fun = Capture(numbers())
+        .fun_result_ref(|x, i: usize| x.get(i).ok_or(LookupError::OutOfBounds(i)));
+
+    let shared: Rc<dyn TryFun<usize, i32, LookupError>> = Rc::new(fun.clone());
+    assert_eq!(Ok(3), sum_all(&shared, &[0, 1]));
+
+    let shared: Arc<dyn TryFun<usize, i32, LookupError>> = Arc::new(fun);
+    assert_eq!(Err(LookupError::OutOfBounds(9)), sum_all(&shared, &[9]));
+}
@@ -0,0 +1,73 @@
+use orx_closure::*;
+use std::{rc::Rc, sync::Arc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CostError {
+    Unreachable(usize),
+}
+
+fn total<F: TryFunVal<usize, u64, CostError>>(
+    fun: &F,
+    indices: &[usize],
+) -> Result<u64, CostError> {
+    let mut sum = 0;
+    for i in indices {
+        sum += fun.try_call_val(*i)?;
+    }
+    Ok(sum)
+}
+
+type Distances = Vec<Option<u64>>;
+
+fn distances() -> Distances {
+    vec![Some(3), None, Some(5)]
+}
+
+#[test]
+fn try_fun_val_closure() {
+    let fun = Capture(distances())
+        .fun(|d, i: usize| d[i].map(|x| x * 2).ok_or(CostError::Unreachable(i)));
+    assert_eq!(Ok(16), total(&fun, &[0, 2]));
+    assert_eq!(Err(CostError::Unreachable(1)), total(&fun, &[0, 1, 2]));
+    assert_eq!(Ok(10), fun.try_call_val_into(2usize));
+}
+
+#[test]
+fn try_fun_val_unions() {
+    type Cost = ClosureOneOf2<Distances, u64, usize, Result<u64, CostError>>;
+
+    let from_data: Cost = Capture(distances())
+        .fun(|d, i: usize| d[i].ok_or(CostError::Unreachable(i)))
+        .into_oneof2_var1();
+    let constant: Cost = Capture(7)
+        .fun(|c, _: usize| Ok::<_, CostError>(*c))
+        .into_oneof2_var2();
+
+    assert_eq!(Ok(8), total(&from_data, &[0, 2]));
+    assert_eq!(Ok(21), total(&constant, &[0, 1, 2]));
+
+    let fun: ClosureOneOf4<(), (), Distances, (), usize, Result<u64, CostError>> =
+        Capture(distances())
+            .fun(|d, i: usize| d[i].ok_or(CostError::Unreachable(i)))
+            .into_oneof4_var3();
+    assert_eq!(Err(CostError::Unreachable(1)), total(&fun, &[1]));
+}
+
+#[test]
+fn try_fun_val_trait_objects_and_shared() {
+    let fun = Capture(distances()).fun(|d, i: usize| d[i].ok_or(CostError::Unreachable(i)));
+
+    let shared: Rc<dyn TryFunVal<usize, u64, CostError>> = Rc::new(fun.clone());
+    assert_eq!(Ok(8), total(&shared, &[0, 2]));
+
+    let shared: Arc<dyn TryFunVal<usize, u64, CostError>> = Arc::new(fun);
+    assert_eq!(Err(CostError::Unreachable(1)), total(&shared, &[1]));
+}
+
+#[test]
+fn borrowed_results_have_a_single_try_fun_impl() {
+    let v = vec![1, 2, 3];
+    let fun = Capture(&v).fun(|x, i: usize| x.get(i).ok_or("oob"));
+    assert_eq!(Ok(&2), fun.try_call(1usize));
+    assert_eq!(Err("oob"), fun.try_call(3usize));
+}
@@ -0,0 +1,36 @@
+use orx_closure::*;
+
+type Weights = Vec<Vec<u32>>;
+
+fn weights_from(weights: &Weights, source: usize) -> Closure<Vec<u32>, usize, u32> {
+    Capture(weights[source].clone()).fun(|row, target| row[target])
+}
+
+#[test]
+fn uncurry_flattens_a_factory() {
+    let weights: Weights = vec![vec![0, 3], vec![7, 0]];
+    let factory = Capture(weights).fun(weights_from);
+
+    let weight = factory.uncurry();
+    assert_eq!(3, weight.call((0, 1)));
+    assert_eq!(7, weight.call2(1, 0));
+
+    let total: u32 = [(0, 1), (1, 0), (1, 1)]
+        .into_iter()
+        .map(weight.as_fn())
+        .sum();
+    assert_eq!(10, total);
+}
+
+#[test]
+fn uncurry_inverts_curry() {
+    let at = Capture(vec![vec![1, 2], vec![3, 4]]).fun(|m, (i, j): (usize, usize)| m[i][j]);
+    let rows = Capture(at).fun(|at, i: usize| at.clone().curry(i));
+
+    let at = rows.uncurry();
+    assert_eq!(
+        vec![1, 2, 3, 4],
+        at.captured_data().captured_data().eval_grid(2, 2)
+    );
+    assert_eq!(3, at.call((1, 0)));
+}
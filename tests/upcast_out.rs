@@ -0,0 +1,77 @@
+use orx_closure::*;
+use std::{any::Any, fmt::Display};
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+struct Circle(f64);
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.0 * self.0
+    }
+}
+
+fn total_area(shapes: &[&dyn FunRef<usize, dyn Shape>], i: usize) -> f64 {
+    shapes.iter().map(|s| s.call(i).area()).sum()
+}
+
+#[test]
+fn upcast_ref_to_common_trait_object() {
+    let squares = Capture(vec![Square(1.0), Square(2.0)])
+        .fun_ref(|x, i: usize| &x[i])
+        .upcast_out::<dyn Shape>(|x| x);
+    let circles = Capture(vec![Circle(1.0), Circle(2.0)])
+        .fun_ref(|x, i: usize| &x[i])
+        .upcast_out::<dyn Shape>(|x| x);
+
+    assert_eq!(4.0, total_area(&[&squares, &circles], 0));
+    assert_eq!(16.0, total_area(&[&squares, &circles], 1));
+}
+
+#[test]
+fn upcast_ref_keeps_inner_closure() {
+    let display = Capture(vec![1, 2])
+        .fun_ref(|x, i: usize| &x[i])
+        .upcast_out::<dyn Display>(|x| x);
+    assert_eq!("2", display.call(1).to_string());
+
+    let (inner, _) = display.into_captured_data();
+    assert_eq!(&1, inner.call(0));
+}
+
+#[test]
+fn upcast_opt_ref_to_any() {
+    let any = Capture(vec!["a".to_string()])
+        .fun_option_ref(|x, i: usize| x.get(i))
+        .upcast_out::<dyn Any>(|x| x);
+    assert_eq!(
+        Some(&"a".to_string()),
+        any.call(0).and_then(|x| x.downcast_ref::<String>())
+    );
+    assert!(any.call(1).is_none());
+}
+
+#[test]
+fn upcast_res_ref() {
+    let shape = Capture(vec![Square(3.0)])
+        .fun_result_ref(|x, i: usize| x.get(i).ok_or(i))
+        .upcast_out::<dyn Shape>(|x| x);
+    assert_eq!(Ok(9.0), shape.call(0).map(|s| s.area()));
+    assert_eq!(Err(4), shape.call(4).map(|s| s.area()));
+}
+
+#[test]
+fn upcast_with_projection() {
+    let first = Capture(vec![vec![1, 2], vec![3]])
+        .fun_ref(|x, i: usize| &x[i])
+        .upcast_out::<[i32]>(|x| &x[..1]);
+    assert_eq!(&[3], first.call(1));
+}
@@ -0,0 +1,50 @@
+use orx_closure::*;
+
+type Weights = Vec<Vec<i32>>;
+
+fn add_node(weights: &mut Weights, weight: i32) {
+    weights.iter_mut().for_each(|row| row.push(weight));
+    let n = weights.len();
+    weights.push(vec![weight; n + 1]);
+    weights[n][n] = 0;
+}
+
+#[test]
+fn update_capture_closure() {
+    let mut weight = Capture(vec![vec![0]]).fun(|w: &Weights, (i, j): (usize, usize)| w[i][j]);
+    weight.update_capture(|w| add_node(w, 5));
+    weight.update_capture(|w| add_node(w, 9));
+
+    assert_eq!(5, weight.call((0, 1)));
+    assert_eq!(9, weight.call((2, 1)));
+    assert_eq!(0, weight.call((2, 2)));
+    assert_eq!(3, weight.captured_data().len());
+}
+
+#[test]
+fn update_capture_keeps_fun() {
+    let mut double = Capture(2).fun(|factor, x: i32| x * factor);
+    double.update_capture(|f| *f = 3);
+    assert_eq!(12, double.call(4));
+
+    let triple = double.with_fun(|factor, x| x + factor);
+    assert_eq!(7, triple.call(4));
+}
+
+#[test]
+fn update_capture_ref_kinds() {
+    let mut by_ref = Capture(vec!["a".to_string()]).fun_ref(|x, i: usize| x[i].as_str());
+    let mut by_opt =
+        Capture(vec!["a".to_string()]).fun_option_ref(|x, i: usize| x.get(i).map(|x| x.as_str()));
+    let mut by_res = Capture(vec!["a".to_string()])
+        .fun_result_ref(|x, i: usize| x.get(i).map(|x| x.as_str()).ok_or(i));
+
+    by_ref.update_capture(|x| x[0].push('!'));
+    by_opt.update_capture(|x| x.clear());
+    by_res.update_capture(|x| x.insert(0, "z".to_string()));
+
+    assert_eq!("a!", by_ref.call(0));
+    assert_eq!(None, by_opt.call(0));
+    assert_eq!(Ok("z"), by_res.call(0));
+    assert_eq!(Ok("a"), by_res.call(1));
+}
@@ -0,0 +1,124 @@
+use orx_closure::*;
+use std::collections::HashMap;
+
+type Id = u32;
+
+struct Person {
+    name: String,
+}
+
+fn people() -> HashMap<Id, Person> {
+    HashMap::from([
+        (
+            1,
+            Person {
+                name: "john".to_string(),
+            },
+        ),
+        (
+            2,
+            Person {
+                name: "doe".to_string(),
+            },
+        ),
+        (
+            3,
+            Person {
+                name: "jane".to_string(),
+            },
+        ),
+    ])
+}
+
+fn ages() -> HashMap<Id, u8> {
+    HashMap::from([(1, 42), (3, 17), (4, 99)])
+}
+
+#[test]
+fn zip_with_joins_per_key() {
+    let name = Capture(people()).fun_option_ref(|p, id: Id| p.get(&id).map(|x| x.name.as_str()));
+    let age = Capture(ages()).fun_option_ref(|a, id: Id| a.get(&id));
+
+    let describe = name.zip_with(age, |n, a| format!("{} ({})", n, a));
+
+    assert_eq!(Some("john (42)".to_string()), describe.call(1));
+    assert_eq!(None, describe.call(2));
+    assert_eq!(Some("jane (17)".to_string()), describe.call(3));
+    assert_eq!(None, describe.call(4));
+    assert_eq!(None, describe.call(5));
+}
+
+#[test]
+fn zip_with_owns_both_captures() {
+    let describe = {
+        let name =
+            Capture(people()).fun_option_ref(|p, id: Id| p.get(&id).map(|x| x.name.as_str()));
+        let age = Capture(ages()).fun_option_ref(|a, id: Id| a.get(&id));
+        name.zip_with(age, |n, a| n.len() + *a as usize)
+    };
+    assert_eq!(Some(46), describe.call(1));
+
+    let (name, age, _) = describe.into_captured_data();
+    assert_eq!(3, name.captured_data().len());
+    assert_eq!(Some(&99), age.call(4));
+}
+
+#[test]
+fn zip_with_chained() {
+    let a = Capture(vec![1, 2, 3]).fun_option_ref(|x, i: usize| x.get(i));
+    let b = Capture(vec![10, 20]).fun_option_ref(|x, i: usize| x.get(i));
+    let c = Capture(vec![100]).fun_option_ref(|x, i: usize| x.get(i));
+
+    let abc = Capture((a.zip_with(b, |a, b| a + b), c))
+        .fun(|(ab, c), i: usize| ab.call(i).zip(c.call(i)).map(|(ab, c)| ab + c));
+
+    assert_eq!(Some(111), abc.call(0));
+    assert_eq!(None, abc.call(1));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Tolls(Vec<u32>);
+
+#[test]
+fn zip_with_sums_base_cost_and_penalty() {
+    let distances = vec![10, 20, 30];
+    let base = Capture(distances).fun(|d, e: usize| d[e]);
+    let penalty = Capture(Tolls(vec![0, 5, 1])).fun(|t, e: usize| t.0[e]);
+
+    let cost = base.zip_with(penalty, |b, p| b + p);
+    let costs: Vec<_> = (0..3).map(cost.as_fn()).collect();
+    assert_eq!(vec![10, 25, 31], costs);
+}
+
+#[test]
+fn zip_with_different_output_types() {
+    let names = Capture(vec!["a", "b"]).fun(|n, i: usize| n[i]);
+    let scores = Capture(vec![1.5, 2.5]).fun(|s, i: usize| s[i]);
+
+    let labels = names.zip_with(scores, |n, s| format!("{}={}", n, s));
+    assert_eq!("b=2.5", labels.call(1));
+}
+
+#[test]
+fn zip_with_clone_and_into_captured_data() {
+    let base = Capture(vec![1, 2]).fun(|d, e: usize| d[e]);
+    let penalty = Capture(Tolls(vec![3, 4])).fun(|t, e: usize| t.0[e]);
+    let cost = base.zip_with(penalty, |b, p| b * p);
+
+    let cloned = cost.clone();
+    assert_eq!(8, cloned.call(1));
+
+    let (base, penalty, _) = cost.into_captured_data();
+    assert_eq!(vec![1, 2], base.into_captured_data());
+    assert_eq!(Tolls(vec![3, 4]), penalty.into_captured_data());
+}
+
+#[test]
+fn zip_with_nested() {
+    let a = Capture(1).fun(|k, x: i32| k + x);
+    let b = Capture(2).fun(|k, x: i32| k * x);
+    let c = Capture(3).fun(|k, x: i32| k - x);
+
+    let all = a.zip_with(b, |a, b| a + b).zip_with(c, |ab, c| ab * c);
+    assert_eq!(-13, all.call(4));
+}